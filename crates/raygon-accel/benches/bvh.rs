@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use thermite::backends::{avx2::AVX2, sse42::SSE42};
+
+use raygon_accel::{Bvh, BvhOptions, WideBvh};
+use raygon_geometry::scalar::Bounds3;
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn bench_traversal(c: &mut Criterion) {
+    let rays = random_rays(4096, 1);
+
+    let mut group = c.benchmark_group("bvh");
+    group.throughput(Throughput::Elements(rays.len() as u64));
+
+    for &count in &[1000, 100_000] {
+        let triangles = random_triangles_of_size(count, 20.0 / (count as f32).cbrt(), 2);
+        let bounds: Vec<Bounds3> = triangles.iter().map(bounds).collect();
+
+        let binary = Bvh::build(&bounds, &BvhOptions::default());
+        let wide4 = WideBvh::<SSE42>::collapse(&binary);
+        let wide8 = WideBvh::<AVX2>::collapse(&binary);
+
+        group.bench_with_input(BenchmarkId::new("binary", count), &rays, |b, rays| {
+            b.iter(|| {
+                rays.iter()
+                    .filter(|&&ray| binary.intersect(&mut { ray }, |ray, i| intersect(ray, &triangles[i as usize])))
+                    .count()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("wide4", count), &rays, |b, rays| {
+            b.iter(|| {
+                rays.iter()
+                    .filter(|&&ray| wide4.intersect(&mut { ray }, |ray, i| intersect(ray, &triangles[i as usize])))
+                    .count()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("wide8", count), &rays, |b, rays| {
+            b.iter(|| {
+                rays.iter()
+                    .filter(|&&ray| wide8.intersect(&mut { ray }, |ray, i| intersect(ray, &triangles[i as usize])))
+                    .count()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_traversal);
+criterion_main!(benches);
@@ -0,0 +1,213 @@
+use raygon_core::partition_bidir;
+
+use crate::geometry::scalar::{Bounds3, Point3};
+
+use super::{Bvh, BvhNode, BvhOptions, BvhStats, MAX_DEPTH};
+
+#[derive(Debug, Clone, Copy)]
+struct BuildPrimitive {
+    index: u32,
+    bounds: Bounds3,
+    centroid: Point3,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bin {
+    bounds: Bounds3,
+    count: u32,
+}
+
+impl Default for Bin {
+    fn default() -> Bin {
+        Bin {
+            bounds: Bounds3::empty(),
+            count: 0,
+        }
+    }
+}
+
+/// Where to split a node, as the last bin on the left side
+#[derive(Debug, Clone, Copy)]
+struct Split {
+    axis: usize,
+    bin: usize,
+    cost: f32,
+}
+
+struct Builder<'a> {
+    options: &'a BvhOptions,
+    nodes: Vec<BvhNode>,
+    leaves: usize,
+    max_depth: usize,
+    sah_cost: f64,
+}
+
+pub(super) fn build(bounds: &[Bounds3], options: &BvhOptions) -> Bvh {
+    assert!(options.bins >= 2, "SAH needs at least two bins");
+    assert!(bounds.len() <= u32::MAX as usize);
+
+    let mut primitives: Vec<BuildPrimitive> = bounds
+        .iter()
+        .enumerate()
+        .map(|(index, &bounds)| BuildPrimitive {
+            index: index as u32,
+            bounds,
+            centroid: bounds.centroid(),
+        })
+        .collect();
+
+    let mut builder = Builder {
+        options,
+        nodes: Vec::with_capacity(bounds.len().saturating_mul(2)),
+        leaves: 0,
+        max_depth: 0,
+        sah_cost: 0.0,
+    };
+
+    if !primitives.is_empty() {
+        builder.build(&mut primitives, 0, 0);
+    }
+
+    let mut stats = BvhStats::new();
+    stats.nodes.add(builder.nodes.len() as u64);
+    stats.leaves.add(builder.leaves as u64);
+    stats.max_depth.add(builder.max_depth as u64);
+
+    let root_area = builder.nodes.first().map_or(0.0, |root| root.bounds.surface_area());
+    if root_area > 0.0 {
+        stats.sah_cost = (builder.sah_cost / root_area as f64) as f32;
+    }
+
+    Bvh {
+        nodes: builder.nodes,
+        indices: primitives.iter().map(|p| p.index).collect(),
+        stats,
+    }
+}
+
+impl Builder<'_> {
+    /// Builds the subtree over `primitives`, which start at `offset` in the final order, returning its root
+    fn build(&mut self, primitives: &mut [BuildPrimitive], offset: usize, depth: usize) -> usize {
+        let node_index = self.nodes.len();
+
+        let bounds = primitives.iter().fold(Bounds3::empty(), |b, p| b.union(&p.bounds));
+        let area = bounds.surface_area();
+
+        // placeholder until the children are built
+        self.nodes.push(BvhNode {
+            bounds,
+            offset: offset as u32,
+            count: primitives.len() as u32,
+            axis: 0,
+        });
+
+        let n = primitives.len();
+        let centroid_bounds = primitives.iter().fold(Bounds3::empty(), |b, p| b.union_point(p.centroid));
+
+        let split = if n > 1 && depth + 1 < MAX_DEPTH {
+            self.find_split(primitives, &centroid_bounds, area)
+        } else {
+            None
+        };
+
+        let split = match split {
+            Some(split) if n > self.options.max_leaf_size || split.cost < n as f32 => split,
+            _ => {
+                self.leaves += 1;
+                self.max_depth = self.max_depth.max(depth);
+                self.sah_cost += n as f64 * area as f64;
+
+                return node_index;
+            }
+        };
+
+        let bins = self.options.bins;
+
+        let mid = partition_bidir(primitives, |p| {
+            bin_index(&centroid_bounds, p.centroid, split.axis, bins) <= split.bin
+        });
+
+        debug_assert!(mid > 0 && mid < n);
+
+        self.sah_cost += self.options.traversal_cost as f64 * area as f64;
+
+        let (left, right) = primitives.split_at_mut(mid);
+
+        self.build(left, offset, depth + 1);
+        let second = self.build(right, offset + mid, depth + 1);
+
+        self.nodes[node_index] = BvhNode {
+            bounds,
+            offset: second as u32,
+            count: 0,
+            axis: split.axis as u8,
+        };
+
+        node_index
+    }
+
+    /// Cheapest binned SAH split along the widest axis of the centroids, if they can be split at all
+    fn find_split(&self, primitives: &[BuildPrimitive], centroid_bounds: &Bounds3, area: f32) -> Option<Split> {
+        let axis = centroid_bounds.max_extent();
+
+        // every centroid in the same place, so no split separates them
+        if centroid_bounds.max[axis] <= centroid_bounds.min[axis] {
+            return None;
+        }
+
+        let n = self.options.bins;
+        let mut bins = vec![Bin::default(); n];
+
+        for p in primitives {
+            let bin = &mut bins[bin_index(centroid_bounds, p.centroid, axis, n)];
+
+            bin.count += 1;
+            bin.bounds = bin.bounds.union(&p.bounds);
+        }
+
+        // sweep from the right for the cost of everything after each split
+        let mut right_cost = vec![0.0f32; n];
+        let mut right = Bin::default();
+
+        for i in (1..n).rev() {
+            right.count += bins[i].count;
+            right.bounds = right.bounds.union(&bins[i].bounds);
+
+            right_cost[i - 1] = if right.count > 0 {
+                right.count as f32 * right.bounds.surface_area()
+            } else {
+                f32::INFINITY
+            };
+        }
+
+        let mut left = Bin::default();
+        let mut best: Option<Split> = None;
+
+        // nodes without area can't be scored, but any split that separates their primitives will do
+        let area = area.max(f32::MIN_POSITIVE);
+
+        for (i, bin) in bins.iter().enumerate().take(n - 1) {
+            left.count += bin.count;
+            left.bounds = left.bounds.union(&bin.bounds);
+
+            if left.count == 0 {
+                continue;
+            }
+
+            let cost = self.options.traversal_cost + (left.count as f32 * left.bounds.surface_area() + right_cost[i]) / area;
+
+            if cost < best.map_or(f32::INFINITY, |best| best.cost) {
+                best = Some(Split { axis, bin: i, cost });
+            }
+        }
+
+        best
+    }
+}
+
+#[inline]
+fn bin_index(centroid_bounds: &Bounds3, centroid: Point3, axis: usize, bins: usize) -> usize {
+    let offset = centroid_bounds.offset(centroid)[axis];
+
+    ((offset * bins as f32) as usize).min(bins - 1)
+}
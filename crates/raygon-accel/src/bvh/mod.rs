@@ -0,0 +1,163 @@
+//! Bounding volume hierarchy
+//!
+//! Built top-down with binned SAH over the bounds of each primitive, then stored depth-first in a flat array
+//! where the first child of an interior node immediately follows it. The BVH only stores primitive indices,
+//! so traversal takes a callback that intersects a ray with the primitive at a given index.
+//!
+//! A binary BVH can be collapsed into a [`WideBvh`] with a child per SIMD lane, which tests a ray against
+//! all children of a node with one slab test.
+
+use std::fmt::{self, Display};
+
+use raygon_profiler::Counter;
+
+use crate::geometry::scalar::Bounds3;
+
+mod build;
+mod traverse;
+mod wide;
+
+pub use self::wide::{WideBvh, WideChild, WideNode, MAX_WIDTH};
+
+/// Deepest a BVH may be, which bounds the traversal stack
+pub const MAX_DEPTH: usize = 64;
+
+/// Parameters of BVH construction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BvhOptions {
+    /// Number of bins along the split axis that SAH splits are evaluated between
+    pub bins: usize,
+    /// Largest leaf that construction will stop at, unless its primitives can't be separated
+    pub max_leaf_size: usize,
+    /// Cost of traversing one node, relative to intersecting one primitive
+    pub traversal_cost: f32,
+}
+
+impl Default for BvhOptions {
+    fn default() -> BvhOptions {
+        BvhOptions {
+            bins: 12,
+            max_leaf_size: 4,
+            traversal_cost: 0.125,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BvhNode {
+    pub bounds: Bounds3,
+    /// First primitive of a leaf in `Bvh::indices`, or the second child of an interior node
+    pub offset: u32,
+    /// Number of primitives in a leaf, or zero for interior nodes
+    pub count: u32,
+    /// Axis along which an interior node's children were split
+    pub axis: u8,
+}
+
+impl BvhNode {
+    #[inline(always)]
+    pub fn is_leaf(&self) -> bool {
+        self.count > 0
+    }
+}
+
+/// Statistics of a BVH's construction and traversal
+///
+/// Traversal counters are updated by every trace, and may be read while rays are being traced.
+#[derive(Debug)]
+pub struct BvhStats {
+    /// Nodes in the tree, including leaves
+    pub nodes: Counter,
+    pub leaves: Counter,
+    /// Depth of the deepest leaf, where the root is at depth zero
+    pub max_depth: Counter,
+    /// SAH cost of the root node, relative to intersecting one primitive, computed once when the tree is built
+    ///
+    /// Unlike the counters, this is the expected cost of tracing a ray through the whole tree. A `WideBvh`
+    /// reports the cost of the binary tree it was collapsed from.
+    pub sah_cost: f32,
+    /// Rays traced, counting each active lane of a packet
+    pub rays: Counter,
+    /// Nodes whose bounds were tested, counting each packet once
+    pub nodes_visited: Counter,
+    /// Primitives passed to the intersection callback, counting each packet once
+    pub primitives_tested: Counter,
+}
+
+impl BvhStats {
+    fn new() -> BvhStats {
+        BvhStats {
+            nodes: Counter::new(),
+            leaves: Counter::new(),
+            max_depth: Counter::new(),
+            sah_cost: 0.0,
+            rays: Counter::new(),
+            nodes_visited: Counter::new(),
+            primitives_tested: Counter::new(),
+        }
+    }
+}
+
+impl Display for BvhStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} nodes, {} leaves, depth {}, SAH cost {:.3}",
+            self.nodes, self.leaves, self.max_depth, self.sah_cost
+        )?;
+
+        let rays = self.rays.get().max(1) as f64;
+
+        writeln!(
+            f,
+            "{} rays, {:.2} nodes and {:.2} primitives per ray",
+            self.rays,
+            self.nodes_visited.get() as f64 / rays,
+            self.primitives_tested.get() as f64 / rays,
+        )
+    }
+}
+
+/// Bounding volume hierarchy over a set of primitives
+#[derive(Debug)]
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    indices: Vec<u32>,
+    stats: BvhStats,
+}
+
+impl Bvh {
+    /// Builds a BVH over primitives with the given bounds, which are referred to by their index in `bounds`
+    pub fn build(bounds: &[Bounds3], options: &BvhOptions) -> Bvh {
+        build::build(bounds, options)
+    }
+
+    /// Nodes in depth-first order, starting with the root
+    #[inline]
+    pub fn nodes(&self) -> &[BvhNode] {
+        &self.nodes
+    }
+
+    /// Primitive indices, in the order leaves refer to them
+    #[inline]
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    #[inline]
+    pub fn stats(&self) -> &BvhStats {
+        &self.stats
+    }
+
+    /// Bounds of every primitive
+    #[inline]
+    pub fn bounds(&self) -> Bounds3 {
+        self.nodes.first().map_or_else(Bounds3::empty, |root| root.bounds)
+    }
+
+    /// Primitives of a leaf node
+    #[inline]
+    pub fn leaf_primitives(&self, node: &BvhNode) -> &[u32] {
+        &self.indices[node.offset as usize..(node.offset + node.count) as usize]
+    }
+}
@@ -0,0 +1,261 @@
+use thermite::*;
+
+use crate::geometry::{scalar, soa};
+
+use super::{Bvh, MAX_DEPTH};
+
+/// Number of lanes set in `mask`
+#[inline]
+fn count_lanes<S: Simd>(mask: Mask<S, Vf32<S>>) -> u64 {
+    let ones = mask.select(Vf32::<S>::one(), Vf32::<S>::zero());
+
+    (0..Vf32::<S>::NUM_ELEMENTS).filter(|&lane| ones.extract(lane) != 0.0).count() as u64
+}
+
+impl Bvh {
+    /// Finds the closest hit of a single ray
+    ///
+    /// `intersect(ray, primitive)` must test the primitive with index `primitive`, and on a hit shrink
+    /// `ray.tmax` to it and return true. Children are visited nearest first along the ray's direction,
+    /// so primitives behind the closest hit are mostly culled by their bounds.
+    pub fn intersect<F>(&self, ray: &mut scalar::Ray3, mut intersect: F) -> bool
+    where
+        F: FnMut(&mut scalar::Ray3, u32) -> bool,
+    {
+        self.traverse(ray, false, &mut intersect)
+    }
+
+    /// Whether a single ray hits any primitive before `ray.tmax`, stopping at the first hit found
+    pub fn occluded<F>(&self, ray: &scalar::Ray3, mut intersect: F) -> bool
+    where
+        F: FnMut(&mut scalar::Ray3, u32) -> bool,
+    {
+        self.traverse(&mut { *ray }, true, &mut intersect)
+    }
+
+    fn traverse<F>(&self, ray: &mut scalar::Ray3, any_hit: bool, intersect: &mut F) -> bool
+    where
+        F: FnMut(&mut scalar::Ray3, u32) -> bool,
+    {
+        if self.nodes.is_empty() {
+            return false;
+        }
+
+        let inv_dir = ray.inv_direction();
+        let dir_is_neg = [inv_dir.x < 0.0, inv_dir.y < 0.0, inv_dir.z < 0.0];
+
+        let mut stack = [0u32; MAX_DEPTH];
+        let mut top = 0;
+        let mut current = 0;
+
+        let (mut visited, mut tested) = (0, 0);
+        let mut hit = false;
+
+        'traversal: loop {
+            let node = &self.nodes[current];
+            visited += 1;
+
+            if node.bounds.intersect_p(ray, &inv_dir).is_some() {
+                if node.is_leaf() {
+                    for &primitive in self.leaf_primitives(node) {
+                        tested += 1;
+
+                        if intersect(ray, primitive) {
+                            hit = true;
+
+                            if any_hit {
+                                break 'traversal;
+                            }
+                        }
+                    }
+                } else {
+                    let (near, far) = if dir_is_neg[node.axis as usize] {
+                        (node.offset as usize, current + 1)
+                    } else {
+                        (current + 1, node.offset as usize)
+                    };
+
+                    stack[top] = far as u32;
+                    top += 1;
+                    current = near;
+
+                    continue;
+                }
+            }
+
+            if top == 0 {
+                break;
+            }
+
+            top -= 1;
+            current = stack[top] as usize;
+        }
+
+        self.stats.rays.incr();
+        self.stats.nodes_visited.add(visited);
+        self.stats.primitives_tested.add(tested);
+
+        hit
+    }
+
+    /// Finds the closest hit of each lane of a packet of rays that is set in `active`, returning the lanes that hit
+    ///
+    /// `intersect(ray, mask, primitive)` must test the primitive with index `primitive` for the lanes in `mask`,
+    /// shrink `ray.tmax` in the lanes that hit and return them. Nodes are visited while any active lane hits
+    /// their bounds, nearest first along the direction of the first lane, so coherent packets work best.
+    pub fn intersect_packet<S: Simd, F>(&self, ray: &mut soa::Ray3<S>, active: Mask<S, Vf32<S>>, mut intersect: F) -> Mask<S, Vf32<S>>
+    where
+        F: FnMut(&mut soa::Ray3<S>, Mask<S, Vf32<S>>, u32) -> Mask<S, Vf32<S>>,
+    {
+        let mut hit = active & !active;
+
+        if self.nodes.is_empty() || !active.any() {
+            return hit;
+        }
+
+        let inv_dir = ray.inv_direction();
+        let dir_is_neg = [inv_dir.x.extract(0) < 0.0, inv_dir.y.extract(0) < 0.0, inv_dir.z.extract(0) < 0.0];
+
+        let mut stack = [0u32; MAX_DEPTH];
+        let mut top = 0;
+        let mut current = 0;
+
+        let (mut visited, mut tested) = (0, 0);
+
+        loop {
+            let node = &self.nodes[current];
+            visited += 1;
+
+            let (mask, ..) = soa::Bounds3::<S>::splat(&node.bounds).intersect_p(ray, &inv_dir);
+            let mask = mask & active;
+
+            if mask.any() {
+                if node.is_leaf() {
+                    for &primitive in self.leaf_primitives(node) {
+                        tested += 1;
+                        hit = hit | intersect(ray, mask, primitive);
+                    }
+                } else {
+                    let (near, far) = if dir_is_neg[node.axis as usize] {
+                        (node.offset as usize, current + 1)
+                    } else {
+                        (current + 1, node.offset as usize)
+                    };
+
+                    stack[top] = far as u32;
+                    top += 1;
+                    current = near;
+
+                    continue;
+                }
+            }
+
+            if top == 0 {
+                break;
+            }
+
+            top -= 1;
+            current = stack[top] as usize;
+        }
+
+        self.stats.rays.add(count_lanes::<S>(active));
+        self.stats.nodes_visited.add(visited);
+        self.stats.primitives_tested.add(tested);
+
+        hit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::geometry::scalar::{Bounds3, Point3, Ray3, Vector3};
+    use crate::BvhOptions;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    /// A grid of small triangles in the z = 0 plane
+    fn grid(n: usize) -> Vec<[[f32; 3]; 3]> {
+        (0..n * n)
+            .map(|i| {
+                let (x, y) = ((i % n) as f32, (i / n) as f32);
+                [[x, y, 0.0], [x + 0.9, y, 0.0], [x, y + 0.9, 0.0]]
+            })
+            .collect()
+    }
+
+    fn bounds(triangle: &[[f32; 3]; 3]) -> Bounds3 {
+        let p = |v: [f32; 3]| Point3::new(v[0], v[1], v[2]);
+        Bounds3::new(p(triangle[0]), p(triangle[1])).union_point(p(triangle[2]))
+    }
+
+    #[test]
+    fn test_packet_matches_single_rays() {
+        let triangles = grid(16);
+        let bvh = Bvh::build(&triangles.iter().map(bounds).collect::<Vec<_>>(), &BvhOptions::default());
+
+        let origins: Vec<[f32; 3]> = (0..LANES)
+            .map(|lane| [lane as f32 * 1.7 + 0.2, lane as f32 * 0.9 + 0.3, 5.0])
+            .collect();
+
+        let mut packet = soa::Ray3::<AVX2> {
+            origin: soa::Point3 {
+                x: Vf32::load_unaligned(&origins.iter().map(|o| o[0]).collect::<Vec<_>>()),
+                y: Vf32::load_unaligned(&origins.iter().map(|o| o[1]).collect::<Vec<_>>()),
+                z: Vf32::splat(5.0),
+            },
+            direction: soa::Vector3 {
+                x: Vf32::splat(0.05),
+                y: Vf32::splat(0.02),
+                z: Vf32::splat(-1.0),
+            },
+            tmax: Vf32::splat(f32::INFINITY),
+        };
+
+        let mut primitives = [u32::MAX; LANES];
+
+        let hit = bvh.intersect_packet(&mut packet, Vf32::one().eq(Vf32::one()), |ray, mask, primitive| {
+            let hit = soa::Triangle::<AVX2>::splat(triangles[primitive as usize]).intersect(ray).mask & mask;
+
+            for (lane, p) in primitives.iter_mut().enumerate() {
+                if hit.select(Vf32::one(), Vf32::zero()).extract(lane) != 0.0 {
+                    *p = primitive;
+                }
+            }
+
+            hit
+        });
+
+        for (lane, origin) in origins.iter().enumerate() {
+            let mut ray = Ray3::new(Point3::new(origin[0], origin[1], origin[2]), Vector3::new(0.05, 0.02, -1.0));
+            let mut primitive = u32::MAX;
+
+            let single_hit = bvh.intersect(&mut ray, |ray, p| {
+                let mut lanes = soa::Ray3::<AVX2>::splat(ray);
+
+                let hit = soa::Triangle::<AVX2>::splat(triangles[p as usize]).intersect(&mut lanes).mask.any();
+                if hit {
+                    ray.tmax = lanes.tmax.extract(0);
+                    primitive = p;
+                }
+                hit
+            });
+
+            assert_eq!(
+                single_hit,
+                hit.select(Vf32::one(), Vf32::zero()).extract(lane) != 0.0,
+                "lane {}",
+                lane
+            );
+            assert_eq!(primitive, primitives[lane], "lane {}", lane);
+
+            if single_hit {
+                assert_eq!(ray.tmax, packet.tmax.extract(lane));
+            }
+        }
+    }
+}
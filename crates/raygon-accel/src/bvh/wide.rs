@@ -0,0 +1,392 @@
+use std::cmp::Ordering;
+
+use thermite::*;
+
+use crate::geometry::{scalar, soa};
+
+use super::{Bvh, BvhStats, MAX_DEPTH};
+
+/// Widest node supported, in children
+pub const MAX_WIDTH: usize = 8;
+
+/// Every child pushed along the deepest path, as each level leaves at most `MAX_WIDTH - 1` siblings behind
+const STACK_SIZE: usize = MAX_DEPTH * (MAX_WIDTH - 1) + 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WideChild {
+    Empty,
+    /// Index of a node in `WideBvh::nodes`
+    Node(u32),
+    /// Range of `WideBvh::indices`
+    Leaf {
+        offset: u32,
+        count: u32,
+    },
+}
+
+/// Node with one child per lane of `S`, whose bounds are tested against a ray all at once
+#[derive(Debug, Clone, Copy)]
+pub struct WideNode<S: Simd> {
+    /// Bounds of each child, with empty boxes in unused lanes
+    pub bounds: soa::Bounds3<S>,
+    pub children: [WideChild; MAX_WIDTH],
+    /// Number of children, which occupy the first lanes
+    pub count: u8,
+    /// Children in front-to-back order for rays in each octant, indexed by the sign bits of the ray direction
+    pub order: [[u8; MAX_WIDTH]; 8],
+}
+
+/// Bounding volume hierarchy with as many children per node as `S` has lanes
+///
+/// Collapsed from a binary [`Bvh`], so that a single slab test visits up to eight binary nodes.
+#[derive(Debug)]
+pub struct WideBvh<S: Simd> {
+    nodes: Vec<WideNode<S>>,
+    indices: Vec<u32>,
+    stats: BvhStats,
+}
+
+struct Collapser<'a, S: Simd> {
+    bvh: &'a Bvh,
+    width: usize,
+    nodes: Vec<WideNode<S>>,
+    leaves: usize,
+    max_depth: usize,
+}
+
+impl<S: Simd> WideBvh<S> {
+    /// Collapses a binary BVH into one with a child per lane of `S`
+    ///
+    /// Each wide node starts from the two children of a binary node, then repeatedly replaces the interior
+    /// child with the largest surface area by its own children until every lane is used. Leaves are kept as
+    /// they are, so the primitive order and SAH cost in the statistics are those of `bvh`.
+    ///
+    /// # Panics
+    ///
+    /// If `S` has fewer than two or more than [`MAX_WIDTH`] lanes
+    pub fn collapse(bvh: &Bvh) -> Self {
+        let width = Vf32::<S>::NUM_ELEMENTS;
+        assert!(
+            (2..=MAX_WIDTH).contains(&width),
+            "wide BVH nodes must have between 2 and {} children",
+            MAX_WIDTH
+        );
+
+        let mut collapser = Collapser {
+            bvh,
+            width,
+            nodes: Vec::with_capacity(bvh.nodes().len() / (width - 1) + 1),
+            leaves: 0,
+            max_depth: 0,
+        };
+
+        if !bvh.nodes().is_empty() {
+            collapser.collapse(0, 0);
+        }
+
+        let mut stats = BvhStats::new();
+        stats.nodes.add((collapser.nodes.len() + collapser.leaves) as u64);
+        stats.leaves.add(collapser.leaves as u64);
+        stats.max_depth.add(collapser.max_depth as u64);
+        stats.sah_cost = bvh.stats().sah_cost;
+
+        WideBvh {
+            nodes: collapser.nodes,
+            indices: bvh.indices().to_vec(),
+            stats,
+        }
+    }
+
+    /// Interior nodes in depth-first order, starting with the root
+    #[inline]
+    pub fn nodes(&self) -> &[WideNode<S>] {
+        &self.nodes
+    }
+
+    /// Primitive indices, in the order leaves refer to them
+    #[inline]
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Statistics, where `nodes` counts leaves as well as wide nodes
+    #[inline]
+    pub fn stats(&self) -> &BvhStats {
+        &self.stats
+    }
+
+    /// Finds the closest hit of a single ray, as with [`Bvh::intersect`]
+    pub fn intersect<F>(&self, ray: &mut scalar::Ray3, mut intersect: F) -> bool
+    where
+        F: FnMut(&mut scalar::Ray3, u32) -> bool,
+    {
+        self.traverse(ray, false, &mut intersect)
+    }
+
+    /// Whether a single ray hits any primitive before `ray.tmax`, as with [`Bvh::occluded`]
+    pub fn occluded<F>(&self, ray: &scalar::Ray3, mut intersect: F) -> bool
+    where
+        F: FnMut(&mut scalar::Ray3, u32) -> bool,
+    {
+        self.traverse(&mut { *ray }, true, &mut intersect)
+    }
+
+    fn traverse<F>(&self, ray: &mut scalar::Ray3, any_hit: bool, intersect: &mut F) -> bool
+    where
+        F: FnMut(&mut scalar::Ray3, u32) -> bool,
+    {
+        if self.nodes.is_empty() {
+            return false;
+        }
+
+        let inv_dir = soa::Ray3::<S>::splat(ray).inv_direction();
+        let octant = (ray.direction.x < 0.0) as usize | ((ray.direction.y < 0.0) as usize) << 1 | ((ray.direction.z < 0.0) as usize) << 2;
+
+        // children to visit, with the ray's entry parameter into their bounds
+        let mut stack = [(WideChild::Empty, 0.0f32); STACK_SIZE];
+        let mut top = 0;
+        let mut current = WideChild::Node(0);
+
+        let (mut visited, mut tested) = (0, 0);
+        let mut hit = false;
+
+        'traversal: loop {
+            match current {
+                WideChild::Node(index) => {
+                    let node = &self.nodes[index as usize];
+                    visited += 1;
+
+                    // re-splatted for every node, since hits shrink tmax
+                    let (mask, t0, _) = node.bounds.intersect_p(&soa::Ray3::splat(ray), &inv_dir);
+
+                    if mask.any() {
+                        let lanes = mask.select(Vf32::<S>::one(), Vf32::<S>::zero());
+
+                        // pushed back to front, so the nearest child is popped first
+                        for &lane in node.order[octant][..node.count as usize].iter().rev() {
+                            let lane = lane as usize;
+
+                            if lanes.extract(lane) != 0.0 {
+                                stack[top] = (node.children[lane], t0.extract(lane));
+                                top += 1;
+                            }
+                        }
+                    }
+                }
+                WideChild::Leaf { offset, count } => {
+                    for &primitive in &self.indices[offset as usize..(offset + count) as usize] {
+                        tested += 1;
+
+                        if intersect(ray, primitive) {
+                            hit = true;
+
+                            if any_hit {
+                                break 'traversal;
+                            }
+                        }
+                    }
+                }
+                WideChild::Empty => {}
+            }
+
+            // skip children that lie entirely behind hits found since they were pushed
+            loop {
+                if top == 0 {
+                    break 'traversal;
+                }
+
+                top -= 1;
+
+                if stack[top].1 <= ray.tmax {
+                    current = stack[top].0;
+                    break;
+                }
+            }
+        }
+
+        self.stats.rays.incr();
+        self.stats.nodes_visited.add(visited);
+        self.stats.primitives_tested.add(tested);
+
+        hit
+    }
+}
+
+impl<S: Simd> Collapser<'_, S> {
+    /// Collapses the subtree under binary node `node` into wide nodes, returning the index of its root
+    fn collapse(&mut self, node: usize, depth: usize) -> u32 {
+        let nodes = self.bvh.nodes();
+
+        let mut children = if nodes[node].is_leaf() {
+            vec![node]
+        } else {
+            vec![node + 1, nodes[node].offset as usize]
+        };
+
+        while children.len() < self.width {
+            let largest = children
+                .iter()
+                .enumerate()
+                .filter(|&(_, &c)| !nodes[c].is_leaf())
+                .max_by(|(_, &a), (_, &b)| {
+                    let (a, b) = (nodes[a].bounds.surface_area(), nodes[b].bounds.surface_area());
+                    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+                })
+                .map(|(i, _)| i);
+
+            match largest {
+                Some(i) => {
+                    let opened = children[i];
+                    children[i] = opened + 1;
+                    children.push(nodes[opened].offset as usize);
+                }
+                None => break,
+            }
+        }
+
+        let bounds: Vec<scalar::Bounds3> = children.iter().map(|&c| nodes[c].bounds).collect();
+
+        let index = self.nodes.len();
+
+        // placeholder until the children are collapsed
+        self.nodes.push(WideNode {
+            bounds: soa::Bounds3::load(&bounds),
+            children: [WideChild::Empty; MAX_WIDTH],
+            count: children.len() as u8,
+            order: octant_order(&bounds),
+        });
+
+        let mut slots = [WideChild::Empty; MAX_WIDTH];
+
+        for (slot, &c) in slots.iter_mut().zip(&children) {
+            let child = &nodes[c];
+
+            *slot = if child.is_leaf() {
+                self.leaves += 1;
+                self.max_depth = self.max_depth.max(depth + 1);
+
+                WideChild::Leaf {
+                    offset: child.offset,
+                    count: child.count,
+                }
+            } else {
+                WideChild::Node(self.collapse(c, depth + 1))
+            };
+        }
+
+        self.nodes[index].children = slots;
+
+        index as u32
+    }
+}
+
+/// For each octant of ray directions, the children sorted by how far along it their centroids lie
+fn octant_order(bounds: &[scalar::Bounds3]) -> [[u8; MAX_WIDTH]; 8] {
+    let mut order = [[0; MAX_WIDTH]; 8];
+
+    for (octant, lanes) in order.iter_mut().enumerate() {
+        let key = |lane: usize| {
+            let c = bounds[lane].centroid();
+
+            (0..3)
+                .map(|axis| if octant & (1 << axis) != 0 { -c[axis] } else { c[axis] })
+                .sum::<f32>()
+        };
+
+        let mut sorted: Vec<usize> = (0..bounds.len()).collect();
+        sorted.sort_by(|&a, &b| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal));
+
+        for (slot, lane) in lanes.iter_mut().zip(sorted) {
+            *slot = lane as u8;
+        }
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::geometry::scalar::{Bounds3, Point3, Ray3, Vector3};
+    use crate::BvhOptions;
+
+    /// Boxes along a line, so rays along it hit them in a known order
+    fn row(n: usize) -> Vec<Bounds3> {
+        (0..n)
+            .map(|i| Bounds3::new(Point3::new(i as f32, 0.0, 0.0), Point3::new(i as f32 + 0.5, 1.0, 1.0)))
+            .collect()
+    }
+
+    #[test]
+    fn test_collapse() {
+        let bounds = row(100);
+        let bvh = Bvh::build(&bounds, &BvhOptions::default());
+        let wide = WideBvh::<AVX2>::collapse(&bvh);
+
+        assert_eq!(wide.stats().leaves.get(), bvh.stats().leaves.get());
+        assert_eq!(
+            wide.stats().nodes.get(),
+            (wide.nodes().len() + bvh.stats().leaves.get() as usize) as u64
+        );
+        assert!(wide.nodes().len() < bvh.nodes().len() / 4);
+
+        // every leaf is reached exactly once
+        let mut primitives: Vec<u32> = wide
+            .nodes()
+            .iter()
+            .flat_map(|node| node.children[..node.count as usize].iter())
+            .flat_map(|child| match *child {
+                WideChild::Leaf { offset, count } => wide.indices()[offset as usize..(offset + count) as usize].to_vec(),
+                _ => Vec::new(),
+            })
+            .collect();
+
+        primitives.sort_unstable();
+        assert!(primitives.iter().copied().eq(0..bounds.len() as u32));
+
+        for node in wide.nodes() {
+            for lane in node.count as usize..<AVX2 as Simd>::Vf32::NUM_ELEMENTS {
+                assert!(node.bounds.extract(lane).is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_front_to_back() {
+        let bounds = row(100);
+        let options = BvhOptions {
+            max_leaf_size: 1,
+            ..BvhOptions::default()
+        };
+        let wide = WideBvh::<AVX2>::collapse(&Bvh::build(&bounds, &options));
+
+        for &(x, dx) in &[(-1.0, 1.0), (101.0, -1.0)] {
+            let mut ray = Ray3::new(Point3::new(x, 0.5, 0.5), Vector3::new(dx, 0.0, 0.0));
+            let mut order = Vec::new();
+
+            // without hits, every box is visited, nearest first
+            wide.intersect(&mut ray, |_, i| {
+                order.push(i);
+                false
+            });
+
+            let mut expected: Vec<u32> = (0..bounds.len() as u32).collect();
+            if dx < 0.0 {
+                expected.reverse();
+            }
+
+            assert_eq!(order, expected);
+
+            // the first hit then culls every other box
+            let mut tested = 0;
+            assert!(wide.intersect(&mut ray, |ray, i| {
+                tested += 1;
+                ray.tmax = (bounds[i as usize].min.x - ray.origin.x).abs();
+                true
+            }));
+            assert_eq!(tested, 1);
+        }
+    }
+}
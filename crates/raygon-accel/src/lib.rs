@@ -0,0 +1,7 @@
+//! Acceleration structures for ray tracing
+
+extern crate raygon_geometry as geometry;
+
+pub mod bvh;
+
+pub use self::bvh::{Bvh, BvhNode, BvhOptions, BvhStats, WideBvh};
@@ -0,0 +1,159 @@
+use thermite::backends::{avx2::AVX2, sse42::SSE42};
+
+use raygon_accel::{Bvh, BvhOptions, WideBvh};
+use raygon_geometry::scalar::{Bounds3, Point3, Ray3, Vector3};
+
+mod common;
+
+use common::*;
+
+fn brute_force(triangles: &[Triangle], ray: &Ray3) -> Option<(u32, f32)> {
+    let mut ray = *ray;
+    let mut closest = None;
+
+    for (i, t) in triangles.iter().enumerate() {
+        if intersect(&mut ray, t) {
+            closest = Some((i as u32, ray.tmax));
+        }
+    }
+
+    closest
+}
+
+#[test]
+fn test_matches_brute_force() {
+    let triangles = random_triangles(2000, 1);
+    let bvh = Bvh::build(&triangles.iter().map(bounds).collect::<Vec<_>>(), &BvhOptions::default());
+
+    let mut hits = 0;
+
+    for ray in random_rays(2000, 2) {
+        let expected = brute_force(&triangles, &ray);
+
+        let mut traced = ray;
+        let mut closest = None;
+
+        let hit = bvh.intersect(&mut traced, |ray, i| {
+            let hit = intersect(ray, &triangles[i as usize]);
+            if hit {
+                closest = Some((i, ray.tmax));
+            }
+            hit
+        });
+
+        assert_eq!(hit, expected.is_some());
+        assert_eq!(closest, expected);
+        assert_eq!(hit, bvh.occluded(&ray, |ray, i| intersect(ray, &triangles[i as usize])));
+
+        hits += hit as usize;
+    }
+
+    // make sure the scene actually exercises both outcomes
+    assert!(hits > 100 && hits < 1900, "{} hits", hits);
+
+    let stats = bvh.stats();
+    assert_eq!(stats.rays.get(), 4000);
+    assert!(stats.primitives_tested.get() < 4000 * triangles.len() as u64 / 10, "{}", stats);
+}
+
+/// Traces `ray` with `trace`, returning the closest triangle hit and the final `tmax`
+fn closest_hit<T>(triangles: &[Triangle], mut ray: Ray3, trace: T) -> (Option<u32>, f32)
+where
+    T: FnOnce(&mut Ray3, &mut dyn FnMut(&mut Ray3, u32) -> bool) -> bool,
+{
+    let mut closest = None;
+
+    trace(&mut ray, &mut |ray, i| {
+        let hit = intersect(ray, &triangles[i as usize]);
+        if hit {
+            closest = Some(i);
+        }
+        hit
+    });
+
+    (closest, ray.tmax)
+}
+
+#[test]
+fn test_wide_matches_binary() {
+    let triangles = random_triangles(2000, 4);
+    let bvh = Bvh::build(&triangles.iter().map(bounds).collect::<Vec<_>>(), &BvhOptions::default());
+
+    let wide4 = WideBvh::<SSE42>::collapse(&bvh);
+    let wide8 = WideBvh::<AVX2>::collapse(&bvh);
+
+    assert!(wide8.nodes().len() < wide4.nodes().len());
+
+    for ray in random_rays(2000, 5) {
+        let expected = closest_hit(&triangles, ray, |ray, f| bvh.intersect(ray, f));
+
+        assert_eq!(closest_hit(&triangles, ray, |ray, f| wide4.intersect(ray, f)), expected);
+        assert_eq!(closest_hit(&triangles, ray, |ray, f| wide8.intersect(ray, f)), expected);
+
+        let occluded = |ray: &mut Ray3, i: u32| intersect(ray, &triangles[i as usize]);
+        assert_eq!(wide8.occluded(&ray, occluded), expected.0.is_some());
+    }
+
+    // each wide node replaces several binary ones, so fewer are visited
+    assert!(wide8.stats().nodes_visited.get() < bvh.stats().nodes_visited.get());
+}
+
+#[test]
+fn test_structure() {
+    let triangles = random_triangles(1000, 3);
+    let bounds: Vec<Bounds3> = triangles.iter().map(bounds).collect();
+
+    let options = BvhOptions::default();
+    let bvh = Bvh::build(&bounds, &options);
+
+    let nodes = bvh.nodes();
+    let stats = bvh.stats();
+
+    assert_eq!(stats.nodes.get(), nodes.len() as u64);
+    assert_eq!(stats.leaves.get(), nodes.iter().filter(|n| n.is_leaf()).count() as u64);
+    assert_eq!(stats.nodes.get(), 2 * stats.leaves.get() - 1);
+    assert!(stats.sah_cost > 0.0 && stats.sah_cost < bounds.len() as f32);
+
+    // every primitive is in exactly one leaf
+    let mut indices = bvh.indices().to_vec();
+    indices.sort_unstable();
+    assert!(indices.iter().copied().eq(0..bounds.len() as u32));
+
+    for (i, node) in nodes.iter().enumerate() {
+        if node.is_leaf() {
+            assert!(node.count as usize <= options.max_leaf_size);
+
+            for &p in bvh.leaf_primitives(node) {
+                assert_eq!(node.bounds.union(&bounds[p as usize]), node.bounds);
+            }
+        } else {
+            for child in &[nodes[i + 1], nodes[node.offset as usize]] {
+                assert_eq!(node.bounds.union(&child.bounds), node.bounds);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_degenerate() {
+    let empty = Bvh::build(&[], &BvhOptions::default());
+    let mut ray = Ray3::new(Point3::origin(), Vector3::new(1.0, 0.0, 0.0));
+
+    assert!(empty.nodes().is_empty());
+    assert!(empty.bounds().is_empty());
+    assert!(!empty.intersect(&mut ray, |_, _| unreachable!()));
+
+    // primitives sharing a centroid can't be separated, so they end up in one leaf
+    let b = Bounds3::new(Point3::new(1.0, -1.0, -1.0), Point3::new(2.0, 1.0, 1.0));
+    let same = Bvh::build(&vec![b; 10], &BvhOptions::default());
+
+    assert_eq!(same.nodes().len(), 1);
+    assert_eq!(same.leaf_primitives(&same.nodes()[0]).len(), 10);
+
+    let mut tested = 0;
+    assert!(!same.intersect(&mut ray, |_, _| {
+        tested += 1;
+        false
+    }));
+    assert_eq!(tested, 10);
+}
@@ -0,0 +1,91 @@
+//! Scenes and reference intersections shared by the BVH tests and benchmarks
+
+#![allow(dead_code)]
+
+use raygon_geometry::scalar::{Bounds3, Point3, Ray3, Vector3};
+
+/// Small deterministic generator, so the scenes don't depend on a sampler
+pub struct Lcg(pub u64);
+
+impl Lcg {
+    pub fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    pub fn point(&mut self, scale: f32) -> Point3 {
+        Point3::new(self.next() * scale, self.next() * scale, self.next() * scale)
+    }
+}
+
+pub type Triangle = [Point3; 3];
+
+/// Triangles with edges of up to `size` scattered through a 10x10x10 box
+pub fn random_triangles_of_size(count: usize, size: f32, seed: u64) -> Vec<Triangle> {
+    let mut rng = Lcg(seed);
+
+    (0..count)
+        .map(|_| {
+            let p = rng.point(10.0);
+            [p, p + rng.point(size).coords(), p + rng.point(size).coords()]
+        })
+        .collect()
+}
+
+pub fn random_triangles(count: usize, seed: u64) -> Vec<Triangle> {
+    random_triangles_of_size(count, 1.0, seed)
+}
+
+/// Rays from around the triangles' box towards points inside it
+pub fn random_rays(count: usize, seed: u64) -> Vec<Ray3> {
+    let mut rng = Lcg(seed);
+
+    (0..count)
+        .map(|_| {
+            let origin = rng.point(14.0) + Vector3::diag(-2.0);
+            let target = rng.point(10.0);
+            Ray3::new(origin, (target - origin).normalize())
+        })
+        .collect()
+}
+
+pub fn bounds(t: &Triangle) -> Bounds3 {
+    Bounds3::new(t[0], t[1]).union_point(t[2])
+}
+
+/// Möller-Trumbore, shrinking `ray.tmax` on a hit
+pub fn intersect(ray: &mut Ray3, t: &Triangle) -> bool {
+    let e1 = t[1] - t[0];
+    let e2 = t[2] - t[0];
+
+    let p = ray.direction.cross(&e2);
+    let det = e1.dot(&p);
+
+    if det.abs() < 1e-9 {
+        return false;
+    }
+
+    let inv_det = 1.0 / det;
+    let s = ray.origin - t[0];
+    let u = s.dot(&p) * inv_det;
+
+    if !(0.0..=1.0).contains(&u) {
+        return false;
+    }
+
+    let q = s.cross(&e1);
+    let v = ray.direction.dot(&q) * inv_det;
+
+    if v < 0.0 || u + v > 1.0 {
+        return false;
+    }
+
+    let dist = e2.dot(&q) * inv_det;
+
+    if dist > 0.0 && dist < ray.tmax {
+        ray.tmax = dist;
+        return true;
+    }
+
+    false
+}
@@ -0,0 +1,221 @@
+use crate::math::scalar::gamma;
+
+use super::{Point3, Ray3, Transform3, Vector3};
+
+/// Axis-aligned bounding box
+///
+/// The empty box has `min` at positive infinity and `max` at negative infinity,
+/// so taking its union with anything gives the other operand.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Default for Bounds3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Bounds3::empty()
+    }
+}
+
+impl Bounds3 {
+    #[inline(always)]
+    pub fn empty() -> Self {
+        Bounds3 {
+            min: Point3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+            max: Point3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+        }
+    }
+
+    /// Smallest box containing both `a` and `b`, in any order
+    #[inline(always)]
+    pub fn new(a: Point3, b: Point3) -> Self {
+        Bounds3 {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    #[inline(always)]
+    pub fn from_point(p: Point3) -> Self {
+        Bounds3 { min: p, max: p }
+    }
+
+    #[inline(always)]
+    pub fn union(&self, other: &Self) -> Self {
+        Bounds3 {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    #[inline(always)]
+    pub fn union_point(&self, p: Point3) -> Self {
+        Bounds3 {
+            min: self.min.min(p),
+            max: self.max.max(p),
+        }
+    }
+
+    /// Overlap of both boxes, which is empty if they don't overlap
+    #[inline(always)]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Bounds3 {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    #[inline(always)]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Whether `p` is inside the box or on its boundary
+    #[inline(always)]
+    pub fn contains(&self, p: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y) && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    #[inline(always)]
+    pub fn diagonal(&self) -> Vector3 {
+        self.max - self.min
+    }
+
+    #[inline(always)]
+    pub fn centroid(&self) -> Point3 {
+        self.min + self.diagonal() * Vector3::diag(0.5)
+    }
+
+    /// Surface area, or zero for the empty box
+    #[inline]
+    pub fn surface_area(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let d = self.diagonal();
+        2.0 * (d.x * d.y + d.x * d.z + d.y * d.z)
+    }
+
+    /// Volume, or zero for the empty box
+    #[inline]
+    pub fn volume(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let d = self.diagonal();
+        d.x * d.y * d.z
+    }
+
+    /// Axis along which the box is widest
+    #[inline]
+    pub fn max_extent(&self) -> usize {
+        let d = self.diagonal();
+
+        if d.x > d.y && d.x > d.z {
+            0
+        } else if d.y > d.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Position of `p` relative to the box, from zero at `min` to one at `max` in each dimension
+    #[inline]
+    pub fn offset(&self, p: Point3) -> Vector3 {
+        let mut o = p - self.min;
+
+        if self.max.x > self.min.x {
+            o.x /= self.max.x - self.min.x;
+        }
+        if self.max.y > self.min.y {
+            o.y /= self.max.y - self.min.y;
+        }
+        if self.max.z > self.min.z {
+            o.z /= self.max.z - self.min.z;
+        }
+
+        o
+    }
+
+    /// One of the eight corners, with bit `k` of `index` choosing `max` over `min` in dimension `k`
+    #[inline]
+    pub fn corner(&self, index: usize) -> Point3 {
+        Point3::new(
+            if index & 1 == 0 { self.min.x } else { self.max.x },
+            if index & 2 == 0 { self.min.y } else { self.max.y },
+            if index & 4 == 0 { self.min.z } else { self.max.z },
+        )
+    }
+
+    /// Smallest axis-aligned box containing the transformed box
+    ///
+    /// Uses Arvo's method, which is exact for affine transforms. The empty box stays empty.
+    #[inline]
+    pub fn transform(&self, transform: &Transform3) -> Self {
+        if self.is_empty() {
+            return Bounds3::empty();
+        }
+
+        let m = &transform.forward;
+
+        let mut lo = Point3::new(m[0][3], m[1][3], m[2][3]);
+        let mut hi = lo;
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let a = m[i][j] * self.min[j];
+                let b = m[i][j] * self.max[j];
+
+                lo[i] += a.min(b);
+                hi[i] += a.max(b);
+            }
+        }
+
+        Bounds3 { min: lo, max: hi }
+    }
+
+    /// Slab test of `ray` against the box, returning the ray's entry and exit parameters clipped to `[0, ray.tmax]`
+    ///
+    /// `inv_dir` is `ray.inv_direction()`. The exit parameter is enlarged by `2 gamma(3)` to cover rounding
+    /// error, so rays are never falsely reported as missing. Empty boxes are never hit.
+    #[inline]
+    pub fn intersect_p(&self, ray: &Ray3, inv_dir: &Vector3) -> Option<(f32, f32)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut t0 = 0.0f32;
+        let mut t1 = ray.tmax;
+
+        for axis in 0..3 {
+            let mut near = (self.min[axis] - ray.origin[axis]) * inv_dir[axis];
+            let mut far = (self.max[axis] - ray.origin[axis]) * inv_dir[axis];
+
+            if near > far {
+                std::mem::swap(&mut near, &mut far);
+            }
+
+            far *= 1.0 + 2.0 * gamma(3);
+
+            // written so NaN, from rays in the plane of a slab, leaves the interval unchanged
+            t0 = if near > t0 { near } else { t0 };
+            t1 = if far < t1 { far } else { t1 };
+
+            if t0 > t1 {
+                return None;
+            }
+        }
+
+        Some((t0, t1))
+    }
+}
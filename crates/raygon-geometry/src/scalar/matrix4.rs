@@ -0,0 +1,159 @@
+use std::ops::*;
+
+use crate::math::scalar::*;
+
+use super::{Point3, Vector3};
+
+/// Row-major 4x4 matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix4(pub [[f32; 4]; 4]);
+
+impl Deref for Matrix4 {
+    type Target = [[f32; 4]; 4];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Matrix4 {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for Matrix4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Matrix4::identity()
+    }
+}
+
+impl Matrix4 {
+    #[inline(always)]
+    pub fn identity() -> Self {
+        Matrix4([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[inline(always)]
+    pub fn transpose(self) -> Self {
+        let m = self;
+
+        Matrix4([
+            [m[0][0], m[1][0], m[2][0], m[3][0]],
+            [m[0][1], m[1][1], m[2][1], m[3][1]],
+            [m[0][2], m[1][2], m[2][2], m[3][2]],
+            [m[0][3], m[1][3], m[2][3], m[3][3]],
+        ])
+    }
+
+    /// Inverse, or `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Matrix4> {
+        let m = self;
+
+        let s0 = difference_of_products(m[0][0], m[1][1], m[1][0], m[0][1]);
+        let s1 = difference_of_products(m[0][0], m[1][2], m[1][0], m[0][2]);
+        let s2 = difference_of_products(m[0][0], m[1][3], m[1][0], m[0][3]);
+
+        let s3 = difference_of_products(m[0][1], m[1][2], m[1][1], m[0][2]);
+        let s4 = difference_of_products(m[0][1], m[1][3], m[1][1], m[0][3]);
+        let s5 = difference_of_products(m[0][2], m[1][3], m[1][2], m[0][3]);
+
+        let c0 = difference_of_products(m[2][0], m[3][1], m[3][0], m[2][1]);
+        let c1 = difference_of_products(m[2][0], m[3][2], m[3][0], m[2][2]);
+        let c2 = difference_of_products(m[2][0], m[3][3], m[3][0], m[2][3]);
+
+        let c3 = difference_of_products(m[2][1], m[3][2], m[3][1], m[2][2]);
+        let c4 = difference_of_products(m[2][1], m[3][3], m[3][1], m[2][3]);
+        let c5 = difference_of_products(m[2][2], m[3][3], m[3][2], m[2][3]);
+
+        let det = s0.mul_add(c5, s2.mul_add(c3, -s1 * c4)) + s3.mul_add(c2, s5.mul_add(c0, -s4 * c1));
+
+        if det == 0.0 {
+            return None;
+        }
+
+        let s = 1.0 / det;
+
+        let dot3 = |a: f32, b: f32, c: f32, d: f32, e: f32, f: f32| s * a.mul_add(b, c.mul_add(d, e * f));
+
+        Some(Matrix4([
+            [
+                dot3(m[1][1], c5, m[1][3], c3, -m[1][2], c4),
+                dot3(-m[0][1], c5, m[0][2], c4, -m[0][3], c3),
+                dot3(m[3][1], s5, m[3][3], s3, -m[3][2], s4),
+                dot3(-m[2][1], s5, m[2][2], s4, -m[2][3], s3),
+            ],
+            [
+                dot3(-m[1][0], c5, m[1][2], c2, -m[1][3], c1),
+                dot3(m[0][0], c5, m[0][3], c1, -m[0][2], c2),
+                dot3(-m[3][0], s5, m[3][2], s2, -m[3][3], s1),
+                dot3(m[2][0], s5, m[2][3], s1, -m[2][2], s2),
+            ],
+            [
+                dot3(m[1][0], c4, m[1][3], c0, -m[1][1], c2),
+                dot3(-m[0][0], c4, m[0][1], c2, -m[0][3], c0),
+                dot3(m[3][0], s4, m[3][3], s0, -m[3][1], s2),
+                dot3(-m[2][0], s4, m[2][1], s2, -m[2][3], s0),
+            ],
+            [
+                dot3(-m[1][0], c3, m[1][1], c1, -m[1][2], c0),
+                dot3(m[0][0], c3, m[0][2], c0, -m[0][1], c1),
+                dot3(-m[3][0], s3, m[3][1], s1, -m[3][2], s0),
+                dot3(m[2][0], s3, m[2][2], s0, -m[2][1], s1),
+            ],
+        ]))
+    }
+}
+
+impl Mul for &Matrix4 {
+    type Output = Matrix4;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Matrix4 {
+        let mut res = Matrix4([[0.0; 4]; 4]);
+
+        for i in 0..4 {
+            for j in 0..4 {
+                res[i][j] = self[i][0].mul_add(rhs[0][j], self[i][1] * rhs[1][j]) + self[i][2].mul_add(rhs[2][j], self[i][3] * rhs[3][j]);
+            }
+        }
+
+        res
+    }
+}
+
+impl Mul<Point3> for &Matrix4 {
+    type Output = Point3;
+
+    #[inline]
+    fn mul(self, rhs: Point3) -> Point3 {
+        let m = self;
+
+        let row = |i: usize| m[i][0].mul_add(rhs.x, m[i][1].mul_add(rhs.y, m[i][2].mul_add(rhs.z, m[i][3])));
+
+        let w = row(3);
+
+        Point3::new(row(0) / w, row(1) / w, row(2) / w)
+    }
+}
+
+impl Mul<Vector3> for &Matrix4 {
+    type Output = Vector3;
+
+    #[inline]
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        let m = self;
+
+        let row = |i: usize| m[i][0].mul_add(rhs.x, m[i][1].mul_add(rhs.y, m[i][2] * rhs.z));
+
+        Vector3::new(row(0), row(1), row(2))
+    }
+}
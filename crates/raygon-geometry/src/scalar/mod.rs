@@ -1 +1,11 @@
-pub mod vector3;
\ No newline at end of file
+pub mod bounds3;
+pub mod matrix4;
+pub mod point3;
+pub mod quaternion;
+pub mod ray3;
+pub mod transform3;
+pub mod vector3;
+
+pub use self::{
+    bounds3::Bounds3, matrix4::Matrix4, point3::Point3, quaternion::Quaternion, ray3::Ray3, transform3::Transform3, vector3::Vector3,
+};
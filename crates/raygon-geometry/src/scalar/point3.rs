@@ -0,0 +1,133 @@
+use std::ops::*;
+
+use super::Vector3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Point3 {
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[inline(always)]
+    pub fn origin() -> Self {
+        Point3::new(0.0, 0.0, 0.0)
+    }
+
+    #[inline(always)]
+    pub fn coords(self) -> Vector3 {
+        let Point3 { x, y, z } = self;
+        Vector3 { x, y, z }
+    }
+
+    #[inline(always)]
+    pub fn distance_squared(self, other: Self) -> f32 {
+        (self - other).norm_squared()
+    }
+
+    #[inline(always)]
+    pub fn distance(self, other: Self) -> f32 {
+        (self - other).norm()
+    }
+
+    /// Componentwise minimum
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        Point3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    /// Componentwise maximum
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        Point3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+
+    /// `self` at `t = 0` to `other` at `t = 1`
+    #[inline(always)]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Index<usize> for Point3 {
+    type Output = f32;
+
+    #[inline(always)]
+    fn index(&self, axis: usize) -> &f32 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Point3 axis {} out of range", axis),
+        }
+    }
+}
+
+impl IndexMut<usize> for Point3 {
+    #[inline(always)]
+    fn index_mut(&mut self, axis: usize) -> &mut f32 {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Point3 axis {} out of range", axis),
+        }
+    }
+}
+
+impl Sub<Point3> for Point3 {
+    type Output = Vector3;
+
+    #[inline(always)]
+    fn sub(self, rhs: Point3) -> Vector3 {
+        Vector3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Add<Vector3> for Point3 {
+    type Output = Point3;
+
+    #[inline(always)]
+    fn add(mut self, rhs: Vector3) -> Point3 {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self
+    }
+}
+
+impl Sub<Vector3> for Point3 {
+    type Output = Point3;
+
+    #[inline(always)]
+    fn sub(mut self, rhs: Vector3) -> Point3 {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self
+    }
+}
+
+impl AddAssign<Vector3> for Point3 {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Vector3) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Vector3> for Point3 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Vector3) {
+        *self = *self - rhs;
+    }
+}
@@ -0,0 +1,249 @@
+use std::ops::*;
+
+use crate::math::scalar::*;
+
+use super::{Matrix4, Transform3, Vector3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Default for Quaternion {
+    #[inline(always)]
+    fn default() -> Self {
+        Quaternion::identity()
+    }
+}
+
+impl Quaternion {
+    #[inline(always)]
+    pub fn identity() -> Self {
+        Quaternion {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn dot(&self, other: &Self) -> f32 {
+        sum_of_products(self.x, other.x, self.y, other.y) + sum_of_products(self.z, other.z, self.w, other.w)
+    }
+
+    #[inline(always)]
+    pub fn normalize(self) -> Self {
+        self.scale(1.0 / self.dot(&self).sqrt())
+    }
+
+    #[inline(always)]
+    fn scale(self, s: f32) -> Self {
+        Quaternion {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: self.w * s,
+        }
+    }
+
+    #[inline(always)]
+    pub fn lerp(self, other: &Self, t: f32) -> Self {
+        Quaternion {
+            x: t.mul_add(other.x - self.x, self.x),
+            y: t.mul_add(other.y - self.y, self.y),
+            z: t.mul_add(other.z - self.z, self.z),
+            w: t.mul_add(other.w - self.w, self.w),
+        }
+    }
+
+    #[inline]
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let cos_theta = self.dot(other);
+
+        if cos_theta > 0.9995 {
+            return self.lerp(other, t).normalize();
+        }
+
+        let theta = cos_theta.clamp(-1.0, 1.0).acos();
+
+        let qperp = Quaternion {
+            x: self.x.mul_add(-cos_theta, other.x),
+            y: self.y.mul_add(-cos_theta, other.y),
+            z: self.z.mul_add(-cos_theta, other.z),
+            w: self.w.mul_add(-cos_theta, other.w),
+        }
+        .normalize();
+
+        let (s, c) = (theta * t).sin_cos();
+
+        Quaternion {
+            x: sum_of_products(self.x, c, qperp.x, s),
+            y: sum_of_products(self.y, c, qperp.y, s),
+            z: sum_of_products(self.z, c, qperp.z, s),
+            w: sum_of_products(self.w, c, qperp.w, s),
+        }
+    }
+
+    /// Rotation by `|axis_angle|` radians counter-clockwise about `axis_angle`
+    #[inline]
+    pub fn from_axis_angle(axis_angle: Vector3) -> Self {
+        let angle = axis_angle.norm();
+
+        if angle == 0.0 {
+            return Quaternion::identity();
+        }
+
+        let (s, c) = (angle * 0.5).sin_cos();
+        let axis = axis_angle * (s / angle);
+
+        Quaternion {
+            x: axis.x,
+            y: axis.y,
+            z: axis.z,
+            w: c,
+        }
+    }
+
+    /// Axis scaled by the angle of rotation in radians, which is in `[0, pi]`, assuming a unit quaternion
+    #[inline]
+    pub fn to_axis_angle(&self) -> Vector3 {
+        // q and -q are the same rotation, so pick the one with the shorter angle
+        let q = if self.w < 0.0 { self.scale(-1.0) } else { *self };
+
+        let v = Vector3::new(q.x, q.y, q.z);
+        let s = v.norm();
+
+        if s == 0.0 {
+            return v;
+        }
+
+        v * (2.0 * s.atan2(q.w) / s)
+    }
+
+    /// Inverse of unit quaternions
+    #[inline(always)]
+    pub fn conjugate(&self) -> Self {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Inverse of any non-zero quaternion
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        self.conjugate().scale(1.0 / self.dot(self))
+    }
+
+    /// Rotates `v`, assuming a unit quaternion
+    #[inline]
+    pub fn rotate(&self, v: Vector3) -> Vector3 {
+        let q = Vector3::new(self.x, self.y, self.z);
+        let t = q.cross(&v) * 2.0;
+
+        v + t * self.w + q.cross(&t)
+    }
+
+    /// Rotation matrix, assuming a unit quaternion
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix4 {
+        let Quaternion { x, y, z, w } = *self;
+
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        Matrix4([
+            [1.0 - 2.0 * (yy + zz), 2.0 * (xy - wz), 2.0 * (xz + wy), 0.0],
+            [2.0 * (xy + wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz - wx), 0.0],
+            [2.0 * (xz - wy), 2.0 * (yz + wx), 1.0 - 2.0 * (xx + yy), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Rotation transform, with the transposed matrix as its inverse
+    #[inline]
+    pub fn to_transform(&self) -> Transform3 {
+        let forward = self.to_matrix();
+
+        Transform3 {
+            forward,
+            inverse: forward.transpose(),
+        }
+    }
+
+    /// Rotation of the upper-left 3x3 part of `m`, which must be a rotation matrix
+    #[inline]
+    pub fn from_matrix(m: &Matrix4) -> Self {
+        let (m00, m11, m22) = (m[0][0], m[1][1], m[2][2]);
+        let trace = m00 + m11 + m22;
+
+        // work from the largest component, which keeps the division well away from zero
+        if trace >= m00 && trace >= m11 && trace >= m22 {
+            let four_w = 2.0 * (trace + 1.0).sqrt();
+
+            Quaternion {
+                x: (m[2][1] - m[1][2]) / four_w,
+                y: (m[0][2] - m[2][0]) / four_w,
+                z: (m[1][0] - m[0][1]) / four_w,
+                w: four_w * 0.25,
+            }
+        } else if m00 >= m11 && m00 >= m22 {
+            let four_x = 2.0 * (1.0 + m00 - m11 - m22).sqrt();
+
+            Quaternion {
+                x: four_x * 0.25,
+                y: (m[0][1] + m[1][0]) / four_x,
+                z: (m[0][2] + m[2][0]) / four_x,
+                w: (m[2][1] - m[1][2]) / four_x,
+            }
+        } else if m11 >= m22 {
+            let four_y = 2.0 * (1.0 + m11 - m00 - m22).sqrt();
+
+            Quaternion {
+                x: (m[0][1] + m[1][0]) / four_y,
+                y: four_y * 0.25,
+                z: (m[1][2] + m[2][1]) / four_y,
+                w: (m[0][2] - m[2][0]) / four_y,
+            }
+        } else {
+            let four_z = 2.0 * (1.0 + m22 - m00 - m11).sqrt();
+
+            Quaternion {
+                x: (m[0][2] + m[2][0]) / four_z,
+                y: (m[1][2] + m[2][1]) / four_z,
+                z: four_z * 0.25,
+                w: (m[1][0] - m[0][1]) / four_z,
+            }
+        }
+    }
+
+    /// Rotation of `transform`, which must be a pure rotation
+    #[inline(always)]
+    pub fn from_transform(transform: &Transform3) -> Self {
+        Self::from_matrix(&transform.forward)
+    }
+}
+
+/// Hamilton product, which rotates by `rhs` and then by `self`
+impl Mul for Quaternion {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+
+        Quaternion {
+            x: sum_of_products(a.w, b.x, a.x, b.w) + difference_of_products(a.y, b.z, a.z, b.y),
+            y: sum_of_products(a.w, b.y, a.y, b.w) + difference_of_products(a.z, b.x, a.x, b.z),
+            z: sum_of_products(a.w, b.z, a.z, b.w) + difference_of_products(a.x, b.y, a.y, b.x),
+            w: difference_of_products(a.w, b.w, a.x, b.x) - sum_of_products(a.y, b.y, a.z, b.z),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::{Point3, Vector3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray3 {
+    pub origin: Point3,
+    pub direction: Vector3,
+    pub tmax: f32,
+}
+
+impl Ray3 {
+    /// Creates a ray with no limit on its extent
+    #[inline(always)]
+    pub fn new(origin: Point3, direction: Vector3) -> Self {
+        Ray3 {
+            origin,
+            direction,
+            tmax: f32::INFINITY,
+        }
+    }
+
+    #[inline(always)]
+    pub fn at(&self, t: f32) -> Point3 {
+        self.origin + self.direction * Vector3::diag(t)
+    }
+
+    /// Reciprocal of each direction component, for slab tests against bounding boxes
+    #[inline(always)]
+    pub fn inv_direction(&self) -> Vector3 {
+        Vector3::new(1.0 / self.direction.x, 1.0 / self.direction.y, 1.0 / self.direction.z)
+    }
+}
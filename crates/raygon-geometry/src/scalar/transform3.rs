@@ -0,0 +1,251 @@
+use std::ops::*;
+
+use super::{Bounds3, Matrix4, Point3, Quaternion, Ray3, Vector3};
+
+/// Affine or projective transform, with its inverse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3 {
+    pub forward: Matrix4,
+    pub inverse: Matrix4,
+}
+
+impl Deref for Transform3 {
+    type Target = Matrix4;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.forward
+    }
+}
+
+impl Default for Transform3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Transform3::identity()
+    }
+}
+
+impl Transform3 {
+    #[inline(always)]
+    pub fn identity() -> Self {
+        Transform3 {
+            forward: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+        }
+    }
+
+    /// Transform by `forward`, or `None` if it can't be inverted
+    #[inline]
+    pub fn from_forward(forward: Matrix4) -> Option<Self> {
+        forward.inverse().map(|inverse| Transform3 { forward, inverse })
+    }
+
+    /// Moves by `delta`
+    #[inline]
+    pub fn translate(delta: &Vector3) -> Self {
+        let by = |d: Vector3| {
+            Matrix4([
+                [1.0, 0.0, 0.0, d.x],
+                [0.0, 1.0, 0.0, d.y],
+                [0.0, 0.0, 1.0, d.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ])
+        };
+
+        Transform3 {
+            forward: by(*delta),
+            inverse: by(-*delta),
+        }
+    }
+
+    /// Scales each axis, or `None` if any of them would be flattened
+    #[inline]
+    pub fn scale(axis: &Vector3) -> Option<Self> {
+        if axis.x * axis.y * axis.z == 0.0 {
+            return None;
+        }
+
+        let by = |s: Vector3| {
+            Matrix4([
+                [s.x, 0.0, 0.0, 0.0],
+                [0.0, s.y, 0.0, 0.0],
+                [0.0, 0.0, s.z, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ])
+        };
+
+        Some(Transform3 {
+            forward: by(*axis),
+            inverse: by(Vector3::diag(1.0) / *axis),
+        })
+    }
+
+    /// Rotation by `|axis_angle|` radians counter-clockwise about `axis_angle`
+    #[inline]
+    pub fn rotate(axis_angle: Vector3) -> Self {
+        Quaternion::from_axis_angle(axis_angle).to_transform()
+    }
+
+    /// World-to-camera transform for a camera at `position` looking at `target`, with `up` roughly above it
+    ///
+    /// The camera looks down its `+z` axis, with `+y` up and `+x` to the right. Returns `None` if `target`
+    /// coincides with `position` or `up` is parallel to the view direction.
+    pub fn look_at(position: Point3, target: Point3, up: &Vector3) -> Option<Self> {
+        let dir = target - position;
+        let right = up.cross(&dir);
+
+        if dir.norm_squared() == 0.0 || right.norm_squared() == 0.0 {
+            return None;
+        }
+
+        let dir = dir.normalize();
+        let right = up.cross(&dir).normalize();
+        let new_up = dir.cross(&right);
+
+        let p = position.coords();
+
+        Some(Transform3 {
+            forward: Matrix4([
+                [right.x, right.y, right.z, -right.dot(&p)],
+                [new_up.x, new_up.y, new_up.z, -new_up.dot(&p)],
+                [dir.x, dir.y, dir.z, -dir.dot(&p)],
+                [0.0, 0.0, 0.0, 1.0],
+            ]),
+            inverse: Matrix4([
+                [right.x, new_up.x, dir.x, p.x],
+                [right.y, new_up.y, dir.y, p.y],
+                [right.z, new_up.z, dir.z, p.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ]),
+        })
+    }
+
+    /// Perspective projection of camera space, looking down `+z`, with a vertical field of view of `fov` radians
+    ///
+    /// Points on the near and far planes are mapped to `z = 0` and `z = 1`, and the edges of the field of view to
+    /// `x, y = ±1`. Returns `None` if the field of view is outside `(0, pi)` or `0 < near < far` doesn't hold.
+    pub fn perspective(fov: f32, near: f32, far: f32) -> Option<Self> {
+        if !(fov > 0.0 && fov < std::f32::consts::PI && near > 0.0 && far > near) {
+            return None;
+        }
+
+        let tan = (fov * 0.5).tan();
+        let depth = far - near;
+
+        Some(Transform3 {
+            forward: Matrix4([
+                [1.0 / tan, 0.0, 0.0, 0.0],
+                [0.0, 1.0 / tan, 0.0, 0.0],
+                [0.0, 0.0, far / depth, -(far * near) / depth],
+                [0.0, 0.0, 1.0, 0.0],
+            ]),
+            inverse: Matrix4([
+                [tan, 0.0, 0.0, 0.0],
+                [0.0, tan, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+                [0.0, 0.0, -depth / (far * near), 1.0 / near],
+            ]),
+        })
+    }
+
+    /// Orthographic projection of camera space, mapping `z` in `[near, far]` to `[0, 1]` and leaving `x` and `y` as they are
+    ///
+    /// Returns `None` if `near` and `far` are equal.
+    pub fn orthographic(near: f32, far: f32) -> Option<Self> {
+        let depth = far - near;
+
+        if depth == 0.0 {
+            return None;
+        }
+
+        Some(Transform3 {
+            forward: Matrix4([
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0 / depth, -near / depth],
+                [0.0, 0.0, 0.0, 1.0],
+            ]),
+            inverse: Matrix4([
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, depth, near],
+                [0.0, 0.0, 0.0, 1.0],
+            ]),
+        })
+    }
+
+    /// The opposite transform, without recomputing any inverse
+    #[inline(always)]
+    pub fn invert(&self) -> Self {
+        Transform3 {
+            forward: self.inverse,
+            inverse: self.forward,
+        }
+    }
+
+    /// Transforms a surface normal by the inverse transpose, keeping it perpendicular to transformed tangents
+    ///
+    /// The result is not renormalized.
+    #[inline]
+    pub fn normal(&self, n: Vector3) -> Vector3 {
+        let m = &self.inverse;
+
+        let column = |j: usize| m[0][j].mul_add(n.x, m[1][j].mul_add(n.y, m[2][j] * n.z));
+
+        Vector3::new(column(0), column(1), column(2))
+    }
+}
+
+/// Applies `rhs` first, then `self`
+impl Mul for &Transform3 {
+    type Output = Transform3;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Transform3 {
+        Transform3 {
+            forward: &self.forward * &rhs.forward,
+            inverse: &rhs.inverse * &self.inverse,
+        }
+    }
+}
+
+impl Mul<Point3> for &Transform3 {
+    type Output = Point3;
+
+    #[inline(always)]
+    fn mul(self, rhs: Point3) -> Point3 {
+        &self.forward * rhs
+    }
+}
+
+impl Mul<Vector3> for &Transform3 {
+    type Output = Vector3;
+
+    #[inline(always)]
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        &self.forward * rhs
+    }
+}
+
+/// Transforms the origin and direction, without renormalizing the direction, so `tmax` and hit distances are unchanged
+impl Mul<Ray3> for &Transform3 {
+    type Output = Ray3;
+
+    #[inline(always)]
+    fn mul(self, rhs: Ray3) -> Ray3 {
+        Ray3 {
+            origin: &self.forward * rhs.origin,
+            direction: &self.forward * rhs.direction,
+            tmax: rhs.tmax,
+        }
+    }
+}
+
+impl Mul<Bounds3> for &Transform3 {
+    type Output = Bounds3;
+
+    #[inline(always)]
+    fn mul(self, rhs: Bounds3) -> Bounds3 {
+        rhs.transform(self)
+    }
+}
@@ -4,12 +4,17 @@ use crate::math::scalar::*;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vector3 {
-    x: f32,
-    y: f32,
-    z: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
 }
 
 impl Vector3 {
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Vector3 { x, y, z }
+    }
+
     /// Creates a vector where all dimensions are the same value
     #[inline(always)]
     pub fn diag(xyz: f32) -> Self {
@@ -40,6 +45,11 @@ impl Vector3 {
         self.norm_squared().sqrt()
     }
 
+    #[inline(always)]
+    pub fn normalize(self) -> Self {
+        self * Self::diag(1.0 / self.norm())
+    }
+
     #[inline(always)]
     pub fn cross(&self, other: &Self) -> Self {
         Vector3 {
@@ -53,6 +63,50 @@ impl Vector3 {
     pub fn face_forward(self, v: &Self) -> Self {
         self * Self::diag(self.dot(v).signum())
     }
+
+    /// Componentwise minimum
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        Vector3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    /// Componentwise maximum
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        Vector3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+
+    /// `self` at `t = 0` to `other` at `t = 1`
+    #[inline(always)]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Index<usize> for Vector3 {
+    type Output = f32;
+
+    #[inline(always)]
+    fn index(&self, axis: usize) -> &f32 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vector3 axis {} out of range", axis),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector3 {
+    #[inline(always)]
+    fn index_mut(&mut self, axis: usize) -> &mut f32 {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vector3 axis {} out of range", axis),
+        }
+    }
 }
 
 impl Neg for Vector3 {
@@ -78,3 +132,108 @@ impl Mul<Vector3> for Vector3 {
         self
     }
 }
+
+impl Add<Vector3> for Vector3 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(mut self, rhs: Self) -> Self {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self
+    }
+}
+
+impl Sub<Vector3> for Vector3 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(mut self, rhs: Self) -> Self {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self
+    }
+}
+
+impl Div<Vector3> for Vector3 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(mut self, rhs: Self) -> Self {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+        self
+    }
+}
+
+impl Mul<f32> for Vector3 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: f32) -> Self {
+        self * Vector3::diag(rhs)
+    }
+}
+
+impl Mul<Vector3> for f32 {
+    type Output = Vector3;
+
+    #[inline(always)]
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        rhs * self
+    }
+}
+
+impl Div<f32> for Vector3 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: f32) -> Self {
+        self * (1.0 / rhs)
+    }
+}
+
+impl AddAssign<Vector3> for Vector3 {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Vector3) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Vector3> for Vector3 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Vector3) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<Vector3> for Vector3 {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Vector3) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f32> for Vector3 {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<Vector3> for Vector3 {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: Vector3) {
+        *self = *self / rhs;
+    }
+}
+
+impl DivAssign<f32> for Vector3 {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,412 @@
+use thermite::*;
+
+use crate::math::soa::*;
+
+use super::{Bounds3, Matrix4, Point3, Quaternion, Transform3, Vector3};
+
+/// Most iterations of the polar decomposition, which usually converges in a handful
+const MAX_POLAR_ITERATIONS: usize = 100;
+
+/// Number of pieces the shutter interval is split into when bounding motion
+const MOTION_BOUND_STEPS: usize = 8;
+
+/// Transform moving between two keyframes over a time interval, for motion blur
+///
+/// Each keyframe is decomposed into a translation, a rotation and a remaining scale (and shear), which are interpolated
+/// separately so that rotating objects keep their shape in between, unlike interpolating the matrices directly.
+/// Every lane has its own keyframes and times.
+#[derive(Debug, Clone, Copy)]
+pub struct AnimatedTransform<S: Simd> {
+    pub start_time: Vf32<S>,
+    pub end_time: Vf32<S>,
+
+    start: Transform3<S>,
+    end: Transform3<S>,
+    translation: [Vector3<S>; 2],
+    rotation: [Quaternion<S>; 2],
+    scale: [Matrix4<S>; 2],
+}
+
+/// Splits the affine `m` into its translation, rotation and what remains of it after rotating back
+///
+/// The rotation is the orthogonal factor of the polar decomposition of the upper-left 3x3 part, found by averaging the
+/// matrix with its inverse transpose until it stops changing. Any reflection is left in the remaining part, so the
+/// rotation is always proper.
+#[inline]
+fn decompose<S: Simd>(m: &Matrix4<S>) -> (Vector3<S>, Quaternion<S>, Matrix4<S>) {
+    let zero = Vf32::<S>::zero();
+    let one = Vf32::<S>::one();
+    let half = Vf32::<S>::splat(0.5);
+
+    let translation = Vector3 {
+        x: m[0][3],
+        y: m[1][3],
+        z: m[2][3],
+    };
+
+    let mut r = *m;
+
+    for i in 0..3 {
+        r[i][3] = zero;
+        r[3][i] = zero;
+    }
+    r[3][3] = one;
+
+    for _ in 0..MAX_POLAR_ITERATIONS {
+        let inverse_transpose = r.inverse().0.transpose();
+
+        let mut next = r;
+        let mut change = zero;
+
+        for i in 0..3 {
+            for j in 0..3 {
+                next[i][j] = half * (r[i][j] + inverse_transpose[i][j]);
+                change = change.max((next[i][j] - r[i][j]).abs());
+            }
+        }
+
+        r = next;
+
+        if change.lt(Vf32::<S>::splat(1e-6)).all() {
+            break;
+        }
+    }
+
+    // mirrored keyframes give a reflection rather than a rotation, so flip it, leaving the reflection to the scale
+    let row = |i: usize| Vector3::<S> {
+        x: r[i][0],
+        y: r[i][1],
+        z: r[i][2],
+    };
+    let mirrored = row(0).dot(&row(1).cross(&row(2))).lt(zero);
+
+    for i in 0..3 {
+        for j in 0..3 {
+            r[i][j] = mirrored.select(-r[i][j], r[i][j]);
+        }
+    }
+
+    let rotation = Quaternion::from_matrix(&r);
+
+    // M = R S with R orthogonal, so S = R^T M
+    let scale = &r.transpose() * m;
+
+    (translation, rotation, scale)
+}
+
+#[dispatch]
+impl<S: Simd> AnimatedTransform<S> {
+    /// Moves from `start` at `start_time` to `end` at `end_time`, which must be affine
+    #[inline]
+    pub fn new(start: &Transform3<S>, start_time: Vf32<S>, end: &Transform3<S>, end_time: Vf32<S>) -> Self {
+        let (t0, r0, mut s0) = decompose::<S>(&start.forward);
+        let (t1, mut r1, mut s1) = decompose::<S>(&end.forward);
+
+        // q and -q are the same rotation, so take the one that turns the short way round
+        let flip = r0.dot(&r1).lt(Vf32::<S>::zero());
+        let sign = flip.select(Vf32::<S>::neg_one(), Vf32::<S>::one());
+
+        r1.x *= sign;
+        r1.y *= sign;
+        r1.z *= sign;
+        r1.w *= sign;
+
+        // translation is interpolated on its own
+        for i in 0..3 {
+            s0[i][3] = Vf32::<S>::zero();
+            s1[i][3] = Vf32::<S>::zero();
+        }
+
+        AnimatedTransform {
+            start: *start,
+            end: *end,
+            start_time,
+            end_time,
+            translation: [t0, t1],
+            rotation: [r0, r1],
+            scale: [s0, s1],
+        }
+    }
+
+    /// Transform at `start_time`
+    #[inline]
+    pub fn start(&self) -> &Transform3<S> {
+        &self.start
+    }
+
+    /// Transform at `end_time`
+    #[inline]
+    pub fn end(&self) -> &Transform3<S> {
+        &self.end
+    }
+
+    /// Fraction of the way from `start_time` to `end_time`, clamped to the interval
+    #[inline(always)]
+    fn fraction(&self, time: Vf32<S>) -> Vf32<S> {
+        let duration = self.end_time - self.start_time;
+        let t = (time - self.start_time) / duration;
+
+        // instantaneous or reversed intervals stay at the start
+        duration
+            .gt(Vf32::<S>::zero())
+            .select(t.clamp(Vf32::<S>::zero(), Vf32::<S>::one()), Vf32::<S>::zero())
+    }
+
+    /// Transform at `time` in each lane, which is held at the nearest keyframe outside of the interval
+    #[inline]
+    pub fn interpolate(&self, time: Vf32<S>) -> Transform3<S> {
+        let t = self.fraction(time);
+
+        let [t0, t1] = self.translation;
+        let translation = Vector3 {
+            x: t.lerp(t0.x, t1.x),
+            y: t.lerp(t0.y, t1.y),
+            z: t.lerp(t0.z, t1.z),
+        };
+
+        let rotation = self.rotation[0].slerp(&self.rotation[1], t).to_transform();
+
+        let mut scale = Matrix4::identity();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                scale[i][j] = t.lerp(self.scale[0][i][j], self.scale[1][i][j]);
+            }
+        }
+
+        let (scale, _) = Transform3::from_forward(scale);
+
+        &Transform3::translate(&translation) * &(&rotation * &scale)
+    }
+
+    /// Boxes containing `bounds` at every time in the interval
+    ///
+    /// The interval is split into pieces, and each piece bounded by the box at its middle grown by the furthest
+    /// any point of `bounds` can move in half a piece, which is limited by the rates of change of each part of
+    /// the decomposition.
+    #[inline]
+    pub fn motion_bounds(&self, bounds: &Bounds3<S>) -> Bounds3<S> {
+        let zero = Vf32::<S>::zero();
+
+        let [t0, t1] = self.translation;
+        let [r0, r1] = self.rotation;
+        let [s0, s1] = &self.scale;
+
+        let mut scale_delta = Matrix4::splat(zero);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                scale_delta[i][j] = s1[i][j] - s0[i][j];
+            }
+        }
+
+        // Both S(t) p and its rate of change (S1 - S0) p are linear in p, so their lengths are largest at a corner,
+        // and lerping never makes S(t) p longer than the longer of S0 p and S1 p
+        let (mut scaled, mut scale_change) = (zero, zero);
+
+        for corner in 0..8 {
+            let p = Vector3 {
+                x: if corner & 1 == 0 { bounds.min.x } else { bounds.max.x },
+                y: if corner & 2 == 0 { bounds.min.y } else { bounds.max.y },
+                z: if corner & 4 == 0 { bounds.min.z } else { bounds.max.z },
+            };
+
+            // avoiding `norm`, which is NaN at zero
+            let length = |m: &Matrix4<S>| (m * p).norm_squared().sqrt();
+
+            scaled = scaled.max(length(s0)).max(length(s1));
+            scale_change = scale_change.max(length(&scale_delta));
+        }
+
+        // slerp turns at a constant rate, with a little room for the normalized lerp it uses for small angles
+        let angle = Vf32::<S>::splat(2.0) * r0.dot(&r1).abs().clamp(zero, Vf32::<S>::one()).acos();
+        let angle = angle * Vf32::<S>::splat(1.01);
+
+        let speed = (t1 - t0).norm_squared().sqrt() + angle.mul_add(scaled, scale_change);
+
+        let duration = self.end_time - self.start_time;
+        let step = Vf32::<S>::splat(1.0 / MOTION_BOUND_STEPS as f32);
+
+        // with no duration only the start is ever used
+        let reach = duration.gt(zero).select(speed * step * Vf32::<S>::splat(0.5), zero);
+        let reach = Vector3::diag(reach);
+
+        let mut motion = Bounds3::empty();
+
+        for i in 0..MOTION_BOUND_STEPS {
+            let middle = Vf32::<S>::splat((i as f32 + 0.5) / MOTION_BOUND_STEPS as f32);
+            let time = middle.mul_add(duration, self.start_time);
+
+            let b = bounds.transform(&self.interpolate(time));
+
+            motion = motion.union(&Bounds3 {
+                min: b.min + -reach,
+                max: b.max + reach,
+            });
+        }
+
+        let empty = bounds.is_empty();
+        let nothing = Bounds3::<S>::empty();
+
+        Bounds3 {
+            min: Point3 {
+                x: empty.select(nothing.min.x, motion.min.x),
+                y: empty.select(nothing.min.y, motion.min.y),
+                z: empty.select(nothing.min.z, motion.min.z),
+            },
+            max: Point3 {
+                x: empty.select(nothing.max.x, motion.max.x),
+                y: empty.select(nothing.max.y, motion.max.y),
+                z: empty.select(nothing.max.z, motion.max.z),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    fn vector(x: f32, y: f32, z: f32) -> Vector3<AVX2> {
+        Vector3 {
+            x: Vf32::splat(x),
+            y: Vf32::splat(y),
+            z: Vf32::splat(z),
+        }
+    }
+
+    fn at(x: f32, y: f32, z: f32) -> Point3<AVX2> {
+        let v = vector(x, y, z);
+        Point3 { x: v.x, y: v.y, z: v.z }
+    }
+
+    /// Scales, then rotates about z, then moves
+    fn placement(scale: [f32; 3], angle: f32, offset: [f32; 3]) -> Transform3<AVX2> {
+        let scale = Transform3::scale(&vector(scale[0], scale[1], scale[2])).0;
+        let rotate = Transform3::rotate(vector(0.0, 0.0, angle));
+        let translate = Transform3::translate(&vector(offset[0], offset[1], offset[2]));
+
+        &translate * &(&rotate * &scale)
+    }
+
+    fn assert_same_matrix(a: &Matrix4<AVX2>, b: &Matrix4<AVX2>) {
+        for i in 0..4 {
+            for j in 0..4 {
+                for lane in 0..LANES {
+                    let (a, b) = (a[i][j].extract(lane), b[i][j].extract(lane));
+                    assert!((a - b).abs() < 1e-4, "[{}][{}] {} != {}", i, j, a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyframes() {
+        let start = placement([1.0, 2.0, 1.0], 0.3, [1.0, 2.0, 3.0]);
+        let end = placement([0.5, 1.0, 3.0], 2.0, [-4.0, 0.0, 8.0]);
+
+        let animated = AnimatedTransform::new(&start, Vf32::splat(1.0), &end, Vf32::splat(3.0));
+
+        assert_same_matrix(&animated.interpolate(Vf32::splat(1.0)).forward, &start.forward);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(3.0)).forward, &end.forward);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(3.0)).inverse, &end.inverse);
+
+        // held outside of the interval
+        assert_same_matrix(&animated.interpolate(Vf32::splat(-5.0)).forward, &start.forward);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(9.0)).forward, &end.forward);
+
+        // each part moves evenly
+        let halfway = placement([0.75, 1.5, 2.0], 1.15, [-1.5, 1.0, 5.5]);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(2.0)).forward, &halfway.forward);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(2.0)).inverse, &halfway.inverse);
+
+        // a different time in each lane
+        let times: Vec<f32> = (0..LANES).map(|lane| 1.0 + 2.0 * lane as f32 / (LANES - 1) as f32).collect();
+        let lanes = animated.interpolate(Vf32::load_unaligned(&times));
+
+        for (lane, &time) in times.iter().enumerate() {
+            let single = animated.interpolate(Vf32::splat(time));
+
+            for i in 0..4 {
+                for j in 0..4 {
+                    assert_eq!(lanes.forward[i][j].extract(lane), single.forward[i][j].extract(0));
+                }
+            }
+        }
+
+        // rotating the short way round, rather than through almost a full turn
+        let back = placement([1.0, 1.0, 1.0], -3.0, [0.0, 0.0, 0.0]);
+        let round = placement([1.0, 1.0, 1.0], 3.0, [0.0, 0.0, 0.0]);
+        let animated = AnimatedTransform::new(&back, Vf32::zero(), &round, Vf32::one());
+
+        let across = placement([1.0, 1.0, 1.0], std::f32::consts::PI, [0.0, 0.0, 0.0]);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(0.5)).forward, &across.forward);
+    }
+
+    #[test]
+    fn test_mirrored_keyframes() {
+        let start = placement([-1.0, 2.0, 1.0], 0.3, [1.0, 2.0, 3.0]);
+        let end = placement([-0.5, 1.0, 3.0], 2.0, [-4.0, 0.0, 8.0]);
+
+        let animated = AnimatedTransform::new(&start, Vf32::splat(1.0), &end, Vf32::splat(3.0));
+
+        assert_same_matrix(&animated.interpolate(Vf32::splat(1.0)).forward, &start.forward);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(3.0)).forward, &end.forward);
+
+        // stays mirrored in between
+        let halfway = placement([-0.75, 1.5, 2.0], 1.15, [-1.5, 1.0, 5.5]);
+        assert_same_matrix(&animated.interpolate(Vf32::splat(2.0)).forward, &halfway.forward);
+
+        // the mirror alone
+        let mirror = Transform3::scale(&vector(-1.0, 1.0, 1.0)).0;
+        let still = AnimatedTransform::new(&mirror, Vf32::zero(), &mirror, Vf32::one());
+        assert_same_matrix(&still.interpolate(Vf32::zero()).forward, &mirror.forward);
+    }
+
+    #[test]
+    fn test_motion_bounds() {
+        let start = placement([1.0, 2.0, 1.0], 0.3, [1.0, 2.0, 3.0]);
+        let end = placement([0.5, 1.0, 3.0], 2.5, [-4.0, 0.0, 8.0]);
+
+        let animated = AnimatedTransform::new(&start, Vf32::zero(), &end, Vf32::one());
+
+        let bounds = Bounds3::new(at(-1.0, 0.5, -0.5), at(2.0, 1.0, 1.5));
+        let motion = animated.motion_bounds(&bounds);
+
+        let mut swept = Bounds3::<AVX2>::empty();
+
+        for i in 0..=1000 {
+            let b = bounds.transform(&animated.interpolate(Vf32::splat(i as f32 / 1000.0)));
+
+            assert!(
+                motion.union(&b).extract(0) == motion.extract(0),
+                "{:?} not in {:?}",
+                b.extract(0),
+                motion.extract(0)
+            );
+            swept = swept.union(&b);
+        }
+
+        // conservative, but not wildly so
+        assert!(motion.surface_area().extract(0) < swept.surface_area().extract(0) * 2.0);
+
+        assert_same_matrix(&animated.start().forward, &start.forward);
+        assert_same_matrix(&animated.end().forward, &end.forward);
+
+        // without motion the bounds are exact
+        let still = AnimatedTransform::new(&start, Vf32::zero(), &start, Vf32::one());
+        let expected = bounds.transform(&start).extract(0);
+        let actual = still.motion_bounds(&bounds).extract(0);
+
+        for (a, b) in [actual.min, actual.max].iter().zip(&[expected.min, expected.max]) {
+            assert!((*a - *b).norm() < 1e-4, "{:?} != {:?}", actual, expected);
+        }
+
+        assert!(animated.motion_bounds(&Bounds3::empty()).is_empty().all());
+    }
+}
@@ -0,0 +1,369 @@
+use thermite::*;
+
+use crate::math::scalar::gamma;
+use crate::math::soa::*;
+
+use crate::scalar;
+
+use super::{load_lanes, Point3, Ray3, Transform3, Vector3};
+
+/// One axis-aligned bounding box per lane
+///
+/// Splatting a single box tests it against a packet of rays, while loading several boxes
+/// tests a splatted ray against all of them at once.
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds3<S: Simd> {
+    pub min: Point3<S>,
+    pub max: Point3<S>,
+}
+
+#[dispatch]
+impl<S: Simd> Bounds3<S> {
+    #[inline(always)]
+    pub fn empty() -> Self {
+        Bounds3::splat(&scalar::Bounds3::empty())
+    }
+
+    /// Smallest boxes containing both `a` and `b`, in any order
+    #[inline(always)]
+    pub fn new(a: Point3<S>, b: Point3<S>) -> Self {
+        Bounds3 {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    #[inline(always)]
+    pub fn from_point(p: Point3<S>) -> Self {
+        Bounds3 { min: p, max: p }
+    }
+
+    /// The same box in every lane
+    #[inline(always)]
+    pub fn splat(bounds: &scalar::Bounds3) -> Self {
+        let point = |p: scalar::Point3| Point3 {
+            x: Vf32::<S>::splat(p.x),
+            y: Vf32::<S>::splat(p.y),
+            z: Vf32::<S>::splat(p.z),
+        };
+
+        Bounds3 {
+            min: point(bounds.min),
+            max: point(bounds.max),
+        }
+    }
+
+    /// Loads one box per lane from `bounds`, filling any remaining lanes with empty boxes
+    #[inline]
+    pub fn load(bounds: &[scalar::Bounds3]) -> Self {
+        let n = bounds.len();
+
+        Bounds3 {
+            min: Point3 {
+                x: load_lanes::<S>(n, f32::INFINITY, |i| bounds[i].min.x),
+                y: load_lanes::<S>(n, f32::INFINITY, |i| bounds[i].min.y),
+                z: load_lanes::<S>(n, f32::INFINITY, |i| bounds[i].min.z),
+            },
+            max: Point3 {
+                x: load_lanes::<S>(n, f32::NEG_INFINITY, |i| bounds[i].max.x),
+                y: load_lanes::<S>(n, f32::NEG_INFINITY, |i| bounds[i].max.y),
+                z: load_lanes::<S>(n, f32::NEG_INFINITY, |i| bounds[i].max.z),
+            },
+        }
+    }
+
+    /// Box in lane `lane`
+    #[inline]
+    pub fn extract(&self, lane: usize) -> scalar::Bounds3 {
+        let point = |p: &Point3<S>| scalar::Point3::new(p.x.extract(lane), p.y.extract(lane), p.z.extract(lane));
+
+        scalar::Bounds3 {
+            min: point(&self.min),
+            max: point(&self.max),
+        }
+    }
+
+    #[inline(always)]
+    pub fn union(&self, other: &Self) -> Self {
+        Bounds3 {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    #[inline(always)]
+    pub fn union_point(&self, p: Point3<S>) -> Self {
+        Bounds3 {
+            min: self.min.min(p),
+            max: self.max.max(p),
+        }
+    }
+
+    /// Overlap of both boxes, which is empty in lanes where they don't overlap
+    #[inline(always)]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Bounds3 {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> Mask<S, Vf32<S>> {
+        self.min.x.gt(self.max.x) | self.min.y.gt(self.max.y) | self.min.z.gt(self.max.z)
+    }
+
+    /// Whether `p` is inside the box or on its boundary
+    #[inline(always)]
+    pub fn contains(&self, p: Point3<S>) -> Mask<S, Vf32<S>> {
+        p.x.ge(self.min.x) & p.x.le(self.max.x) & p.y.ge(self.min.y) & p.y.le(self.max.y) & p.z.ge(self.min.z) & p.z.le(self.max.z)
+    }
+
+    #[inline(always)]
+    pub fn diagonal(&self) -> Vector3<S> {
+        self.max - self.min
+    }
+
+    #[inline(always)]
+    pub fn centroid(&self) -> Point3<S> {
+        self.min + self.diagonal() * Vector3::diag(Vf32::<S>::splat(0.5))
+    }
+
+    /// Surface area, or zero for empty boxes
+    #[inline]
+    pub fn surface_area(&self) -> Vf32<S> {
+        let d = self.diagonal();
+        let area = Vf32::<S>::splat(2.0) * d.x.mul_add(d.y, sum_of_products::<S>(d.x, d.z, d.y, d.z));
+
+        self.is_empty().select(Vf32::<S>::zero(), area)
+    }
+
+    /// Volume, or zero for empty boxes
+    #[inline]
+    pub fn volume(&self) -> Vf32<S> {
+        let d = self.diagonal();
+
+        self.is_empty().select(Vf32::<S>::zero(), d.x * d.y * d.z)
+    }
+
+    /// Smallest axis-aligned boxes containing the transformed boxes
+    ///
+    /// Uses Arvo's method, which is exact for the affine transforms `Transform3` represents.
+    /// Empty boxes stay empty.
+    #[inline]
+    pub fn transform(&self, transform: &Transform3<S>) -> Self {
+        let m = &transform.forward;
+
+        let min = [self.min.x, self.min.y, self.min.z];
+        let max = [self.max.x, self.max.y, self.max.z];
+
+        let mut lo = [m[0][3], m[1][3], m[2][3]];
+        let mut hi = lo;
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let a = m[i][j] * min[j];
+                let b = m[i][j] * max[j];
+
+                lo[i] += a.min(b);
+                hi[i] += a.max(b);
+            }
+        }
+
+        let empty = self.is_empty();
+        let inf = Vf32::<S>::splat(f32::INFINITY);
+        let neg_inf = Vf32::<S>::splat(f32::NEG_INFINITY);
+
+        Bounds3 {
+            min: Point3 {
+                x: empty.select(inf, lo[0]),
+                y: empty.select(inf, lo[1]),
+                z: empty.select(inf, lo[2]),
+            },
+            max: Point3 {
+                x: empty.select(neg_inf, hi[0]),
+                y: empty.select(neg_inf, hi[1]),
+                z: empty.select(neg_inf, hi[2]),
+            },
+        }
+    }
+
+    /// Slab test of `ray` against each box, returning the lanes that hit and the ray's entry and exit
+    /// parameters, clipped to `[0, ray.tmax]`
+    ///
+    /// `inv_dir` is `ray.inv_direction()`. The exit parameter is enlarged by `2 gamma(3)` to cover rounding
+    /// error, so rays are never falsely reported as missing, which would let them slip through geometry.
+    /// Empty boxes are never hit.
+    #[inline]
+    pub fn intersect_p(&self, ray: &Ray3<S>, inv_dir: &Vector3<S>) -> (Mask<S, Vf32<S>>, Vf32<S>, Vf32<S>) {
+        let scale = Vf32::<S>::splat(1.0 + 2.0 * gamma(3));
+
+        let mut t0 = Vf32::<S>::zero();
+        let mut t1 = ray.tmax;
+        let mut miss = t0.gt(t1) | self.is_empty();
+
+        let slabs = [
+            (self.min.x, self.max.x, ray.origin.x, inv_dir.x),
+            (self.min.y, self.max.y, ray.origin.y, inv_dir.y),
+            (self.min.z, self.max.z, ray.origin.z, inv_dir.z),
+        ];
+
+        for &(min, max, origin, inv) in &slabs {
+            let near = (min - origin) * inv;
+            let far = (max - origin) * inv;
+
+            // written so NaN, from rays in the plane of a slab, leaves the interval unchanged
+            let swap = near.gt(far);
+            let (near, far) = (swap.select(far, near), swap.select(near, far) * scale);
+
+            t0 = near.gt(t0).select(near, t0);
+            t1 = far.lt(t1).select(far, t1);
+
+            miss = miss | t0.gt(t1);
+        }
+
+        (!miss, t0, t1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    fn splat_point(x: f32, y: f32, z: f32) -> Point3<AVX2> {
+        Point3 {
+            x: Vf32::splat(x),
+            y: Vf32::splat(y),
+            z: Vf32::splat(z),
+        }
+    }
+
+    fn ray(origin: [f32; 3], direction: [f32; 3]) -> Ray3<AVX2> {
+        Ray3 {
+            origin: splat_point(origin[0], origin[1], origin[2]),
+            direction: Vector3 {
+                x: Vf32::splat(direction[0]),
+                y: Vf32::splat(direction[1]),
+                z: Vf32::splat(direction[2]),
+            },
+            tmax: Vf32::splat(f32::INFINITY),
+        }
+    }
+
+    fn unit_box() -> scalar::Bounds3 {
+        scalar::Bounds3::new(scalar::Point3::new(0.0, 0.0, 0.0), scalar::Point3::new(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        let a = unit_box();
+        let b = scalar::Bounds3::new(scalar::Point3::new(0.5, -1.0, 0.25), scalar::Point3::new(2.0, 0.5, 0.75));
+
+        let (va, vb) = (Bounds3::<AVX2>::splat(&a), Bounds3::<AVX2>::splat(&b));
+
+        assert_eq!(va.union(&vb).extract(1), a.union(&b));
+        assert_eq!(va.intersection(&vb).extract(2), a.intersection(&b));
+        assert_eq!(va.surface_area().extract(0), a.surface_area());
+        assert_eq!(va.intersection(&vb).volume().extract(0), a.intersection(&b).volume());
+        assert_eq!(Bounds3::<AVX2>::empty().surface_area().extract(0), 0.0);
+        assert!(Bounds3::<AVX2>::empty().is_empty().all());
+    }
+
+    #[test]
+    fn test_slab() {
+        let bounds = Bounds3::<AVX2>::splat(&unit_box());
+
+        let r = ray([-1.0, 0.5, 0.5], [1.0, 0.0, 0.0]);
+        let (hit, t0, t1) = bounds.intersect_p(&r, &r.inv_direction());
+        assert!(hit.all());
+        assert_eq!(t0.extract(0), 1.0);
+        assert!(t1.extract(0) >= 2.0 && t1.extract(0) < 2.0001);
+
+        // starting inside
+        let r = ray([0.5, 0.5, 0.5], [0.0, 1.0, 0.0]);
+        let (hit, t0, _) = bounds.intersect_p(&r, &r.inv_direction());
+        assert!(hit.all());
+        assert_eq!(t0.extract(0), 0.0);
+
+        // pointing away, passing beside, and stopping short
+        let r = ray([-1.0, 0.5, 0.5], [-1.0, 0.0, 0.0]);
+        assert!(!bounds.intersect_p(&r, &r.inv_direction()).0.any());
+
+        let r = ray([-1.0, 1.5, 0.5], [1.0, 0.0, 0.0]);
+        assert!(!bounds.intersect_p(&r, &r.inv_direction()).0.any());
+
+        let mut r = ray([-1.0, 0.5, 0.5], [1.0, 0.0, 0.0]);
+        r.tmax = Vf32::splat(0.5);
+        assert!(!bounds.intersect_p(&r, &r.inv_direction()).0.any());
+
+        // grazing a face, in the plane of the slab
+        let r = ray([-1.0, 1.0, 0.5], [1.0, 0.0, 0.0]);
+        assert!(bounds.intersect_p(&r, &r.inv_direction()).0.all());
+    }
+
+    #[test]
+    fn test_packet_of_boxes() {
+        let boxes: Vec<scalar::Bounds3> = (0..LANES - 1)
+            .map(|i| {
+                let y = i as f32 - 2.0;
+                scalar::Bounds3::new(scalar::Point3::new(1.0, y, -1.0), scalar::Point3::new(2.0, y + 0.5, 1.0))
+            })
+            .collect();
+
+        let packet = Bounds3::<AVX2>::load(&boxes);
+        let r = ray([0.0, 0.25, 0.0], [1.0, 0.0, 0.0]);
+        let hit = packet.intersect_p(&r, &r.inv_direction()).0.select(Vf32::one(), Vf32::zero());
+
+        for lane in 0..LANES {
+            assert_eq!(hit.extract(lane) == 1.0, lane == 2, "lane {}", lane);
+        }
+    }
+
+    #[test]
+    fn test_transform() {
+        let bounds = Bounds3::<AVX2>::splat(&unit_box());
+
+        let (scale, _) = Transform3::<AVX2>::scale(&Vector3 {
+            x: Vf32::splat(2.0),
+            y: Vf32::splat(-1.0),
+            z: Vf32::splat(1.0),
+        });
+
+        let scaled = bounds.transform(&scale).extract(0);
+        assert_eq!(scaled.min, scalar::Point3::new(0.0, -1.0, 0.0));
+        assert_eq!(scaled.max, scalar::Point3::new(2.0, 0.0, 1.0));
+
+        // a quarter turn about z keeps a centered box the same size
+        let centered = Bounds3::<AVX2>::splat(&scalar::Bounds3::new(
+            scalar::Point3::new(-1.0, -2.0, -3.0),
+            scalar::Point3::new(1.0, 2.0, 3.0),
+        ));
+        let rotate = Transform3::<AVX2>::rotate(Vector3 {
+            x: Vf32::zero(),
+            y: Vf32::zero(),
+            z: Vf32::splat(std::f32::consts::FRAC_PI_2),
+        });
+
+        let rotated = centered.transform(&rotate).extract(0);
+        assert!((rotated.max.x - 2.0).abs() < 1e-5 && (rotated.max.y - 1.0).abs() < 1e-5 && rotated.max.z == 3.0);
+
+        assert!(Bounds3::<AVX2>::empty().transform(&rotate).is_empty().all());
+
+        // any empty box becomes the canonical one, as it does for scalar boxes
+        let inverted = scalar::Bounds3 {
+            min: scalar::Point3::new(1.0, 0.0, 0.0),
+            max: scalar::Point3::new(-1.0, 1.0, 1.0),
+        };
+
+        assert_eq!(
+            Bounds3::<AVX2>::splat(&inverted).transform(&rotate).extract(0),
+            scalar::Bounds3::empty()
+        );
+        assert_eq!(inverted.transform(&rotate.extract(0)), scalar::Bounds3::empty());
+    }
+}
@@ -4,7 +4,9 @@ use thermite::*;
 
 use crate::math::soa::*;
 
-use super::{Point3, Vector3};
+use crate::scalar;
+
+use super::{load_lanes, Point3, Vector3};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Matrix4<S: Simd>(pub [[Vf32<S>; 4]; 4]);
@@ -45,6 +47,49 @@ impl<S: Simd> Matrix4<S> {
         ])
     }
 
+    /// The same matrix in every lane, where `splat` fills every element with one value
+    #[inline(always)]
+    pub fn splat_matrix(m: &scalar::Matrix4) -> Self {
+        let mut res = Matrix4::splat(Vf32::<S>::zero());
+
+        for i in 0..4 {
+            for j in 0..4 {
+                res[i][j] = Vf32::<S>::splat(m[i][j]);
+            }
+        }
+
+        res
+    }
+
+    /// Loads one matrix per lane from `matrices`, filling any remaining lanes with the identity
+    #[inline]
+    pub fn load(matrices: &[scalar::Matrix4]) -> Self {
+        let mut res = Matrix4::splat(Vf32::<S>::zero());
+
+        for i in 0..4 {
+            for j in 0..4 {
+                let fill = if i == j { 1.0 } else { 0.0 };
+                res[i][j] = load_lanes::<S>(matrices.len(), fill, |lane| matrices[lane][i][j]);
+            }
+        }
+
+        res
+    }
+
+    /// Matrix in lane `lane`
+    #[inline]
+    pub fn extract(&self, lane: usize) -> scalar::Matrix4 {
+        let mut res = scalar::Matrix4([[0.0; 4]; 4]);
+
+        for i in 0..4 {
+            for j in 0..4 {
+                res[i][j] = self[i][j].extract(lane);
+            }
+        }
+
+        res
+    }
+
     #[inline(always)]
     pub fn at(&self, row: usize, col: usize) -> &Vf32<S> {
         &self.0[row][col]
@@ -203,10 +248,10 @@ impl<S: Simd> Mul<Point3<S>> for &Matrix4<S> {
     fn mul(self, rhs: Point3<S>) -> Point3<S> {
         let m = self;
 
-        let x = inner_product7::<S>(m[0][0], rhs.x, m[0][1], rhs.y, m[0][2], rhs.x, m[0][3]);
-        let y = inner_product7::<S>(m[1][0], rhs.x, m[1][1], rhs.y, m[1][2], rhs.x, m[1][3]);
-        let z = inner_product7::<S>(m[2][0], rhs.x, m[2][1], rhs.y, m[2][2], rhs.x, m[2][3]);
-        let w = inner_product7::<S>(m[3][0], rhs.x, m[3][1], rhs.y, m[3][2], rhs.x, m[3][3]);
+        let x = inner_product7::<S>(m[0][0], rhs.x, m[0][1], rhs.y, m[0][2], rhs.z, m[0][3]);
+        let y = inner_product7::<S>(m[1][0], rhs.x, m[1][1], rhs.y, m[1][2], rhs.z, m[1][3]);
+        let z = inner_product7::<S>(m[2][0], rhs.x, m[2][1], rhs.y, m[2][2], rhs.z, m[2][3]);
+        let w = inner_product7::<S>(m[3][0], rhs.x, m[3][1], rhs.y, m[3][2], rhs.z, m[3][3]);
 
         let mut p = Point3 { x, y, z };
 
@@ -229,9 +274,9 @@ impl<S: Simd> Mul<Vector3<S>> for &Matrix4<S> {
         let m = self;
 
         Vector3 {
-            x: inner_product6::<S>(m[0][0], rhs.x, m[0][1], rhs.y, m[0][2], rhs.x),
-            y: inner_product6::<S>(m[1][0], rhs.x, m[1][1], rhs.y, m[1][2], rhs.x),
-            z: inner_product6::<S>(m[2][0], rhs.x, m[2][1], rhs.y, m[2][2], rhs.x),
+            x: inner_product6::<S>(m[0][0], rhs.x, m[0][1], rhs.y, m[0][2], rhs.z),
+            y: inner_product6::<S>(m[1][0], rhs.x, m[1][1], rhs.y, m[1][2], rhs.z),
+            z: inner_product6::<S>(m[2][0], rhs.x, m[2][1], rhs.y, m[2][2], rhs.z),
         }
     }
 }
@@ -1,11 +1,48 @@
+use thermite::*;
+
+pub mod animated_transform;
+pub mod bounds3;
 pub mod matrix4;
 pub mod point3;
 pub mod quaternion;
 pub mod ray3;
+pub mod shapes;
 pub mod tangent;
 pub mod transform3;
+pub mod triangle;
 pub mod vector3;
 
-pub use self::{matrix4::Matrix4, point3::Point3, quaternion::Quaternion, ray3::Ray3, transform3::Transform3, vector3::Vector3};
+pub use self::{
+    animated_transform::AnimatedTransform,
+    bounds3::Bounds3,
+    matrix4::Matrix4,
+    point3::Point3,
+    quaternion::{EulerOrder, Quaternion},
+    ray3::{Ray3, Ray3Error3},
+    shapes::{Cylinder, Disk, OrientedBox, Plane, Sphere, SurfaceHit},
+    tangent::TangentFrame,
+    transform3::Transform3,
+    triangle::{Triangle, TriangleHit},
+    vector3::Vector3,
+};
 
+/// Conservative bound on the absolute rounding error of each component of a point or vector
 pub type Error3<S> = Vector3<S>;
+
+/// Widest supported vector, in lanes
+const MAX_LANES: usize = 16;
+
+/// Vector with `value(lane)` in each of the first `count` lanes, and `fill` in the rest
+#[inline(always)]
+fn load_lanes<S: Simd>(count: usize, fill: f32, value: impl Fn(usize) -> f32) -> Vf32<S> {
+    let lanes = Vf32::<S>::NUM_ELEMENTS;
+    debug_assert!(lanes <= MAX_LANES);
+    debug_assert!(count <= lanes);
+
+    let mut values = [fill; MAX_LANES];
+    for (lane, v) in values.iter_mut().enumerate().take(count.min(lanes)) {
+        *v = value(lane);
+    }
+
+    Vf32::<S>::load_unaligned(&values[..lanes])
+}
@@ -4,7 +4,9 @@ use thermite::*;
 
 use crate::math::*;
 
-use super::Vector3;
+use crate::scalar;
+
+use super::{load_lanes, Vector3};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Point3<S: Simd> {
@@ -15,6 +17,34 @@ pub struct Point3<S: Simd> {
 
 #[dispatch]
 impl<S: Simd> Point3<S> {
+    /// The same point in every lane
+    #[inline(always)]
+    pub fn splat(p: &scalar::Point3) -> Self {
+        Point3 {
+            x: Vf32::<S>::splat(p.x),
+            y: Vf32::<S>::splat(p.y),
+            z: Vf32::<S>::splat(p.z),
+        }
+    }
+
+    /// Loads one point per lane from `points`, filling any remaining lanes with the origin
+    #[inline]
+    pub fn load(points: &[scalar::Point3]) -> Self {
+        let n = points.len();
+
+        Point3 {
+            x: load_lanes::<S>(n, 0.0, |i| points[i].x),
+            y: load_lanes::<S>(n, 0.0, |i| points[i].y),
+            z: load_lanes::<S>(n, 0.0, |i| points[i].z),
+        }
+    }
+
+    /// Point in lane `lane`
+    #[inline]
+    pub fn extract(&self, lane: usize) -> scalar::Point3 {
+        scalar::Point3::new(self.x.extract(lane), self.y.extract(lane), self.z.extract(lane))
+    }
+
     #[inline(always)]
     pub fn coords(self) -> Vector3<S> {
         let Point3 { x, y, z } = self;
@@ -30,6 +60,62 @@ impl<S: Simd> Point3<S> {
     pub fn distance(self, other: Self) -> Vf32<S> {
         (self - other).norm()
     }
+
+    /// Componentwise minimum
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        Point3 {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Componentwise maximum
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        Point3 {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// `self` at `t = 0` to `other` at `t = 1`
+    #[inline(always)]
+    pub fn lerp(self, other: Self, t: Vf32<S>) -> Self {
+        Point3 {
+            x: t.lerp(self.x, other.x),
+            y: t.lerp(self.y, other.y),
+            z: t.lerp(self.z, other.z),
+        }
+    }
+}
+
+impl<S: Simd> Index<usize> for Point3<S> {
+    type Output = Vf32<S>;
+
+    #[inline(always)]
+    fn index(&self, axis: usize) -> &Vf32<S> {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Point3 axis {} out of range", axis),
+        }
+    }
+}
+
+impl<S: Simd> IndexMut<usize> for Point3<S> {
+    #[inline(always)]
+    fn index_mut(&mut self, axis: usize) -> &mut Vf32<S> {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Point3 axis {} out of range", axis),
+        }
+    }
 }
 
 impl<S: Simd> Sub<Point3<S>> for Point3<S> {
@@ -56,3 +142,29 @@ impl<S: Simd> Add<Vector3<S>> for Point3<S> {
         self
     }
 }
+
+impl<S: Simd> Sub<Vector3<S>> for Point3<S> {
+    type Output = Point3<S>;
+
+    #[inline(always)]
+    fn sub(mut self, rhs: Vector3<S>) -> Point3<S> {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self
+    }
+}
+
+impl<S: Simd> AddAssign<Vector3<S>> for Point3<S> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Vector3<S>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<S: Simd> SubAssign<Vector3<S>> for Point3<S> {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Vector3<S>) {
+        *self = *self - rhs;
+    }
+}
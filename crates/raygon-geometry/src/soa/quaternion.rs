@@ -4,7 +4,44 @@ use thermite::*;
 
 use crate::math::soa::*;
 
-use super::Vector3;
+use crate::scalar;
+
+use super::{load_lanes, Matrix4, Transform3, Vector3};
+
+/// Order in which rotations about the coordinate axes are applied, first to last
+///
+/// Rotations are about the fixed world axes, so `XYZ` rotates about `x`, then `y`, then `z`.
+/// This is the same as rotating about the body's own axes in the reverse order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Indices of the axes in the order they are applied
+    #[inline(always)]
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    /// Whether the axes are a cyclic permutation of `x, y, z`
+    #[inline(always)]
+    fn is_cyclic(self) -> bool {
+        matches!(self, EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY)
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct Quaternion<S: Simd> {
@@ -28,6 +65,41 @@ impl<S: Simd> Quaternion<S> {
         }
     }
 
+    /// The same rotation in every lane
+    #[inline(always)]
+    pub fn splat(q: &scalar::Quaternion) -> Self {
+        Quaternion {
+            x: Vf32::<S>::splat(q.x),
+            y: Vf32::<S>::splat(q.y),
+            z: Vf32::<S>::splat(q.z),
+            w: Vf32::<S>::splat(q.w),
+        }
+    }
+
+    /// Loads one quaternion per lane from `quaternions`, filling any remaining lanes with the identity
+    #[inline]
+    pub fn load(quaternions: &[scalar::Quaternion]) -> Self {
+        let n = quaternions.len();
+
+        Quaternion {
+            x: load_lanes::<S>(n, 0.0, |i| quaternions[i].x),
+            y: load_lanes::<S>(n, 0.0, |i| quaternions[i].y),
+            z: load_lanes::<S>(n, 0.0, |i| quaternions[i].z),
+            w: load_lanes::<S>(n, 1.0, |i| quaternions[i].w),
+        }
+    }
+
+    /// Quaternion in lane `lane`
+    #[inline]
+    pub fn extract(&self, lane: usize) -> scalar::Quaternion {
+        scalar::Quaternion {
+            x: self.x.extract(lane),
+            y: self.y.extract(lane),
+            z: self.z.extract(lane),
+            w: self.w.extract(lane),
+        }
+    }
+
     #[inline(always)]
     pub fn dot(&self, other: &Self) -> Vf32<S> {
         sum_of_products::<S>(self.x, other.x, self.y, other.y) + sum_of_products::<S>(self.z, other.z, self.w, other.w)
@@ -99,4 +171,477 @@ impl<S: Simd> Quaternion<S> {
 
         res
     }
+
+    /// Rotation by `|axis_angle|` radians counter-clockwise about `axis_angle`, the same as `Transform3::rotate`
+    #[inline]
+    pub fn from_axis_angle(axis_angle: Vector3<S>) -> Self {
+        let (axis, angle, mask) = axis_angle.normalize_len_mask();
+
+        let (s, c) = (angle * Vf32::<S>::splat(0.5)).sin_cos();
+
+        // no axis means no rotation, so avoid the NaNs of normalizing a zero vector
+        let s = mask.select(Vf32::<S>::zero(), s);
+        let c = mask.select(Vf32::<S>::one(), c);
+        let axis: Vector3<S> = Vector3 {
+            x: mask.select(Vf32::<S>::zero(), axis.x),
+            y: mask.select(Vf32::<S>::zero(), axis.y),
+            z: mask.select(Vf32::<S>::zero(), axis.z),
+        };
+
+        Quaternion {
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+            w: c,
+        }
+    }
+
+    /// Axis scaled by the angle of rotation in radians, which is in `[0, pi]`
+    ///
+    /// Assumes a unit quaternion. Identity rotations give a zero vector.
+    #[inline]
+    pub fn to_axis_angle(&self) -> Vector3<S> {
+        // q and -q are the same rotation, so pick the one with the shorter angle
+        let flip = self.w.lt(Vf32::<S>::zero());
+        let sign = flip.select(Vf32::<S>::neg_one(), Vf32::<S>::one());
+
+        let v: Vector3<S> = Vector3 {
+            x: self.x * sign,
+            y: self.y * sign,
+            z: self.z * sign,
+        };
+
+        let norm_squared = v.norm_squared();
+        let s = norm_squared.sqrt();
+        let angle = Vf32::<S>::splat(2.0) * s.atan2(self.w * sign);
+
+        // angle / s tends to 2 / w = 2 as the angle shrinks
+        let scale = norm_squared.eq(Vf32::<S>::zero()).select(Vf32::<S>::splat(2.0), angle / s);
+
+        v * Vector3::diag(scale)
+    }
+
+    /// Rotation by `angles`, in radians about each axis, applied in `order`
+    #[inline]
+    pub fn from_euler(angles: &Vector3<S>, order: EulerOrder) -> Self {
+        let zero = Vf32::<S>::zero();
+        let angles = [angles.x, angles.y, angles.z];
+
+        let about = |axis: usize| {
+            let mut v = [zero; 3];
+            v[axis] = angles[axis];
+
+            Quaternion::from_axis_angle(Vector3 { x: v[0], y: v[1], z: v[2] })
+        };
+
+        let [i, j, k] = order.axes();
+
+        // later rotations are applied on the left
+        about(k) * (about(j) * about(i))
+    }
+
+    /// Angles about each axis, in radians, that rotate the same as this when applied in `order`
+    ///
+    /// The middle rotation is in `[-pi/2, pi/2]` and the others in `[-pi, pi]`. At gimbal lock, where the middle
+    /// rotation is a quarter turn and only the sum or difference of the other two matters, the last is taken to be zero.
+    /// Assumes a unit quaternion.
+    #[inline]
+    pub fn to_euler(&self, order: EulerOrder) -> Vector3<S> {
+        let m = self.to_matrix();
+        let [i, j, k] = order.axes();
+
+        let sign = Vf32::<S>::splat(if order.is_cyclic() { 1.0 } else { -1.0 });
+
+        let sin_middle = (-sign * m[k][i]).clamp(Vf32::<S>::neg_one(), Vf32::<S>::one());
+        let locked = sin_middle.abs().gt(Vf32::<S>::splat(0.99999));
+
+        let first = locked.select((-sign * m[j][k]).atan2(m[j][j]), (sign * m[k][j]).atan2(m[k][k]));
+        let last = locked.select(Vf32::<S>::zero(), (sign * m[j][i]).atan2(m[i][i]));
+
+        let mut angles = [Vf32::<S>::zero(); 3];
+        angles[i] = first;
+        angles[j] = sin_middle.asin();
+        angles[k] = last;
+
+        Vector3 {
+            x: angles[0],
+            y: angles[1],
+            z: angles[2],
+        }
+    }
+
+    /// Inverse of unit quaternions
+    #[inline(always)]
+    pub fn conjugate(&self) -> Self {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Inverse of any non-zero quaternion
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let inv_norm_squared = Vf32::<S>::one() / self.dot(self);
+        let c = self.conjugate();
+
+        Quaternion {
+            x: c.x * inv_norm_squared,
+            y: c.y * inv_norm_squared,
+            z: c.z * inv_norm_squared,
+            w: c.w * inv_norm_squared,
+        }
+    }
+
+    /// Rotates `v`, assuming a unit quaternion
+    #[inline]
+    pub fn rotate(&self, v: Vector3<S>) -> Vector3<S> {
+        let q = Vector3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+
+        // v + 2w(q x v) + 2q x (q x v), without building the matrix
+        let t = q.cross(&v) * Vector3::diag(Vf32::<S>::splat(2.0));
+
+        v + t * Vector3::diag(self.w) + q.cross(&t)
+    }
+
+    /// Rotation matrix, assuming a unit quaternion
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix4<S> {
+        let one = Vf32::<S>::one();
+        let zero = Vf32::<S>::zero();
+        let two = Vf32::<S>::splat(2.0);
+
+        let Quaternion { x, y, z, w } = *self;
+
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        Matrix4([
+            [two.nmul_add(yy + zz, one), two * (xy - wz), two * (xz + wy), zero],
+            [two * (xy + wz), two.nmul_add(xx + zz, one), two * (yz - wx), zero],
+            [two * (xz - wy), two * (yz + wx), two.nmul_add(xx + yy, one), zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    /// Rotation transform, with the transposed matrix as its inverse
+    #[inline]
+    pub fn to_transform(&self) -> Transform3<S> {
+        let forward = self.to_matrix();
+
+        Transform3 {
+            forward,
+            inverse: forward.transpose(),
+        }
+    }
+
+    /// Rotation of the upper-left 3x3 part of `m`, which must be a rotation matrix
+    ///
+    /// Each lane works from the largest of the trace and diagonal, which keeps the division well away from zero.
+    #[inline]
+    pub fn from_matrix(m: &Matrix4<S>) -> Self {
+        let one = Vf32::<S>::one();
+        let quarter = Vf32::<S>::splat(0.25);
+
+        let (m00, m11, m22) = (m[0][0], m[1][1], m[2][2]);
+        let trace = m00 + m11 + m22;
+
+        // 4 times the largest component, and the others divided by it
+        let from = |four: Vf32<S>, a: Vf32<S>, b: Vf32<S>, c: Vf32<S>| {
+            let inv = one / four;
+            (four * quarter, a * inv, b * inv, c * inv)
+        };
+
+        let two_sqrt = |v: Vf32<S>| Vf32::<S>::splat(2.0) * (v + one).sqrt();
+
+        let (w, x, y, z) = from(two_sqrt(trace), m[2][1] - m[1][2], m[0][2] - m[2][0], m[1][0] - m[0][1]);
+        let by_w = Quaternion { x, y, z, w };
+
+        let (x, w, y, z) = from(two_sqrt(m00 - m11 - m22), m[2][1] - m[1][2], m[0][1] + m[1][0], m[0][2] + m[2][0]);
+        let by_x = Quaternion { x, y, z, w };
+
+        let (y, w, x, z) = from(two_sqrt(m11 - m00 - m22), m[0][2] - m[2][0], m[0][1] + m[1][0], m[1][2] + m[2][1]);
+        let by_y = Quaternion { x, y, z, w };
+
+        let (z, w, x, y) = from(two_sqrt(m22 - m00 - m11), m[1][0] - m[0][1], m[0][2] + m[2][0], m[1][2] + m[2][1]);
+        let by_z = Quaternion { x, y, z, w };
+
+        let largest = trace.max(m00).max(m11).max(m22);
+
+        let q = select::<S>(m22.eq(largest), by_z, by_x);
+        let q = select::<S>(m11.eq(largest), by_y, q);
+
+        select::<S>(trace.eq(largest), by_w, q)
+    }
+
+    /// Rotation of `transform`, which must be a pure rotation
+    #[inline(always)]
+    pub fn from_transform(transform: &Transform3<S>) -> Self {
+        Self::from_matrix(&transform.forward)
+    }
+}
+
+#[inline(always)]
+fn select<S: Simd>(mask: Mask<S, Vf32<S>>, a: Quaternion<S>, b: Quaternion<S>) -> Quaternion<S> {
+    Quaternion {
+        x: mask.select(a.x, b.x),
+        y: mask.select(a.y, b.y),
+        z: mask.select(a.z, b.z),
+        w: mask.select(a.w, b.w),
+    }
+}
+
+/// Hamilton product, which rotates by `rhs` and then by `self`
+impl<S: Simd> Mul for Quaternion<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+
+        Quaternion {
+            x: sum_of_products::<S>(a.w, b.x, a.x, b.w) + difference_of_products::<S>(a.y, b.z, a.z, b.y),
+            y: sum_of_products::<S>(a.w, b.y, a.y, b.w) + difference_of_products::<S>(a.z, b.x, a.x, b.z),
+            z: sum_of_products::<S>(a.w, b.z, a.z, b.w) + difference_of_products::<S>(a.x, b.y, a.y, b.x),
+            w: difference_of_products::<S>(a.w, b.w, a.x, b.x) - sum_of_products::<S>(a.y, b.y, a.z, b.z),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    const ORDERS: [EulerOrder; 6] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+
+    /// A different rotation in each lane
+    fn axis_angles() -> Vector3<AVX2> {
+        let lanes = |f: fn(f32) -> f32| Vf32::load_unaligned(&(0..LANES).map(|l| f(l as f32)).collect::<Vec<_>>());
+
+        Vector3 {
+            x: lanes(|l| 0.3 * l - 1.0),
+            y: lanes(|l| (l * 1.7).sin()),
+            z: lanes(|l| 2.5 - 0.4 * l),
+        }
+    }
+
+    fn assert_within(a: Vf32, b: Vf32, tolerance: f32) {
+        for lane in 0..LANES {
+            assert!((a.extract(lane) - b.extract(lane)).abs() < tolerance, "{:?} != {:?}", a, b);
+        }
+    }
+
+    fn assert_close(a: Vf32, b: Vf32) {
+        assert_within(a, b, 1e-4);
+    }
+
+    fn assert_same_vector(a: &Vector3<AVX2>, b: &Vector3<AVX2>) {
+        assert_close(a.x, b.x);
+        assert_close(a.y, b.y);
+        assert_close(a.z, b.z);
+    }
+
+    fn assert_same_matrix(a: &Matrix4<AVX2>, b: &Matrix4<AVX2>) {
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_close(a[i][j], b[i][j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_transform_rotate() {
+        let v = axis_angles();
+        let q = Quaternion::from_axis_angle(v);
+        let t = Transform3::rotate(v);
+
+        assert_same_matrix(&q.to_matrix(), &t.forward);
+        assert_same_matrix(&q.to_transform().inverse, &t.inverse);
+
+        let p = Vector3 {
+            x: Vf32::splat(1.0),
+            y: Vf32::splat(-2.0),
+            z: Vf32::splat(0.5),
+        };
+        assert_same_vector(&q.rotate(p), &(&t * p));
+
+        // no rotation at all
+        let zero = Vector3::diag(Vf32::zero());
+        assert_same_matrix(&Quaternion::<AVX2>::from_axis_angle(zero).to_matrix(), &Matrix4::identity());
+        assert_same_vector(&Quaternion::<AVX2>::identity().to_axis_angle(), &zero);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let v = axis_angles();
+        let q = Quaternion::from_axis_angle(v);
+
+        // all test angles are under pi, so the axis-angle form comes back exactly
+        assert_same_vector(&q.to_axis_angle(), &v);
+
+        // q and -q are the same rotation, so compare matrices
+        assert_same_matrix(&Quaternion::from_matrix(&q.to_matrix()).to_matrix(), &q.to_matrix());
+        assert_same_matrix(&Quaternion::from_transform(&q.to_transform()).to_matrix(), &q.to_matrix());
+
+        for &order in &ORDERS {
+            let angles = q.to_euler(order);
+            assert_same_matrix(&Quaternion::from_euler(&angles, order).to_matrix(), &q.to_matrix());
+
+            // and back to the same angles, which are in range
+            assert_same_vector(&Quaternion::from_euler(&angles, order).to_euler(order), &angles);
+        }
+    }
+
+    #[test]
+    fn test_euler_order() {
+        let quarter = std::f32::consts::FRAC_PI_2;
+        let angles = Vector3 {
+            x: Vf32::splat(quarter),
+            y: Vf32::splat(0.0),
+            z: Vf32::splat(quarter),
+        };
+
+        let x = Vector3 {
+            x: Vf32::one(),
+            y: Vf32::zero(),
+            z: Vf32::zero(),
+        };
+        let y = Vector3 { x: x.y, y: x.x, z: x.z };
+        let z = Vector3 { x: x.y, y: x.z, z: x.x };
+
+        // y goes to z about x, then z stays put about z
+        assert_same_vector(&Quaternion::from_euler(&angles, EulerOrder::XYZ).rotate(y), &z);
+
+        // y goes to -x about z, then stays put about x
+        assert_same_vector(&Quaternion::from_euler(&angles, EulerOrder::ZYX).rotate(y), &-x);
+    }
+
+    #[test]
+    fn test_gimbal_lock() {
+        for &order in &ORDERS {
+            let [i, j, k] = order.axes();
+
+            let mut angles = [Vf32::splat(0.7), Vf32::splat(0.7), Vf32::splat(0.7)];
+            angles[j] = Vf32::splat(std::f32::consts::FRAC_PI_2);
+            angles[k] = Vf32::splat(-0.4);
+
+            let angles = Vector3 {
+                x: angles[0],
+                y: angles[1],
+                z: angles[2],
+            };
+
+            let q = Quaternion::<AVX2>::from_euler(&angles, order);
+            let back = q.to_euler(order);
+
+            // the middle angle is badly conditioned here, so single precision only gets it to about sqrt(EPSILON)
+            let (a, b) = (Quaternion::from_euler(&back, order).to_matrix(), q.to_matrix());
+
+            for r in 0..3 {
+                for c in 0..3 {
+                    assert_within(a[r][c], b[r][c], 2e-3);
+                }
+            }
+
+            let back = [back.x, back.y, back.z];
+            assert_close(back[k], Vf32::zero());
+            assert!(back[i].extract(0).is_finite());
+        }
+    }
+
+    #[test]
+    fn test_multiply_and_inverse() {
+        let a = Quaternion::from_axis_angle(axis_angles());
+        let b = Quaternion::from_euler(&axis_angles(), EulerOrder::YZX);
+
+        let v = Vector3 {
+            x: Vf32::splat(0.3),
+            y: Vf32::splat(4.0),
+            z: Vf32::splat(-1.0),
+        };
+
+        // b first, then a
+        assert_same_vector(&(a * b).rotate(v), &a.rotate(b.rotate(v)));
+        assert_same_matrix(&(a * b).to_matrix(), &(&a.to_matrix() * &b.to_matrix()));
+
+        assert_same_vector(&a.conjugate().rotate(a.rotate(v)), &v);
+
+        // inverse works without normalizing first
+        let scaled = Quaternion::<AVX2> {
+            x: a.x * Vf32::splat(3.0),
+            y: a.y * Vf32::splat(3.0),
+            z: a.z * Vf32::splat(3.0),
+            w: a.w * Vf32::splat(3.0),
+        };
+
+        let identity = scaled * scaled.inverse();
+        assert_close(identity.x, Vf32::zero());
+        assert_close(identity.y, Vf32::zero());
+        assert_close(identity.z, Vf32::zero());
+        assert_close(identity.w, Vf32::one());
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        let axis_angles = axis_angles();
+        let soa = Quaternion::from_axis_angle(axis_angles);
+        let other = Quaternion::from_euler(&axis_angles, EulerOrder::ZXY);
+
+        let close = |a: scalar::Quaternion, b: scalar::Quaternion| {
+            assert!(
+                (a.x - b.x).abs() < 1e-5 && (a.y - b.y).abs() < 1e-5 && (a.z - b.z).abs() < 1e-5 && (a.w - b.w).abs() < 1e-5,
+                "{:?} != {:?}",
+                a,
+                b
+            );
+        };
+
+        let quaternions: Vec<scalar::Quaternion> = (0..LANES)
+            .map(|lane| scalar::Quaternion::from_axis_angle(axis_angles.extract(lane)))
+            .collect();
+
+        for (lane, &q) in quaternions.iter().enumerate() {
+            let r = other.extract(lane);
+
+            close(soa.extract(lane), q);
+            close((soa * other).extract(lane), q * r);
+            close(soa.slerp(&other, Vf32::splat(0.3)).extract(lane), q.slerp(&r, 0.3));
+            close(
+                Quaternion::from_matrix(&soa.to_matrix()).extract(lane),
+                scalar::Quaternion::from_matrix(&q.to_matrix()),
+            );
+
+            let (a, b) = (soa.to_axis_angle().extract(lane), q.to_axis_angle());
+            assert!((a - b).norm() < 1e-4, "{:?} != {:?}", a, b);
+        }
+
+        // packing round trips, with identity in the left over lanes
+        let packed = Quaternion::<AVX2>::load(&quaternions[..LANES - 1]);
+
+        for (lane, &q) in quaternions[..LANES - 1].iter().enumerate() {
+            assert_eq!(packed.extract(lane), q);
+        }
+
+        assert_eq!(packed.extract(LANES - 1), scalar::Quaternion::identity());
+        assert_eq!(Quaternion::<AVX2>::splat(&quaternions[2]).extract(LANES - 1), quaternions[2]);
+    }
 }
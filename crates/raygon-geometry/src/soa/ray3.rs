@@ -3,8 +3,9 @@ use std::ops::*;
 use thermite::*;
 
 use crate::math::*;
+use crate::scalar;
 
-use super::{Error3, Point3, Vector3};
+use super::{load_lanes, Error3, Point3, Vector3};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Ray3<S: Simd> {
@@ -13,14 +14,131 @@ pub struct Ray3<S: Simd> {
     pub tmax: Vf32<S>,
 }
 
+/// Rounding error of a ray's origin and direction
+#[derive(Debug, Clone, Copy)]
 pub struct Ray3Error3<S: Simd> {
     pub pos: Error3<S>,
     pub dir: Error3<S>,
 }
 
+/// Fraction of a ray towards a target point that is left out at the target's end, so spawned rays don't hit
+/// the surface the target lies on
+pub const SHADOW_EPSILON: f32 = 0.0001;
+
 #[dispatch]
 impl<S: Simd> Ray3<S> {
+    /// The same ray in every lane
+    #[inline(always)]
+    pub fn splat(ray: &scalar::Ray3) -> Self {
+        Ray3 {
+            origin: Point3 {
+                x: Vf32::<S>::splat(ray.origin.x),
+                y: Vf32::<S>::splat(ray.origin.y),
+                z: Vf32::<S>::splat(ray.origin.z),
+            },
+            direction: Vector3 {
+                x: Vf32::<S>::splat(ray.direction.x),
+                y: Vf32::<S>::splat(ray.direction.y),
+                z: Vf32::<S>::splat(ray.direction.z),
+            },
+            tmax: Vf32::<S>::splat(ray.tmax),
+        }
+    }
+
+    /// Loads one ray per lane from `rays`, filling any remaining lanes with rays ending before they start, which never
+    /// hit anything, not even boxes containing their origin
+    #[inline]
+    pub fn load(rays: &[scalar::Ray3]) -> Self {
+        let n = rays.len();
+
+        Ray3 {
+            origin: Point3 {
+                x: load_lanes::<S>(n, 0.0, |i| rays[i].origin.x),
+                y: load_lanes::<S>(n, 0.0, |i| rays[i].origin.y),
+                z: load_lanes::<S>(n, 0.0, |i| rays[i].origin.z),
+            },
+            direction: Vector3 {
+                x: load_lanes::<S>(n, 0.0, |i| rays[i].direction.x),
+                y: load_lanes::<S>(n, 0.0, |i| rays[i].direction.y),
+                z: load_lanes::<S>(n, 1.0, |i| rays[i].direction.z),
+            },
+            tmax: load_lanes::<S>(n, -1.0, |i| rays[i].tmax),
+        }
+    }
+
+    /// Ray in lane `lane`
+    #[inline]
+    pub fn extract(&self, lane: usize) -> scalar::Ray3 {
+        scalar::Ray3 {
+            origin: self.origin.extract(lane),
+            direction: self.direction.extract(lane),
+            tmax: self.tmax.extract(lane),
+        }
+    }
+
     pub fn at(&self, t: Vf32<S>) -> Point3<S> {
         self.origin + self.direction * Vector3::diag(t)
     }
+
+    /// Offsets `p`, with rounding error `error`, along the normal `n` just far enough that it is on the same
+    /// side of the surface as the direction `w` despite the error
+    ///
+    /// The offset is along `n` rather than `w`, so it is the smallest one that clears the error bounds, and the
+    /// result is moved at least one more ulp away from `p` so the offset survives rounding.
+    #[inline]
+    pub fn offset_origin(p: Point3<S>, error: &Error3<S>, n: &Vector3<S>, w: &Vector3<S>) -> Point3<S> {
+        let zero = Vf32::<S>::zero();
+
+        let d = n.abs().dot(error);
+        let d = w.dot(n).lt(zero).select(-d, d);
+
+        let offset = *n * Vector3::diag(d);
+        let po = p + offset;
+
+        // |c| * EPSILON is at least one ulp of c, and MIN_POSITIVE covers zero
+        let away = |c: Vf32<S>, o: Vf32<S>| {
+            let step = c.abs().mul_add(Vf32::<S>::splat(f32::EPSILON), Vf32::<S>::splat(f32::MIN_POSITIVE));
+            o.gt(zero).select(c + step, o.lt(zero).select(c - step, c))
+        };
+
+        Point3 {
+            x: away(po.x, offset.x),
+            y: away(po.y, offset.y),
+            z: away(po.z, offset.z),
+        }
+    }
+
+    /// Ray leaving a surface at `p`, with rounding error `error` and normal `n`, in `direction`
+    #[inline]
+    pub fn spawn(p: Point3<S>, error: &Error3<S>, n: &Vector3<S>, direction: Vector3<S>) -> Self {
+        Ray3 {
+            origin: Self::offset_origin(p, error, n, &direction),
+            direction,
+            tmax: Vf32::<S>::splat(f32::INFINITY),
+        }
+    }
+
+    /// Ray leaving a surface at `p`, with rounding error `error` and normal `n`, that stops just short of `target`
+    #[inline]
+    pub fn spawn_to(p: Point3<S>, error: &Error3<S>, n: &Vector3<S>, target: Point3<S>) -> Self {
+        let origin = Self::offset_origin(p, error, n, &(target - p));
+
+        Ray3 {
+            origin,
+            direction: target - origin,
+            tmax: Vf32::<S>::splat(1.0 - SHADOW_EPSILON),
+        }
+    }
+
+    /// Reciprocal of each direction component, for slab tests against bounding boxes
+    #[inline(always)]
+    pub fn inv_direction(&self) -> Vector3<S> {
+        let one = Vf32::<S>::one();
+
+        Vector3 {
+            x: one / self.direction.x,
+            y: one / self.direction.y,
+            z: one / self.direction.z,
+        }
+    }
 }
@@ -0,0 +1,122 @@
+use thermite::*;
+
+use crate::math::scalar::gamma;
+
+use super::super::{Point3, Ray3, Transform3, Vector3};
+use super::{azimuth, nearest_root, solve_quadratic, to_object, to_world, SurfaceHit};
+
+/// Open cylinder of `radius` about the object-space z axis, between `z_min` and `z_max`
+///
+/// `u` goes once around the z axis from `+x`, and `v` from `z_min` to `z_max`. Normals point away from the axis.
+#[derive(Debug, Clone, Copy)]
+pub struct Cylinder<S: Simd> {
+    pub object_to_world: Transform3<S>,
+    pub radius: Vf32<S>,
+    pub z_min: Vf32<S>,
+    pub z_max: Vf32<S>,
+}
+
+#[dispatch]
+impl<S: Simd> Cylinder<S> {
+    /// Creates a cylinder between `z0` and `z1`, in any order
+    #[inline(always)]
+    pub fn new(object_to_world: Transform3<S>, radius: Vf32<S>, z0: Vf32<S>, z1: Vf32<S>) -> Self {
+        Cylinder {
+            object_to_world,
+            radius,
+            z_min: z0.min(z1),
+            z_max: z0.max(z1),
+        }
+    }
+
+    /// Nearest hit with `0 < t <= ray.tmax`, shrinking `ray.tmax` in lanes that hit
+    #[inline]
+    pub fn intersect(&self, ray: &mut Ray3<S>) -> SurfaceHit<S> {
+        let zero = Vf32::<S>::zero();
+
+        let r = to_object::<S>(&self.object_to_world, ray);
+        let (o, d) = (r.origin, r.direction);
+
+        let a = d.x.mul_add(d.x, d.y * d.y);
+        let b = Vf32::<S>::splat(2.0) * d.x.mul_add(o.x, d.y * o.y);
+        let c = o.x.mul_add(o.x, o.y * o.y) - self.radius * self.radius;
+
+        // rays along the axis have `a = 0`, and never hit the open sides
+        let (roots, t0, t1) = solve_quadratic::<S>(a, b, c);
+        let (hit, t) = nearest_root::<S>(t0, t1, r.tmax, |t| {
+            let z = t.mul_add(d.z, o.z);
+            roots & z.ge(self.z_min) & z.le(self.z_max)
+        });
+
+        // project back onto the surface, which the rounded hit distance misses slightly
+        let p = r.at(t);
+        let scale = self.radius / p.x.mul_add(p.x, p.y * p.y).sqrt();
+        let p = Point3 {
+            x: p.x * scale,
+            y: p.y * scale,
+            z: p.z,
+        };
+
+        let two_pi = Vf32::<S>::splat(2.0 * std::f32::consts::PI);
+        let u = azimuth::<S>(&p) / two_pi;
+        let v = (p.z - self.z_min) / (self.z_max - self.z_min);
+
+        let dpdu = Vector3 {
+            x: -two_pi * p.y,
+            y: two_pi * p.x,
+            z: zero,
+        };
+
+        let normal = Vector3 {
+            x: p.x / self.radius,
+            y: p.y / self.radius,
+            z: zero,
+        };
+
+        // reprojection leaves x and y within gamma(3) of the surface, and z is unaffected
+        let g3 = Vf32::<S>::splat(gamma(3));
+        let error = Vector3 {
+            x: g3 * p.x.abs(),
+            y: g3 * p.y.abs(),
+            z: zero,
+        };
+
+        to_world::<S>(&self.object_to_world, ray, hit, t, (p, error), normal, dpdu, (u, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, assert_hit, placement, ray, Vf32};
+    use super::*;
+
+    #[test]
+    fn test_cylinder() {
+        let cylinder = Cylinder::new(
+            placement(1.0, [0.0, 0.0, 1.0]),
+            Vf32::splat(1.0),
+            Vf32::splat(1.0),
+            Vf32::splat(-1.0),
+        );
+
+        // from outside, halfway up
+        let mut r = ray([0.0, 3.0, 1.5], [0.0, -1.0, 0.0]);
+        let hit = cylinder.intersect(&mut r);
+        assert_hit(&hit, 2.0, [0.0, 1.0, 0.0]);
+        assert_close(hit.u.extract(0), 0.25);
+        assert_close(hit.v.extract(0), 0.75);
+
+        // the near side is below z_min, so the far side is hit from within
+        let mut r = ray([-3.0, 0.0, -1.5], [2.0, 0.0, 1.0]);
+        let hit = cylinder.intersect(&mut r);
+        assert_hit(&hit, 2.0, [1.0, 0.0, 0.0]);
+        assert_close(hit.point.z.extract(0), 0.5);
+
+        // along the axis, and passing over the top
+        let mut r = ray([0.5, 0.0, -5.0], [0.0, 0.0, 1.0]);
+        assert!(!cylinder.intersect(&mut r).mask.any());
+
+        let mut r = ray([-3.0, 0.0, 2.5], [1.0, 0.0, 0.0]);
+        assert!(!cylinder.intersect(&mut r).mask.any());
+    }
+}
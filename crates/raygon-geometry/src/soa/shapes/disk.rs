@@ -0,0 +1,115 @@
+use thermite::*;
+
+use super::super::{Ray3, Transform3, Vector3};
+use super::{azimuth, no_error, to_object, to_world, SurfaceHit};
+
+/// Disk, or annulus with a nonzero `inner_radius`, in the object-space plane `z = height` centered on the z axis
+///
+/// `u` goes once around the z axis from `+x`, and `v` from the outer edge to the inner edge.
+#[derive(Debug, Clone, Copy)]
+pub struct Disk<S: Simd> {
+    pub object_to_world: Transform3<S>,
+    pub height: Vf32<S>,
+    pub radius: Vf32<S>,
+    pub inner_radius: Vf32<S>,
+}
+
+#[dispatch]
+impl<S: Simd> Disk<S> {
+    #[inline(always)]
+    pub fn new(object_to_world: Transform3<S>, height: Vf32<S>, radius: Vf32<S>, inner_radius: Vf32<S>) -> Self {
+        Disk {
+            object_to_world,
+            height,
+            radius,
+            inner_radius,
+        }
+    }
+
+    /// Hit with `0 < t <= ray.tmax`, shrinking `ray.tmax` in lanes that hit
+    #[inline]
+    pub fn intersect(&self, ray: &mut Ray3<S>) -> SurfaceHit<S> {
+        let zero = Vf32::<S>::zero();
+
+        let r = to_object::<S>(&self.object_to_world, ray);
+
+        // rays parallel to the disk give an infinite or NaN distance, and so an infinite or NaN hit point,
+        // which is never within the radius
+        let t = (self.height - r.origin.z) / r.direction.z;
+        let mut p = r.at(t);
+        p.z = self.height;
+
+        let dist_squared = p.x.mul_add(p.x, p.y * p.y);
+
+        let hit =
+            t.gt(zero) & t.le(r.tmax) & dist_squared.le(self.radius * self.radius) & dist_squared.ge(self.inner_radius * self.inner_radius);
+
+        let two_pi = Vf32::<S>::splat(2.0 * std::f32::consts::PI);
+        let u = azimuth::<S>(&p) / two_pi;
+        let v = (self.radius - dist_squared.sqrt()) / (self.radius - self.inner_radius);
+
+        let dpdu = Vector3 {
+            x: -two_pi * p.y,
+            y: two_pi * p.x,
+            z: zero,
+        };
+
+        let normal = Vector3 {
+            x: zero,
+            y: zero,
+            z: Vf32::<S>::one(),
+        };
+
+        to_world::<S>(&self.object_to_world, ray, hit, t, (p, no_error::<S>()), normal, dpdu, (u, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, assert_hit, placement, ray, Vf32};
+    use super::*;
+
+    #[test]
+    fn test_disk() {
+        let disk = Disk::new(
+            placement(2.0, [1.0, 0.0, 0.0]),
+            Vf32::splat(1.0),
+            Vf32::splat(1.0),
+            Vf32::splat(0.5),
+        );
+
+        // the disk is at world z = 2, spanning radii 1 to 2 about x = 1
+        let mut r = ray([2.5, 0.0, 5.0], [0.0, 0.0, -1.0]);
+        let hit = disk.intersect(&mut r);
+        assert_hit(&hit, 3.0, [0.0, 0.0, 1.0]);
+        assert_close(hit.u.extract(0), 0.0);
+        assert_close(hit.v.extract(0), 0.5);
+
+        // from below, the normal still points along +z
+        let mut r = ray([1.0, -1.5, -1.0], [0.0, 0.0, 1.0]);
+        let hit = disk.intersect(&mut r);
+        assert_hit(&hit, 3.0, [0.0, 0.0, 1.0]);
+        assert_close(hit.u.extract(0), 0.75);
+
+        // through the hole, outside, and parallel
+        for &(origin, direction) in &[
+            ([1.5, 0.0, 5.0], [0.0, 0.0, -1.0]),
+            ([3.5, 0.0, 5.0], [0.0, 0.0, -1.0]),
+            ([2.5, 0.0, 2.0], [1.0, 0.0, 0.0]),
+        ] {
+            let mut r = ray(origin, direction);
+            assert!(!disk.intersect(&mut r).mask.any());
+        }
+    }
+
+    #[test]
+    fn test_disk_center() {
+        // a solid disk hit at its center, where u is undefined
+        let disk = Disk::new(placement(1.0, [0.0; 3]), Vf32::zero(), Vf32::splat(1.0), Vf32::zero());
+
+        let mut r = ray([0.0, 0.0, 1.0], [0.0, 0.0, -1.0]);
+        let hit = disk.intersect(&mut r);
+        assert_hit(&hit, 1.0, [0.0, 0.0, 1.0]);
+        assert_close(hit.v.extract(0), 1.0);
+    }
+}
@@ -0,0 +1,304 @@
+//! Analytic shapes, intersected in their own object space
+//!
+//! Each shape is defined in a canonical position around the origin and placed in the world by its
+//! `object_to_world` transform. Rays are transformed into object space without renormalizing their
+//! direction, so hit distances are the same in both spaces.
+
+use thermite::*;
+
+use crate::math::soa::*;
+
+use super::{tangent::TangentFrame, Error3, Point3, Ray3, Transform3, Vector3};
+
+pub mod cylinder;
+pub mod disk;
+pub mod oriented_box;
+pub mod plane;
+pub mod sphere;
+
+pub use self::{cylinder::Cylinder, disk::Disk, oriented_box::OrientedBox, plane::Plane, sphere::Sphere};
+
+/// Where a ray hit a shape, in world space
+#[derive(Debug, Clone, Copy)]
+pub struct SurfaceHit<S: Simd> {
+    /// Lanes that hit
+    pub mask: Mask<S, Vf32<S>>,
+    pub t: Vf32<S>,
+    pub point: Point3<S>,
+    /// Rounding error of `point`
+    pub error: Error3<S>,
+    /// Unit normal, pointing out of closed shapes and along object-space `+z` for flat ones
+    pub normal: Vector3<S>,
+    pub u: Vf32<S>,
+    pub v: Vf32<S>,
+    /// Frame around `normal`, with its tangent along the direction of increasing `u` where that is defined
+    pub frame: TangentFrame<S>,
+}
+
+#[dispatch]
+impl<S: Simd> SurfaceHit<S> {
+    /// Ray leaving the surface in `direction`, starting far enough from it to not hit it again
+    #[inline]
+    pub fn spawn_ray(&self, direction: Vector3<S>) -> Ray3<S> {
+        Ray3::spawn(self.point, &self.error, &self.normal, direction)
+    }
+
+    /// Ray leaving the surface towards `target`, starting far enough from it to not hit it again
+    #[inline]
+    pub fn spawn_ray_to(&self, target: Point3<S>) -> Ray3<S> {
+        Ray3::spawn_to(self.point, &self.error, &self.normal, target)
+    }
+}
+
+/// Zero error, for hit points snapped exactly onto a plane, where error within the plane is harmless to spawned rays
+#[inline(always)]
+fn no_error<S: Simd>() -> Error3<S> {
+    Vector3::diag(Vf32::<S>::zero())
+}
+
+/// Transforms `ray` into object space
+#[inline(always)]
+fn to_object<S: Simd>(transform: &Transform3<S>, ray: &Ray3<S>) -> Ray3<S> {
+    Ray3 {
+        origin: &transform.inverse * ray.origin,
+        direction: &transform.inverse * ray.direction,
+        tmax: ray.tmax,
+    }
+}
+
+/// Nearest of two roots that lies in `(0, tmax]` and passes `valid`, returning which lanes have one
+#[inline(always)]
+fn nearest_root<S: Simd>(
+    t0: Vf32<S>,
+    t1: Vf32<S>,
+    tmax: Vf32<S>,
+    valid: impl Fn(Vf32<S>) -> Mask<S, Vf32<S>>,
+) -> (Mask<S, Vf32<S>>, Vf32<S>) {
+    let zero = Vf32::<S>::zero();
+
+    let first = t0.gt(zero) & t0.le(tmax) & valid(t0);
+    let second = t1.gt(zero) & t1.le(tmax) & valid(t1);
+
+    (first | second, first.select(t0, t1))
+}
+
+/// Real roots of `a t^2 + b t + c`, in increasing order, and which lanes have them
+#[inline(always)]
+fn solve_quadratic<S: Simd>(a: Vf32<S>, b: Vf32<S>, c: Vf32<S>) -> (Mask<S, Vf32<S>>, Vf32<S>, Vf32<S>) {
+    let zero = Vf32::<S>::zero();
+
+    let discriminant = difference_of_products::<S>(b, b, Vf32::<S>::splat(4.0) * a, c);
+    let root = discriminant.max(zero).sqrt();
+
+    // avoids cancellation between `b` and the root
+    let q = Vf32::<S>::splat(-0.5) * (b + b.lt(zero).select(-root, root));
+
+    let r0 = q / a;
+    let r1 = c / q;
+
+    (discriminant.ge(zero) & !a.eq(zero), r0.min(r1), r0.max(r1))
+}
+
+/// Azimuth of `p` about the z axis, in `[0, 2 pi)`
+#[inline(always)]
+fn azimuth<S: Simd>(p: &Point3<S>) -> Vf32<S> {
+    let phi = p.y.atan2(p.x);
+
+    phi.lt(Vf32::<S>::zero())
+        .select(phi + Vf32::<S>::splat(2.0 * std::f32::consts::PI), phi)
+}
+
+#[inline(always)]
+fn select3<S: Simd>(mask: Mask<S, Vf32<S>>, a: Vector3<S>, b: Vector3<S>) -> Vector3<S> {
+    Vector3 {
+        x: mask.select(a.x, b.x),
+        y: mask.select(a.y, b.y),
+        z: mask.select(a.z, b.z),
+    }
+}
+
+/// Completes an object-space hit, with the rounding error of its point, in world space, and shrinks `ray.tmax` in the lanes that hit
+///
+/// Normals are transformed by the inverse transpose, which keeps them perpendicular to `dpdu`.
+/// Where `dpdu` is degenerate, such as at the poles of a sphere, the frame is built from the normal alone.
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+fn to_world<S: Simd>(
+    transform: &Transform3<S>,
+    ray: &mut Ray3<S>,
+    mask: Mask<S, Vf32<S>>,
+    t: Vf32<S>,
+    (point, error): (Point3<S>, Error3<S>),
+    normal: Vector3<S>,
+    dpdu: Vector3<S>,
+    (u, v): (Vf32<S>, Vf32<S>),
+) -> SurfaceHit<S> {
+    let (point, error) = transform.point_with_errors(point, &error);
+    let normal = transform.normal(normal).normalize();
+    let dpdu = &transform.forward * dpdu;
+
+    let no_tangent = dpdu.norm_squared().eq(Vf32::<S>::zero());
+
+    let partial = TangentFrame::partial(normal, select3::<S>(no_tangent, Vector3::diag(Vf32::<S>::one()), dpdu));
+    let fallback = TangentFrame::new(normal);
+
+    ray.tmax = mask.select(t, ray.tmax);
+
+    SurfaceHit {
+        mask,
+        t,
+        point,
+        error,
+        normal,
+        u,
+        v,
+        frame: TangentFrame {
+            normal,
+            tangent: select3::<S>(no_tangent, fallback.tangent, partial.tangent),
+            bitangent: select3::<S>(no_tangent, fallback.bitangent, partial.bitangent),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::soa::Matrix4;
+
+    pub type Vf32 = <AVX2 as Simd>::Vf32;
+
+    pub const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    pub fn vector(x: f32, y: f32, z: f32) -> Vector3<AVX2> {
+        Vector3 {
+            x: Vf32::splat(x),
+            y: Vf32::splat(y),
+            z: Vf32::splat(z),
+        }
+    }
+
+    pub fn ray(origin: [f32; 3], direction: [f32; 3]) -> Ray3<AVX2> {
+        let o = vector(origin[0], origin[1], origin[2]);
+
+        Ray3 {
+            origin: Point3 { x: o.x, y: o.y, z: o.z },
+            direction: vector(direction[0], direction[1], direction[2]),
+            tmax: Vf32::splat(f32::INFINITY),
+        }
+    }
+
+    /// Scales by `scale` then moves by `offset`
+    pub fn placement(scale: f32, offset: [f32; 3]) -> Transform3<AVX2> {
+        let s = Vf32::splat(scale);
+        let (zero, one) = (Vf32::zero(), Vf32::one());
+
+        Transform3::from_forward(Matrix4([
+            [s, zero, zero, Vf32::splat(offset[0])],
+            [zero, s, zero, Vf32::splat(offset[1])],
+            [zero, zero, s, Vf32::splat(offset[2])],
+            [zero, zero, zero, one],
+        ]))
+        .0
+    }
+
+    pub fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    /// Checks that every lane hit at `t`, with the given normal, and an orthonormal frame around it
+    pub fn assert_hit(hit: &SurfaceHit<AVX2>, t: f32, normal: [f32; 3]) {
+        assert!(hit.mask.all());
+
+        let f = &hit.frame;
+
+        for lane in 0..LANES {
+            assert_close(hit.t.extract(lane), t);
+            assert_close(hit.normal.x.extract(lane), normal[0]);
+            assert_close(hit.normal.y.extract(lane), normal[1]);
+            assert_close(hit.normal.z.extract(lane), normal[2]);
+
+            for (a, b, expected) in &[
+                (f.tangent, f.tangent, 1.0),
+                (f.bitangent, f.bitangent, 1.0),
+                (f.normal, f.normal, 1.0),
+                (f.tangent, f.bitangent, 0.0),
+                (f.tangent, f.normal, 0.0),
+                (f.bitangent, f.normal, 0.0),
+            ] {
+                assert_close(a.dot(b).extract(lane), *expected);
+            }
+        }
+    }
+
+    /// Lanes of pseudo-random values in `[-1, 1)`
+    fn random_lanes(state: &mut u32) -> Vf32 {
+        let values: Vec<f32> = (0..LANES)
+            .map(|_| {
+                *state = state.wrapping_mul(747796405).wrapping_add(2891336453);
+                (*state >> 8) as f32 / (1 << 23) as f32 - 1.0
+            })
+            .collect();
+
+        Vf32::load_unaligned(&values)
+    }
+
+    #[test]
+    fn test_spawned_rays_leave_the_surface() {
+        let far = [1000.0, -500.0, 300.0];
+        let sphere = Sphere::new(placement(10.0, far), Vf32::splat(1.0));
+        let plane = Plane::new(placement(3.0, far));
+
+        let mut state = 1;
+
+        for _ in 0..100 {
+            let mut r = ray([far[0] + 50.0, far[1], far[2]], [-1.0, 0.0, 0.0]);
+            r.direction.y = random_lanes(&mut state) * Vf32::splat(0.1);
+            r.direction.z = random_lanes(&mut state) * Vf32::splat(0.1);
+
+            let hit = sphere.intersect(&mut r);
+            assert!(hit.mask.all());
+
+            // reflected off the outside, and back towards where the ray came from
+            let d = r.direction;
+            let reflected = d - hit.normal * Vector3::diag(Vf32::splat(2.0) * d.dot(&hit.normal));
+
+            assert!(!sphere.intersect(&mut hit.spawn_ray(reflected)).mask.any());
+            assert!(!sphere.intersect(&mut hit.spawn_ray_to(r.origin)).mask.any());
+
+            // into the sphere, hitting the far side rather than the same point
+            let mut inside = hit.spawn_ray(d);
+            assert!(sphere.intersect(&mut inside).t.gt(Vf32::splat(1.0)).all());
+
+            // both sides of a plane
+            let mut r = ray([far[0] + 7.0, far[1] - 3.0, far[2] + 20.0], [0.0, 0.0, -1.0]);
+            r.direction.x = random_lanes(&mut state);
+            r.direction.y = random_lanes(&mut state);
+
+            let hit = plane.intersect(&mut r);
+            assert!(hit.mask.all());
+
+            let d = r.direction;
+            let reflected = d - hit.normal * Vector3::diag(Vf32::splat(2.0) * d.dot(&hit.normal));
+
+            assert!(!plane.intersect(&mut hit.spawn_ray(reflected)).mask.any());
+            assert!(!plane.intersect(&mut hit.spawn_ray(d)).mask.any());
+        }
+    }
+
+    #[test]
+    fn test_solve_quadratic() {
+        // (t - 1)(t - 3), with the roots in either order of magnitude of `b`
+        let (mask, t0, t1) = solve_quadratic::<AVX2>(Vf32::splat(1.0), Vf32::splat(-4.0), Vf32::splat(3.0));
+        assert!(mask.all());
+        assert_eq!((t0.extract(0), t1.extract(0)), (1.0, 3.0));
+
+        let (mask, t0, t1) = solve_quadratic::<AVX2>(Vf32::splat(-1.0), Vf32::splat(-4.0), Vf32::splat(-3.0));
+        assert!(mask.all());
+        assert_eq!((t0.extract(0), t1.extract(0)), (-3.0, -1.0));
+
+        let (mask, ..) = solve_quadratic::<AVX2>(Vf32::splat(1.0), Vf32::splat(0.0), Vf32::splat(1.0));
+        assert!(!mask.any());
+    }
+}
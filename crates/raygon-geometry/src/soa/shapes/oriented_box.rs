@@ -0,0 +1,163 @@
+use thermite::*;
+
+use super::super::{Bounds3, Point3, Ray3, Transform3, Vector3};
+use super::{no_error, to_object, to_world, SurfaceHit};
+
+/// Box that is axis-aligned in object space, and oriented however `object_to_world` places it
+///
+/// On each face, `u` and `v` go from zero to one along the next two axes in cyclic order,
+/// so the `+x` face is parameterized by `(y, z)`, the `+y` face by `(z, x)` and the `+z` face by `(x, y)`.
+#[derive(Debug, Clone, Copy)]
+pub struct OrientedBox<S: Simd> {
+    pub object_to_world: Transform3<S>,
+    pub bounds: Bounds3<S>,
+}
+
+#[dispatch]
+impl<S: Simd> OrientedBox<S> {
+    #[inline(always)]
+    pub fn new(object_to_world: Transform3<S>, bounds: Bounds3<S>) -> Self {
+        OrientedBox { object_to_world, bounds }
+    }
+
+    /// Nearest hit with `0 < t <= ray.tmax`, shrinking `ray.tmax` in lanes that hit
+    ///
+    /// Rays starting inside the box hit it where they leave.
+    #[inline]
+    pub fn intersect(&self, ray: &mut Ray3<S>) -> SurfaceHit<S> {
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+
+        let r = to_object::<S>(&self.object_to_world, ray);
+        let inv_dir = r.inv_direction();
+
+        let min = [self.bounds.min.x, self.bounds.min.y, self.bounds.min.z];
+        let max = [self.bounds.max.x, self.bounds.max.y, self.bounds.max.z];
+        let origin = [r.origin.x, r.origin.y, r.origin.z];
+        let inv = [inv_dir.x, inv_dir.y, inv_dir.z];
+
+        // unlike `Bounds3::intersect_p`, the exact entry and exit distances are needed rather than a conservative range
+        let mut t0 = Vf32::<S>::splat(f32::NEG_INFINITY);
+        let mut t1 = Vf32::<S>::splat(f32::INFINITY);
+
+        for k in 0..3 {
+            let near = (min[k] - origin[k]) * inv[k];
+            let far = (max[k] - origin[k]) * inv[k];
+
+            // written so NaN, from rays in the plane of a slab, leaves the interval unchanged
+            let swap = near.gt(far);
+            let (near, far) = (swap.select(far, near), swap.select(near, far));
+
+            t0 = near.gt(t0).select(near, t0);
+            t1 = far.lt(t1).select(far, t1);
+        }
+
+        let inside = t0.le(zero);
+        let t = inside.select(t1, t0);
+        let hit = t0.le(t1) & t.gt(zero) & t.le(r.tmax) & !self.bounds.is_empty();
+
+        // the face is along whichever axis the hit point is relatively furthest from the center
+        let center = self.bounds.centroid();
+        let half = self.bounds.diagonal() * Vector3::diag(Vf32::<S>::splat(0.5));
+
+        let p = r.at(t);
+        let rel = (p - center)
+            * Vector3 {
+                x: one / half.x,
+                y: one / half.y,
+                z: one / half.z,
+            };
+
+        let (ax, ay, az) = (rel.x.abs(), rel.y.abs(), rel.z.abs());
+        let on_x = ax.ge(ay) & ax.ge(az);
+        let on_y = !on_x & ay.ge(az);
+        let on_z = !on_x & !on_y;
+
+        // snap the hit point onto its face
+        let snap = |on: Mask<S, Vf32<S>>, c: Vf32<S>, rel: Vf32<S>, min: Vf32<S>, max: Vf32<S>| on.select(rel.gt(zero).select(max, min), c);
+        let p = Point3 {
+            x: snap(on_x, p.x, rel.x, min[0], max[0]),
+            y: snap(on_y, p.y, rel.y, min[1], max[1]),
+            z: snap(on_z, p.z, rel.z, min[2], max[2]),
+        };
+
+        let sign = |on: Mask<S, Vf32<S>>, rel: Vf32<S>| on.select(rel.gt(zero).select(one, -one), zero);
+        let normal = Vector3 {
+            x: sign(on_x, rel.x),
+            y: sign(on_y, rel.y),
+            z: sign(on_z, rel.z),
+        };
+
+        // offsets of the hit point along each axis, from zero at `min` to one at `max`
+        let offset = |k: usize, c: Vf32<S>| (c - min[k]) / (max[k] - min[k]);
+        let (ox, oy, oz) = (offset(0, p.x), offset(1, p.y), offset(2, p.z));
+
+        let u = on_x.select(oy, on_y.select(oz, ox));
+        let v = on_x.select(oz, on_y.select(ox, oy));
+
+        let dpdu = Vector3 {
+            x: on_z.select(one, zero),
+            y: on_x.select(one, zero),
+            z: on_y.select(one, zero),
+        };
+
+        to_world::<S>(&self.object_to_world, ray, hit, t, (p, no_error::<S>()), normal, dpdu, (u, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, assert_hit, placement, ray, Vf32};
+    use super::*;
+
+    #[test]
+    fn test_oriented_box() {
+        let bounds = Bounds3 {
+            min: Point3 {
+                x: Vf32::splat(-1.0),
+                y: Vf32::splat(-2.0),
+                z: Vf32::splat(-3.0),
+            },
+            max: Point3 {
+                x: Vf32::splat(1.0),
+                y: Vf32::splat(2.0),
+                z: Vf32::splat(3.0),
+            },
+        };
+
+        let cube = OrientedBox::new(placement(1.0, [10.0, 0.0, 0.0]), bounds);
+
+        // each face from outside
+        for &(origin, direction, t, normal) in &[
+            ([6.0, 1.0, 0.0], [1.0, 0.0, 0.0], 3.0, [-1.0, 0.0, 0.0]),
+            ([14.0, 1.0, 0.0], [-1.0, 0.0, 0.0], 3.0, [1.0, 0.0, 0.0]),
+            ([10.0, -4.0, 1.0], [0.0, 1.0, 0.0], 2.0, [0.0, -1.0, 0.0]),
+            ([10.5, 1.0, 5.0], [0.0, 0.0, -1.0], 2.0, [0.0, 0.0, 1.0]),
+        ] {
+            let mut r = ray(origin, direction);
+            assert_hit(&cube.intersect(&mut r), t, normal);
+        }
+
+        // uv on the +x face are (y, z)
+        let mut r = ray([14.0, 1.0, 1.5], [-1.0, 0.0, 0.0]);
+        let hit = cube.intersect(&mut r);
+        assert_close(hit.u.extract(0), 0.75);
+        assert_close(hit.v.extract(0), 0.75);
+        assert_close(hit.frame.tangent.y.extract(0), 1.0);
+
+        // from inside, hitting the far face
+        let mut r = ray([10.0, 0.0, 0.0], [0.0, 0.0, 1.0]);
+        assert_hit(&cube.intersect(&mut r), 3.0, [0.0, 0.0, 1.0]);
+
+        // passing beside, pointing away, and stopping short
+        let mut r = ray([6.0, 2.5, 0.0], [1.0, 0.0, 0.0]);
+        assert!(!cube.intersect(&mut r).mask.any());
+
+        let mut r = ray([6.0, 1.0, 0.0], [-1.0, 0.0, 0.0]);
+        assert!(!cube.intersect(&mut r).mask.any());
+
+        let mut r = ray([6.0, 1.0, 0.0], [1.0, 0.0, 0.0]);
+        r.tmax = Vf32::splat(2.0);
+        assert!(!cube.intersect(&mut r).mask.any());
+    }
+}
@@ -0,0 +1,73 @@
+use thermite::*;
+
+use super::super::{Ray3, Transform3, Vector3};
+use super::{no_error, to_object, to_world, SurfaceHit};
+
+/// Infinite plane through the object-space origin, perpendicular to the z axis
+///
+/// `u` and `v` are the object-space x and y coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct Plane<S: Simd> {
+    pub object_to_world: Transform3<S>,
+}
+
+#[dispatch]
+impl<S: Simd> Plane<S> {
+    #[inline(always)]
+    pub fn new(object_to_world: Transform3<S>) -> Self {
+        Plane { object_to_world }
+    }
+
+    /// Hit with `0 < t <= ray.tmax`, shrinking `ray.tmax` in lanes that hit
+    #[inline]
+    pub fn intersect(&self, ray: &mut Ray3<S>) -> SurfaceHit<S> {
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+
+        let r = to_object::<S>(&self.object_to_world, ray);
+
+        // rays parallel to the plane are masked out, as those below it get `t = +inf`, which passes an infinite tmax
+        let t = -r.origin.z / r.direction.z;
+        let hit = t.gt(zero) & t.le(r.tmax) & !r.direction.z.eq(zero);
+
+        let mut p = r.at(t);
+        p.z = zero;
+
+        let dpdu = Vector3 { x: one, y: zero, z: zero };
+        let normal = Vector3 { x: zero, y: zero, z: one };
+
+        to_world::<S>(&self.object_to_world, ray, hit, t, (p, no_error::<S>()), normal, dpdu, (p.x, p.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, assert_hit, placement, ray};
+    use super::*;
+
+    #[test]
+    fn test_plane() {
+        let plane = Plane::new(placement(2.0, [0.0, 0.0, -1.0]));
+
+        let mut r = ray([3.0, 1.0, 1.0], [0.0, 0.0, -1.0]);
+        let hit = plane.intersect(&mut r);
+        assert_hit(&hit, 2.0, [0.0, 0.0, 1.0]);
+        assert_close(hit.u.extract(0), 1.5);
+        assert_close(hit.v.extract(0), 0.5);
+
+        let f = &hit.frame;
+        assert_close(f.tangent.x.extract(0), 1.0);
+        assert_close(f.bitangent.y.extract(0), 1.0);
+
+        // pointing away, and parallel above and below the plane
+        for &origin in &[[3.0, 1.0, 1.0], [3.0, 1.0, -3.0]] {
+            let mut r = ray(origin, [0.0, 0.0, 1.0]);
+            assert_eq!(plane.intersect(&mut r).mask.any(), origin[2] < -1.0);
+
+            for &direction in &[[1.0, 0.0, 0.0], [0.0, -1.0, 0.0]] {
+                let mut r = ray(origin, direction);
+                assert!(!plane.intersect(&mut r).mask.any());
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use thermite::*;
+
+use crate::math::scalar::gamma;
+
+use super::super::{Point3, Ray3, Transform3, Vector3};
+use super::{azimuth, nearest_root, solve_quadratic, to_object, to_world, SurfaceHit};
+
+/// Sphere of `radius` about the object-space origin
+///
+/// `u` goes once around the z axis from `+x`, and `v` from the pole at `-z` to the pole at `+z`.
+#[derive(Debug, Clone, Copy)]
+pub struct Sphere<S: Simd> {
+    pub object_to_world: Transform3<S>,
+    pub radius: Vf32<S>,
+}
+
+#[dispatch]
+impl<S: Simd> Sphere<S> {
+    #[inline(always)]
+    pub fn new(object_to_world: Transform3<S>, radius: Vf32<S>) -> Self {
+        Sphere { object_to_world, radius }
+    }
+
+    /// Nearest hit with `0 < t <= ray.tmax`, shrinking `ray.tmax` in lanes that hit
+    #[inline]
+    pub fn intersect(&self, ray: &mut Ray3<S>) -> SurfaceHit<S> {
+        let r = to_object::<S>(&self.object_to_world, ray);
+        let o = r.origin.coords();
+
+        let a = r.direction.norm_squared();
+        let b = Vf32::<S>::splat(2.0) * r.direction.dot(&o);
+        let c = o.norm_squared() - self.radius * self.radius;
+
+        let (roots, t0, t1) = solve_quadratic::<S>(a, b, c);
+        let (hit, t) = nearest_root::<S>(t0, t1, r.tmax, |_| roots);
+
+        // project back onto the surface, which the rounded hit distance misses slightly
+        let p = r.at(t).coords();
+        let p = p * Vector3::diag(self.radius / p.norm());
+        let p = Point3 { x: p.x, y: p.y, z: p.z };
+
+        let phi = azimuth::<S>(&p);
+        let theta = (p.z / self.radius).clamp(Vf32::<S>::neg_one(), Vf32::<S>::one()).acos();
+
+        let two_pi = Vf32::<S>::splat(2.0 * std::f32::consts::PI);
+        let u = phi / two_pi;
+        let v = Vf32::<S>::one() - theta * Vf32::<S>::splat(std::f32::consts::FRAC_1_PI);
+
+        let dpdu = Vector3 {
+            x: -two_pi * p.y,
+            y: two_pi * p.x,
+            z: Vf32::<S>::zero(),
+        };
+
+        let normal = p.coords() * Vector3::diag(Vf32::<S>::one() / self.radius);
+
+        // reprojection leaves each coordinate within gamma(5) of the surface
+        let error = p.coords().abs() * Vector3::diag(Vf32::<S>::splat(gamma(5)));
+
+        to_world::<S>(&self.object_to_world, ray, hit, t, (p, error), normal, dpdu, (u, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, assert_hit, placement, ray, Vf32, LANES};
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    #[test]
+    fn test_sphere() {
+        let sphere = Sphere::new(placement(2.0, [0.0, 0.0, 5.0]), Vf32::splat(1.0));
+
+        // from outside, hitting the equator at +x
+        let mut r = ray([4.0, 0.0, 5.0], [-1.0, 0.0, 0.0]);
+        let hit = sphere.intersect(&mut r);
+        assert_hit(&hit, 2.0, [1.0, 0.0, 0.0]);
+        assert_close(hit.u.extract(0), 0.0);
+        assert_close(hit.v.extract(0), 0.5);
+        assert_close(hit.point.x.extract(0), 2.0);
+        assert_eq!(r.tmax.extract(0), hit.t.extract(0));
+
+        // from inside, and through the +z pole, where u is undefined
+        let mut r = ray([0.0, 0.0, 5.0], [0.0, 0.0, 1.0]);
+        let hit = sphere.intersect(&mut r);
+        assert_hit(&hit, 2.0, [0.0, 0.0, 1.0]);
+        assert_close(hit.v.extract(0), 1.0);
+
+        // a quarter turn around from +x
+        let mut r = ray([0.0, -4.0, 5.0], [0.0, 1.0, 0.0]);
+        let hit = sphere.intersect(&mut r);
+        assert_hit(&hit, 2.0, [0.0, -1.0, 0.0]);
+        assert_close(hit.u.extract(0), 0.75);
+
+        // misses, behind and beyond tmax
+        let mut r = ray([0.0, 3.0, 5.0], [1.0, 0.0, 0.0]);
+        assert!(!sphere.intersect(&mut r).mask.any());
+
+        let mut r = ray([4.0, 0.0, 5.0], [1.0, 0.0, 0.0]);
+        assert!(!sphere.intersect(&mut r).mask.any());
+
+        let mut r = ray([4.0, 0.0, 5.0], [-1.0, 0.0, 0.0]);
+        r.tmax = Vf32::splat(1.0);
+        assert!(!sphere.intersect(&mut r).mask.any());
+        assert_eq!(r.tmax.extract(0), 1.0);
+    }
+
+    #[test]
+    fn test_sphere_packet() {
+        let sphere = Sphere::<AVX2>::new(placement(1.0, [0.0; 3]), Vf32::splat(1.0));
+
+        // rays at increasing heights, of which those below the radius hit
+        let heights: Vec<f32> = (0..LANES).map(|lane| lane as f32 * 0.25).collect();
+        let mut r = ray([-5.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        r.origin.z = Vf32::load_unaligned(&heights);
+
+        let hit = sphere.intersect(&mut r);
+        let hits = hit.mask.select(Vf32::one(), Vf32::zero());
+
+        for (lane, &z) in heights.iter().enumerate() {
+            assert_eq!(hits.extract(lane) != 0.0, z <= 1.0, "lane {}", lane);
+
+            if z < 1.0 {
+                assert_close(hit.t.extract(lane), 5.0 - (1.0 - z * z).sqrt());
+            }
+        }
+    }
+}
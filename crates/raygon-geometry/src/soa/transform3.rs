@@ -2,9 +2,12 @@ use thermite::*;
 
 use std::ops::*;
 
+use crate::math::scalar::gamma;
 use crate::math::soa::*;
 
-use super::{Matrix4, Vector3};
+use crate::scalar;
+
+use super::{load_lanes, matrix4::inner_product6, Bounds3, Error3, Matrix4, Point3, Ray3, Ray3Error3, Vector3};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Transform3<S: Simd> {
@@ -31,6 +34,41 @@ impl<S: Simd> Transform3<S> {
         }
     }
 
+    /// The same transform in every lane
+    #[inline]
+    pub fn splat(transform: &scalar::Transform3) -> Self {
+        Transform3 {
+            forward: Matrix4::splat_matrix(&transform.forward),
+            inverse: Matrix4::splat_matrix(&transform.inverse),
+        }
+    }
+
+    /// Loads one transform per lane from `transforms`, filling any remaining lanes with the identity
+    #[inline]
+    pub fn load(transforms: &[scalar::Transform3]) -> Self {
+        let mut res = Transform3::identity();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                let fill = if i == j { 1.0 } else { 0.0 };
+
+                res.forward[i][j] = load_lanes::<S>(transforms.len(), fill, |lane| transforms[lane].forward[i][j]);
+                res.inverse[i][j] = load_lanes::<S>(transforms.len(), fill, |lane| transforms[lane].inverse[i][j]);
+            }
+        }
+
+        res
+    }
+
+    /// Transform in lane `lane`
+    #[inline]
+    pub fn extract(&self, lane: usize) -> scalar::Transform3 {
+        scalar::Transform3 {
+            forward: self.forward.extract(lane),
+            inverse: self.inverse.extract(lane),
+        }
+    }
+
     #[inline]
     pub fn from_forward(forward: Matrix4<S>) -> (Self, Mask<S, Vf32<S>>) {
         let (inverse, mask) = forward.inverse();
@@ -74,9 +112,9 @@ impl<S: Simd> Transform3<S> {
         let Vector3 { mut x, mut y, mut z } = axis;
 
         // set axis to +X if no axis was found. The angle is zero anyway
-        x = mask.select(x, one);
-        y = mask.select(y, zero);
-        z = mask.select(z, zero);
+        x = mask.select(one, x);
+        y = mask.select(zero, y);
+        z = mask.select(zero, z);
 
         // precompute these
         let cyy = c.nmul_add(y, y);
@@ -124,4 +162,606 @@ impl<S: Simd> Transform3<S> {
             inverse: forward.transpose(),
         }
     }
+
+    /// Moves by `delta`
+    #[inline]
+    pub fn translate(delta: &Vector3<S>) -> Self {
+        let one = Vf32::<S>::one();
+        let zero = Vf32::<S>::zero();
+
+        let by = |d: &Vector3<S>| {
+            Matrix4([
+                [one, zero, zero, d.x],
+                [zero, one, zero, d.y],
+                [zero, zero, one, d.z],
+                [zero, zero, zero, one],
+            ])
+        };
+
+        Transform3 {
+            forward: by(delta),
+            inverse: by(&-*delta),
+        }
+    }
+
+    /// World-to-camera transform for a camera at `position` looking at `target`, with `up` roughly above it
+    ///
+    /// The camera looks down its `+z` axis, with `+y` up and `+x` to the right. The mask is set in lanes where
+    /// `target` coincides with `position` or `up` is parallel to the view direction.
+    #[inline]
+    pub fn look_at(position: Point3<S>, target: Point3<S>, up: &Vector3<S>) -> (Self, Mask<S, Vf32<S>>) {
+        let one = Vf32::<S>::one();
+        let zero = Vf32::<S>::zero();
+
+        let (dir, _, no_dir) = (target - position).normalize_len_mask();
+        let (right, _, no_right) = up.cross(&dir).normalize_len_mask();
+        let new_up = dir.cross(&right);
+
+        let p = position.coords();
+
+        // the camera axes are the columns of camera-to-world, so its rotation inverts by transposing
+        let inverse = Matrix4([
+            [right.x, new_up.x, dir.x, p.x],
+            [right.y, new_up.y, dir.y, p.y],
+            [right.z, new_up.z, dir.z, p.z],
+            [zero, zero, zero, one],
+        ]);
+
+        let forward = Matrix4([
+            [right.x, right.y, right.z, -right.dot(&p)],
+            [new_up.x, new_up.y, new_up.z, -new_up.dot(&p)],
+            [dir.x, dir.y, dir.z, -dir.dot(&p)],
+            [zero, zero, zero, one],
+        ]);
+
+        (Transform3 { forward, inverse }, no_dir | no_right)
+    }
+
+    /// Perspective projection of camera space, looking down `+z`, with a vertical field of view of `fov` radians
+    ///
+    /// Points on the near and far planes are mapped to `z = 0` and `z = 1`, and the edges of the field of view to
+    /// `x, y = ±1`. The mask is set in lanes where the field of view is outside `(0, pi)` or `0 < near < far` doesn't hold.
+    #[inline]
+    pub fn perspective(fov: Vf32<S>, near: Vf32<S>, far: Vf32<S>) -> (Self, Mask<S, Vf32<S>>) {
+        let one = Vf32::<S>::one();
+        let zero = Vf32::<S>::zero();
+
+        let (s, c) = (fov * Vf32::<S>::splat(0.5)).sin_cos();
+        let tan = s / c;
+        let inv_tan = c / s;
+
+        let depth = far - near;
+
+        let forward = Matrix4([
+            [inv_tan, zero, zero, zero],
+            [zero, inv_tan, zero, zero],
+            [zero, zero, far / depth, -(far * near) / depth],
+            [zero, zero, one, zero],
+        ]);
+
+        let inverse = Matrix4([
+            [tan, zero, zero, zero],
+            [zero, tan, zero, zero],
+            [zero, zero, zero, one],
+            [zero, zero, -depth / (far * near), one / near],
+        ]);
+
+        let invalid = !(fov.gt(zero) & fov.lt(Vf32::<S>::splat(std::f32::consts::PI)) & near.gt(zero) & far.gt(near));
+
+        (Transform3 { forward, inverse }, invalid)
+    }
+
+    /// Orthographic projection of camera space, mapping `z` in `[near, far]` to `[0, 1]` and leaving `x` and `y` as they are
+    ///
+    /// The mask is set in lanes where `near` and `far` are equal.
+    #[inline]
+    pub fn orthographic(near: Vf32<S>, far: Vf32<S>) -> (Self, Mask<S, Vf32<S>>) {
+        let one = Vf32::<S>::one();
+        let zero = Vf32::<S>::zero();
+
+        let depth = far - near;
+        let inv_depth = one / depth;
+
+        let forward = Matrix4([
+            [one, zero, zero, zero],
+            [zero, one, zero, zero],
+            [zero, zero, inv_depth, -near * inv_depth],
+            [zero, zero, zero, one],
+        ]);
+
+        let inverse = Matrix4([
+            [one, zero, zero, zero],
+            [zero, one, zero, zero],
+            [zero, zero, depth, near],
+            [zero, zero, zero, one],
+        ]);
+
+        (Transform3 { forward, inverse }, depth.eq(zero))
+    }
+
+    /// The opposite transform, without recomputing any inverse
+    #[inline(always)]
+    pub fn invert(&self) -> Self {
+        Transform3 {
+            forward: self.inverse,
+            inverse: self.forward,
+        }
+    }
+
+    /// Transforms a surface normal by the inverse transpose, keeping it perpendicular to transformed tangents
+    ///
+    /// The result is not renormalized.
+    #[inline]
+    pub fn normal(&self, n: Vector3<S>) -> Vector3<S> {
+        let m = &self.inverse;
+
+        Vector3 {
+            x: inner_product6::<S>(m[0][0], n.x, m[1][0], n.y, m[2][0], n.z),
+            y: inner_product6::<S>(m[0][1], n.x, m[1][1], n.y, m[2][1], n.z),
+            z: inner_product6::<S>(m[0][2], n.x, m[1][2], n.y, m[2][2], n.z),
+        }
+    }
+
+    /// Transforms `p`, also returning a bound on the rounding error of the result
+    ///
+    /// This and the other error-tracking transforms assume the transform is affine, as all of the
+    /// constructors here produce.
+    #[inline]
+    pub fn point_with_error(&self, p: Point3<S>) -> (Point3<S>, Error3<S>) {
+        let m = &self.forward;
+        let g3 = Vf32::<S>::splat(gamma(3));
+
+        let abs_sum = |i: usize| (m[i][0] * p.x).abs() + (m[i][1] * p.y).abs() + (m[i][2] * p.z).abs() + m[i][3].abs();
+
+        let error = Vector3 {
+            x: g3 * abs_sum(0),
+            y: g3 * abs_sum(1),
+            z: g3 * abs_sum(2),
+        };
+
+        (m * p, error)
+    }
+
+    /// Transforms `p`, which already has rounding error `p_error`, returning the result and its accumulated error
+    #[inline]
+    pub fn point_with_errors(&self, p: Point3<S>, p_error: &Error3<S>) -> (Point3<S>, Error3<S>) {
+        let m = &self.forward;
+        let g3 = Vf32::<S>::splat(gamma(3));
+        let g3_1 = Vf32::<S>::splat(gamma(3) + 1.0);
+
+        let error_sum = |i: usize| m[i][0].abs() * p_error.x + m[i][1].abs() * p_error.y + m[i][2].abs() * p_error.z;
+        let abs_sum = |i: usize| (m[i][0] * p.x).abs() + (m[i][1] * p.y).abs() + (m[i][2] * p.z).abs() + m[i][3].abs();
+
+        let error = Vector3 {
+            x: g3_1.mul_add(error_sum(0), g3 * abs_sum(0)),
+            y: g3_1.mul_add(error_sum(1), g3 * abs_sum(1)),
+            z: g3_1.mul_add(error_sum(2), g3 * abs_sum(2)),
+        };
+
+        (m * p, error)
+    }
+
+    /// Transforms `v`, also returning a bound on the rounding error of the result
+    #[inline]
+    pub fn vector_with_error(&self, v: Vector3<S>) -> (Vector3<S>, Error3<S>) {
+        let m = &self.forward;
+        let g3 = Vf32::<S>::splat(gamma(3));
+
+        let abs_sum = |i: usize| (m[i][0] * v.x).abs() + (m[i][1] * v.y).abs() + (m[i][2] * v.z).abs();
+
+        let error = Vector3 {
+            x: g3 * abs_sum(0),
+            y: g3 * abs_sum(1),
+            z: g3 * abs_sum(2),
+        };
+
+        (m * v, error)
+    }
+
+    /// Transforms `ray`, also returning bounds on the rounding error of its origin and direction
+    ///
+    /// The origin is moved along the direction to the far edge of its error bounds, and `tmax` shortened to match,
+    /// so the transformed ray can't start behind the surface it left.
+    #[inline]
+    pub fn ray_with_error(&self, ray: &Ray3<S>) -> (Ray3<S>, Ray3Error3<S>) {
+        let (origin, pos) = self.point_with_error(ray.origin);
+        let (direction, dir) = self.vector_with_error(ray.direction);
+
+        let length_squared = direction.norm_squared();
+        let dt = length_squared
+            .gt(Vf32::<S>::zero())
+            .select(direction.abs().dot(&pos) / length_squared, Vf32::<S>::zero());
+
+        let ray = Ray3 {
+            origin: origin + direction * Vector3::diag(dt),
+            direction,
+            tmax: ray.tmax - dt,
+        };
+
+        (ray, Ray3Error3 { pos, dir })
+    }
+}
+
+/// Applies `rhs` first, then `self`
+impl<S: Simd> Mul for &Transform3<S> {
+    type Output = Transform3<S>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Transform3<S> {
+        Transform3 {
+            forward: &self.forward * &rhs.forward,
+            inverse: &rhs.inverse * &self.inverse,
+        }
+    }
+}
+
+impl<S: Simd> Mul<Point3<S>> for &Transform3<S> {
+    type Output = Point3<S>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Point3<S>) -> Point3<S> {
+        &self.forward * rhs
+    }
+}
+
+impl<S: Simd> Mul<Vector3<S>> for &Transform3<S> {
+    type Output = Vector3<S>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Vector3<S>) -> Vector3<S> {
+        &self.forward * rhs
+    }
+}
+
+/// Transforms the origin and direction, without renormalizing the direction, so `tmax` and hit distances are unchanged
+impl<S: Simd> Mul<Ray3<S>> for &Transform3<S> {
+    type Output = Ray3<S>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Ray3<S>) -> Ray3<S> {
+        Ray3 {
+            origin: &self.forward * rhs.origin,
+            direction: &self.forward * rhs.direction,
+            tmax: rhs.tmax,
+        }
+    }
+}
+
+impl<S: Simd> Mul<Bounds3<S>> for &Transform3<S> {
+    type Output = Bounds3<S>;
+
+    #[inline(always)]
+    fn mul(self, rhs: Bounds3<S>) -> Bounds3<S> {
+        rhs.transform(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::scalar;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    /// Rotation about z with an awkward angle, a non-uniform scale and a large translation
+    const FORWARD: [[f32; 4]; 3] = [[0.6, -0.8, 0.0, 1234.567], [1.2, 0.9, 0.0, -987.654], [0.0, 0.0, 3.3, 0.001]];
+
+    fn transform() -> Transform3<AVX2> {
+        let row = |r: [f32; 4]| [Vf32::splat(r[0]), Vf32::splat(r[1]), Vf32::splat(r[2]), Vf32::splat(r[3])];
+
+        Transform3::from_forward(Matrix4([
+            row(FORWARD[0]),
+            row(FORWARD[1]),
+            row(FORWARD[2]),
+            row([0.0, 0.0, 0.0, 1.0]),
+        ]))
+        .0
+    }
+
+    fn point(lane: usize) -> [f32; 3] {
+        let l = lane as f32;
+        [0.1 + l * 17.3, -5.7 * l + 0.01, 1e3 / (l + 3.0)]
+    }
+
+    fn lanes(f: impl Fn(usize) -> f32) -> Vf32 {
+        Vf32::load_unaligned(&(0..LANES).map(f).collect::<Vec<_>>())
+    }
+
+    /// The transformed point, in double precision
+    fn exact(p: [f32; 3], w: f64) -> [f64; 3] {
+        let mut result = [0.0; 3];
+
+        for (i, row) in FORWARD.iter().enumerate() {
+            result[i] = row[0] as f64 * p[0] as f64 + row[1] as f64 * p[1] as f64 + row[2] as f64 * p[2] as f64 + row[3] as f64 * w;
+        }
+
+        result
+    }
+
+    fn components(v: &Vector3<AVX2>, lane: usize) -> [f32; 3] {
+        [v.x.extract(lane), v.y.extract(lane), v.z.extract(lane)]
+    }
+
+    #[test]
+    fn test_point_and_vector_error_bounds() {
+        let p = Point3 {
+            x: lanes(|l| point(l)[0]),
+            y: lanes(|l| point(l)[1]),
+            z: lanes(|l| point(l)[2]),
+        };
+
+        let t = transform();
+        let (tp, p_error) = t.point_with_error(p);
+        let (tv, v_error) = t.vector_with_error(p.coords());
+
+        for lane in 0..LANES {
+            for (result, error, exact) in &[
+                (components(&tp.coords(), lane), components(&p_error, lane), exact(point(lane), 1.0)),
+                (components(&tv, lane), components(&v_error, lane), exact(point(lane), 0.0)),
+            ] {
+                for i in 0..3 {
+                    let diff = (result[i] as f64 - exact[i]).abs();
+                    assert!(
+                        diff <= error[i] as f64,
+                        "lane {}: |{} - {}| > {}",
+                        lane,
+                        result[i],
+                        exact[i],
+                        error[i]
+                    );
+                    assert!(error[i] < 1e-3);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ray_origin_moved_forward() {
+        let ray = Ray3::<AVX2> {
+            origin: Point3 {
+                x: lanes(|l| point(l)[0]),
+                y: lanes(|l| point(l)[1]),
+                z: lanes(|l| point(l)[2]),
+            },
+            direction: Vector3 {
+                x: lanes(|l| l as f32 - 3.5),
+                y: Vf32::splat(0.25),
+                z: Vf32::splat(-1.0),
+            },
+            tmax: Vf32::splat(100.0),
+        };
+
+        let (transformed, error) = transform().ray_with_error(&ray);
+
+        for lane in 0..LANES {
+            let origin = components(&transformed.origin.coords(), lane);
+            let direction = components(&transformed.direction, lane);
+            let exact = exact(point(lane), 1.0);
+
+            // the exact origin is never ahead of the one the ray starts from
+            let along: f64 = (0..3).map(|i| (exact[i] - origin[i] as f64) * direction[i] as f64).sum();
+            assert!(along <= 0.0, "lane {}: {}", lane, along);
+
+            assert!(transformed.tmax.extract(lane) < 100.0);
+            assert!(components(&error.pos, lane).iter().all(|&e| e > 0.0));
+        }
+    }
+
+    fn vector(x: f32, y: f32, z: f32) -> Vector3<AVX2> {
+        Vector3 {
+            x: Vf32::splat(x),
+            y: Vf32::splat(y),
+            z: Vf32::splat(z),
+        }
+    }
+
+    fn at(x: f32, y: f32, z: f32) -> Point3<AVX2> {
+        let v = vector(x, y, z);
+        Point3 { x: v.x, y: v.y, z: v.z }
+    }
+
+    fn assert_close(v: Vector3<AVX2>, expected: [f32; 3]) {
+        for lane in 0..LANES {
+            let v = components(&v, lane);
+
+            for i in 0..3 {
+                assert!((v[i] - expected[i]).abs() < 1e-4, "{:?} != {:?}", v, expected);
+            }
+        }
+    }
+
+    /// Checks that `inverse` undoes `forward` on a few points
+    fn assert_inverts(t: &Transform3<AVX2>) {
+        for p in &[[0.0, 0.0, 1.0], [1.5, -2.0, 3.0], [-0.25, 0.75, 7.0]] {
+            let q = &t.inverse * (t * at(p[0], p[1], p[2]));
+            assert_close(q.coords(), *p);
+        }
+    }
+
+    #[test]
+    fn test_translate() {
+        let t = Transform3::translate(&vector(1.0, -2.0, 3.0));
+
+        assert_close((&t * at(1.0, 1.0, 1.0)).coords(), [2.0, -1.0, 4.0]);
+        assert_close(&t * vector(1.0, 1.0, 1.0), [1.0, 1.0, 1.0]);
+        assert_inverts(&t);
+    }
+
+    #[test]
+    fn test_look_at() {
+        let (t, invalid) = Transform3::look_at(at(1.0, 2.0, 3.0), at(1.0, 2.0, -7.0), &vector(0.0, 1.0, 0.0));
+        assert!(!invalid.any());
+
+        // the camera sits at the origin, looking down +z at its target
+        assert_close((&t * at(1.0, 2.0, 3.0)).coords(), [0.0, 0.0, 0.0]);
+        assert_close((&t * at(1.0, 2.0, -7.0)).coords(), [0.0, 0.0, 10.0]);
+        assert_close(&t * vector(0.0, 1.0, 0.0), [0.0, 1.0, 0.0]);
+        assert_inverts(&t);
+
+        let (_, invalid) = Transform3::look_at(at(1.0, 2.0, 3.0), at(1.0, 2.0, 3.0), &vector(0.0, 1.0, 0.0));
+        assert!(invalid.all());
+
+        let (_, invalid) = Transform3::look_at(at(0.0, 0.0, 0.0), at(0.0, 5.0, 0.0), &vector(0.0, 1.0, 0.0));
+        assert!(invalid.all());
+    }
+
+    #[test]
+    fn test_perspective() {
+        let fov = Vf32::splat(std::f32::consts::FRAC_PI_2);
+        let (t, invalid) = Transform3::<AVX2>::perspective(fov, Vf32::splat(1.0), Vf32::splat(100.0));
+        assert!(!invalid.any());
+
+        assert_close((&t * at(0.0, 0.0, 1.0)).coords(), [0.0, 0.0, 0.0]);
+        assert_close((&t * at(0.0, 0.0, 100.0)).coords(), [0.0, 0.0, 1.0]);
+
+        // a 90 degree field of view reaches as far sideways as it does forwards
+        assert_close((&t * at(10.0, -10.0, 10.0)).coords(), [1.0, -1.0, 100.0 / 99.0 * 0.9]);
+        assert_inverts(&t);
+
+        let (_, invalid) = Transform3::<AVX2>::perspective(fov, Vf32::splat(0.0), Vf32::splat(100.0));
+        assert!(invalid.all());
+
+        let (_, invalid) = Transform3::<AVX2>::perspective(Vf32::splat(4.0), Vf32::splat(1.0), Vf32::splat(100.0));
+        assert!(invalid.all());
+    }
+
+    #[test]
+    fn test_orthographic() {
+        let (t, invalid) = Transform3::orthographic(Vf32::splat(2.0), Vf32::splat(6.0));
+        assert!(!invalid.any());
+
+        assert_close((&t * at(3.0, -1.0, 2.0)).coords(), [3.0, -1.0, 0.0]);
+        assert_close((&t * at(3.0, -1.0, 4.0)).coords(), [3.0, -1.0, 0.5]);
+        assert_inverts(&t);
+
+        assert!(Transform3::<AVX2>::orthographic(Vf32::splat(2.0), Vf32::splat(2.0)).1.all());
+    }
+
+    #[test]
+    fn test_compose_and_apply() {
+        let scale = Transform3::scale(&vector(2.0, 1.0, 0.5)).0;
+        let move_by = Transform3::translate(&vector(0.0, 0.0, 5.0));
+        let rotate = Transform3::rotate(vector(0.0, 0.0, std::f32::consts::FRAC_PI_2));
+
+        // scaled, then rotated, then moved
+        let t = &move_by * &(&rotate * &scale);
+
+        let p = at(1.0, 1.0, 1.0);
+        assert_close((&t * p).coords(), components(&(&move_by * (&rotate * (&scale * p))).coords(), 0));
+        assert_close((&t * p).coords(), [-1.0, 2.0, 5.5]);
+        assert_inverts(&t);
+        assert_close((&t.invert() * (&t * p)).coords(), [1.0, 1.0, 1.0]);
+
+        // normals of the plane x + y = 0 stay perpendicular to it after a non-uniform scale
+        let tangent = &scale * vector(1.0, -1.0, 0.0);
+        let normal = scale.normal(vector(1.0, 1.0, 0.0));
+        assert!(tangent.dot(&normal).abs().extract(0) < 1e-6);
+
+        let ray = Ray3 {
+            origin: at(1.0, 0.0, 0.0),
+            direction: vector(0.0, 1.0, 0.0),
+            tmax: Vf32::splat(3.0),
+        };
+
+        let moved = &t * ray;
+        assert_close(moved.origin.coords(), [0.0, 2.0, 5.0]);
+        assert_close(moved.direction, [-1.0, 0.0, 0.0]);
+        assert_eq!(moved.tmax.extract(0), 3.0);
+
+        let bounds = &t * Bounds3::new(at(0.0, 0.0, 0.0), at(1.0, 1.0, 1.0));
+        assert_close(bounds.min.coords(), [-1.0, 0.0, 5.0]);
+        assert_close(bounds.max.coords(), [0.0, 2.0, 5.5]);
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        let sv = |x: f32, y: f32, z: f32| scalar::Vector3::new(x, y, z);
+        let sp = |x: f32, y: f32, z: f32| scalar::Point3::new(x, y, z);
+
+        let camera = scalar::Transform3::look_at(sp(1.0, 2.0, 3.0), sp(-4.0, 0.5, 7.0), &sv(0.0, 1.0, 0.0)).unwrap();
+        let projection = scalar::Transform3::perspective(1.2, 0.1, 50.0).unwrap();
+        let rotate = scalar::Transform3::rotate(sv(0.3, -1.1, 0.4));
+        let scale = scalar::Transform3::scale(&sv(2.0, 0.5, -1.0)).unwrap();
+        let composed = &(&projection * &camera) * &(&rotate * &scale);
+
+        let expected = [
+            (
+                camera,
+                Transform3::look_at(at(1.0, 2.0, 3.0), at(-4.0, 0.5, 7.0), &vector(0.0, 1.0, 0.0)).0,
+            ),
+            (
+                projection,
+                Transform3::perspective(Vf32::splat(1.2), Vf32::splat(0.1), Vf32::splat(50.0)).0,
+            ),
+            (rotate, Transform3::rotate(vector(0.3, -1.1, 0.4))),
+            (scale, Transform3::scale(&vector(2.0, 0.5, -1.0)).0),
+            (
+                scalar::Transform3::orthographic(1.0, 3.0).unwrap(),
+                Transform3::orthographic(Vf32::splat(1.0), Vf32::splat(3.0)).0,
+            ),
+            (
+                scalar::Transform3::translate(&sv(1.0, 2.0, 3.0)),
+                Transform3::translate(&vector(1.0, 2.0, 3.0)),
+            ),
+        ];
+
+        for (want, got) in &expected {
+            for lane in [0, LANES - 1].iter().copied() {
+                let extracted = got.extract(lane);
+
+                for i in 0..4 {
+                    for j in 0..4 {
+                        assert!(
+                            (extracted.forward[i][j] - want.forward[i][j]).abs() < 1e-5,
+                            "{:?} != {:?}",
+                            extracted,
+                            want
+                        );
+                        assert!(
+                            (extracted.inverse[i][j] - want.inverse[i][j]).abs() < 1e-4,
+                            "{:?} != {:?}",
+                            extracted,
+                            want
+                        );
+                    }
+                }
+            }
+        }
+
+        // the scalar inverse agrees with the one built alongside the transform
+        let inverse = composed.forward.inverse().unwrap();
+        let (p, v) = (sp(0.3, -0.2, 2.0), sv(1.0, 1.0, 1.0));
+
+        assert!(((&composed.inverse * (&composed * p)) - p).norm() < 1e-4);
+        assert!(((&inverse * (&composed * p)) - p).norm() < 1e-4);
+        assert!(scalar::Matrix4([[0.0; 4]; 4]).inverse().is_none());
+
+        let n = scale.normal(sv(1.0, 1.0, 0.0));
+        assert!((&scale * sv(1.0, -1.0, 0.0)).dot(&n).abs() < 1e-6);
+
+        let bounds = scalar::Bounds3::new(sp(0.0, 0.0, 0.0), sp(1.0, 1.0, 1.0));
+        assert_eq!(
+            &scale * bounds,
+            Bounds3::<AVX2>::splat(&bounds).transform(&Transform3::splat(&scale)).extract(0)
+        );
+
+        let ray = scalar::Ray3::new(p, v);
+        let moved = &rotate * ray;
+        assert!((moved.direction - (&rotate * v)).norm() < 1e-6);
+
+        // packing a different transform into each lane
+        let transforms: Vec<scalar::Transform3> = expected.iter().map(|(t, _)| *t).collect();
+        let packed = Transform3::<AVX2>::load(&transforms);
+
+        for (lane, t) in transforms.iter().enumerate() {
+            assert_eq!(packed.extract(lane), *t);
+        }
+
+        assert_eq!(packed.extract(LANES - 1), scalar::Transform3::identity());
+        assert_eq!(Transform3::<AVX2>::splat(&composed).extract(3), composed);
+    }
 }
@@ -0,0 +1,356 @@
+use thermite::*;
+
+use crate::math::scalar::gamma;
+use crate::math::soa::*;
+
+use super::{load_lanes, Error3, Point3, Ray3, Vector3};
+
+/// One triangle per lane
+///
+/// Splatting a single triangle tests it against a packet of rays, while splatting a single ray
+/// tests it against a packet of triangles.
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle<S: Simd> {
+    pub p0: Point3<S>,
+    pub p1: Point3<S>,
+    pub p2: Point3<S>,
+}
+
+/// Result of a ray-triangle intersection test for each lane
+#[derive(Debug, Clone, Copy)]
+pub struct TriangleHit<S: Simd> {
+    /// Lanes where the ray hit the triangle
+    pub mask: Mask<S, Vf32<S>>,
+    /// Ray parameter of the hit point
+    pub t: Vf32<S>,
+    /// Barycentric coordinates of the hit point, weighting `p0`, `p1` and `p2`
+    pub b0: Vf32<S>,
+    pub b1: Vf32<S>,
+    pub b2: Vf32<S>,
+}
+
+/// Cyclically permutes the dimensions of `v` so the dimension selected by `kz_is_x`, `kz_is_y` or neither comes last
+#[inline(always)]
+fn permute<S: Simd>(v: Vector3<S>, kz_is_x: Mask<S, Vf32<S>>, kz_is_y: Mask<S, Vf32<S>>) -> Vector3<S> {
+    Vector3 {
+        x: kz_is_x.select(v.y, kz_is_y.select(v.z, v.x)),
+        y: kz_is_x.select(v.z, kz_is_y.select(v.x, v.y)),
+        z: kz_is_x.select(v.x, kz_is_y.select(v.y, v.z)),
+    }
+}
+
+#[inline(always)]
+fn max_component<S: Simd>(a: Vf32<S>, b: Vf32<S>, c: Vf32<S>) -> Vf32<S> {
+    a.abs().max(b.abs()).max(c.abs())
+}
+
+#[dispatch]
+impl<S: Simd> Triangle<S> {
+    #[inline(always)]
+    pub fn new(p0: Point3<S>, p1: Point3<S>, p2: Point3<S>) -> Self {
+        Triangle { p0, p1, p2 }
+    }
+
+    /// The same triangle in every lane
+    #[inline(always)]
+    pub fn splat(vertices: [[f32; 3]; 3]) -> Self {
+        let point = |[x, y, z]: [f32; 3]| Point3 {
+            x: Vf32::<S>::splat(x),
+            y: Vf32::<S>::splat(y),
+            z: Vf32::<S>::splat(z),
+        };
+
+        Triangle {
+            p0: point(vertices[0]),
+            p1: point(vertices[1]),
+            p2: point(vertices[2]),
+        }
+    }
+
+    /// Loads one triangle per lane from `triangles`, filling any remaining lanes with
+    /// degenerate triangles that are never hit
+    #[inline]
+    pub fn load(triangles: &[[[f32; 3]; 3]]) -> Self {
+        let n = triangles.len();
+
+        let point = |v: usize| Point3 {
+            x: load_lanes::<S>(n, 0.0, |i| triangles[i][v][0]),
+            y: load_lanes::<S>(n, 0.0, |i| triangles[i][v][1]),
+            z: load_lanes::<S>(n, 0.0, |i| triangles[i][v][2]),
+        };
+
+        Triangle {
+            p0: point(0),
+            p1: point(1),
+            p2: point(2),
+        }
+    }
+
+    /// Watertight ray-triangle intersection, from Woop, Benthin and Wald, "Watertight Ray/Triangle Intersection"
+    ///
+    /// Rays passing exactly through a shared edge or vertex hit at least one of the adjacent triangles,
+    /// and only hits with `0 < t <= ray.tmax` are reported. `ray.tmax` is shrunk to `t` in lanes that hit,
+    /// so testing several triangles in turn finds the closest.
+    #[inline]
+    pub fn intersect(&self, ray: &mut Ray3<S>) -> TriangleHit<S> {
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+
+        // transform to a space where the ray starts at the origin and points down the largest dimension of its direction
+        let d = ray.direction.abs();
+        let kz_is_x = d.x.gt(d.y) & d.x.gt(d.z);
+        let kz_is_y = !kz_is_x & d.y.gt(d.z);
+
+        let d = permute::<S>(ray.direction, kz_is_x, kz_is_y);
+        let mut p0 = permute::<S>(self.p0 - ray.origin, kz_is_x, kz_is_y);
+        let mut p1 = permute::<S>(self.p1 - ray.origin, kz_is_x, kz_is_y);
+        let mut p2 = permute::<S>(self.p2 - ray.origin, kz_is_x, kz_is_y);
+
+        // shear so the ray points down +z
+        let sz = one / d.z;
+        let sx = -d.x * sz;
+        let sy = -d.y * sz;
+
+        p0.x = sx.mul_add(p0.z, p0.x);
+        p0.y = sy.mul_add(p0.z, p0.y);
+        p1.x = sx.mul_add(p1.z, p1.x);
+        p1.y = sy.mul_add(p1.z, p1.y);
+        p2.x = sx.mul_add(p2.z, p2.x);
+        p2.y = sy.mul_add(p2.z, p2.y);
+
+        // edge functions, computed robustly so shared edges give exactly opposite results
+        let e0 = difference_of_products::<S>(p1.x, p2.y, p1.y, p2.x);
+        let e1 = difference_of_products::<S>(p2.x, p0.y, p2.y, p0.x);
+        let e2 = difference_of_products::<S>(p0.x, p1.y, p0.y, p1.x);
+
+        let any_negative = e0.lt(zero) | e1.lt(zero) | e2.lt(zero);
+        let any_positive = e0.gt(zero) | e1.gt(zero) | e2.gt(zero);
+
+        let det = e0 + e1 + e2;
+
+        let mut mask = !(any_negative & any_positive) & !det.eq(zero);
+
+        // scaled hit distance, tested against the ray's extent before dividing by the determinant
+        p0.z *= sz;
+        p1.z *= sz;
+        p2.z *= sz;
+
+        let t_scaled = e0.mul_add(p0.z, e1.mul_add(p1.z, e2 * p2.z));
+
+        // flip the sign of both sides by the sign of the determinant
+        let det_sign = det & Vf32::<S>::neg_zero();
+        let t_scaled_signed = t_scaled ^ det_sign;
+        let det_abs = det.abs();
+
+        mask = mask & t_scaled_signed.gt(zero) & t_scaled_signed.le(ray.tmax * det_abs);
+
+        let inv_det = one / det;
+
+        let b0 = e0 * inv_det;
+        let b1 = e1 * inv_det;
+        let b2 = e2 * inv_det;
+        let t = t_scaled * inv_det;
+
+        // make sure t is conservatively greater than zero, accounting for the error of each step
+        let max_zt = max_component::<S>(p0.z, p1.z, p2.z);
+        let max_xt = max_component::<S>(p0.x, p1.x, p2.x);
+        let max_yt = max_component::<S>(p0.y, p1.y, p2.y);
+        let max_e = max_component::<S>(e0, e1, e2);
+
+        let gamma_n = |n| Vf32::<S>::splat(gamma(n));
+
+        let delta_z = gamma_n(3) * max_zt;
+        let delta_x = gamma_n(5) * (max_xt + max_zt);
+        let delta_y = gamma_n(5) * (max_yt + max_zt);
+
+        let delta_e = Vf32::<S>::splat(2.0) * (gamma_n(2) * max_xt).mul_add(max_yt, delta_y.mul_add(max_xt, delta_x * max_yt));
+
+        let delta_t =
+            Vf32::<S>::splat(3.0) * (gamma_n(3) * max_e).mul_add(max_zt, delta_e.mul_add(max_zt, delta_z * max_e)) * inv_det.abs();
+
+        mask = mask & t.gt(delta_t);
+
+        ray.tmax = mask.select(t, ray.tmax);
+
+        TriangleHit { mask, t, b0, b1, b2 }
+    }
+}
+
+#[dispatch]
+impl<S: Simd> TriangleHit<S> {
+    /// Lane with the closest hit, if any lane hit
+    ///
+    /// Useful when a single ray was splatted and tested against a packet of triangles.
+    #[inline]
+    pub fn nearest_lane(&self) -> Option<usize> {
+        let t = self.mask.select(self.t, Vf32::<S>::splat(f32::INFINITY));
+
+        let mut nearest = None;
+        let mut nearest_t = f32::INFINITY;
+
+        for lane in 0..Vf32::<S>::NUM_ELEMENTS {
+            let lane_t = t.extract(lane);
+
+            if lane_t < nearest_t {
+                nearest = Some(lane);
+                nearest_t = lane_t;
+            }
+        }
+
+        nearest
+    }
+
+    /// Hit point interpolated from the barycentric coordinates, which is more accurate than evaluating the ray
+    /// at `t`, and a bound on its rounding error
+    #[inline]
+    pub fn point(&self, triangle: &Triangle<S>) -> (Point3<S>, Error3<S>) {
+        let (b0, b1, b2) = (self.b0, self.b1, self.b2);
+        let (p0, p1, p2) = (&triangle.p0, &triangle.p1, &triangle.p2);
+
+        let interpolate = |c0: Vf32<S>, c1: Vf32<S>, c2: Vf32<S>| b0.mul_add(c0, b1.mul_add(c1, b2 * c2));
+        let abs_sum = |c0: Vf32<S>, c1: Vf32<S>, c2: Vf32<S>| (b0 * c0).abs() + (b1 * c1).abs() + (b2 * c2).abs();
+
+        let point = Point3 {
+            x: interpolate(p0.x, p1.x, p2.x),
+            y: interpolate(p0.y, p1.y, p2.y),
+            z: interpolate(p0.z, p1.z, p2.z),
+        };
+
+        let g7 = Vf32::<S>::splat(gamma(7));
+
+        let error = Vector3 {
+            x: g7 * abs_sum(p0.x, p1.x, p2.x),
+            y: g7 * abs_sum(p0.y, p1.y, p2.y),
+            z: g7 * abs_sum(p0.z, p1.z, p2.z),
+        };
+
+        (point, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    fn ray(origin: [f32; 3], direction: [f32; 3]) -> Ray3<AVX2> {
+        Ray3 {
+            origin: Point3 {
+                x: Vf32::splat(origin[0]),
+                y: Vf32::splat(origin[1]),
+                z: Vf32::splat(origin[2]),
+            },
+            direction: Vector3 {
+                x: Vf32::splat(direction[0]),
+                y: Vf32::splat(direction[1]),
+                z: Vf32::splat(direction[2]),
+            },
+            tmax: Vf32::splat(f32::INFINITY),
+        }
+    }
+
+    #[test]
+    fn test_hit_and_barycentrics() {
+        let triangle = Triangle::<AVX2>::splat([[0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+
+        let mut r = ray([0.25, 0.5, 0.0], [0.0, 0.0, 1.0]);
+        let hit = triangle.intersect(&mut r);
+
+        for lane in 0..LANES {
+            assert!((hit.t.extract(lane) - 1.0).abs() < 1e-6);
+            assert!((hit.b0.extract(lane) - 0.25).abs() < 1e-6);
+            assert!((hit.b1.extract(lane) - 0.25).abs() < 1e-6);
+            assert!((hit.b2.extract(lane) - 0.5).abs() < 1e-6);
+            assert_eq!(r.tmax.extract(lane), hit.t.extract(lane));
+        }
+        assert!(hit.mask.all());
+
+        // tmax is inclusive, so only shrinking it below the hit misses the triangle
+        assert!(triangle.intersect(&mut r).mask.all());
+
+        r.tmax = Vf32::splat(0.999);
+        assert!(!triangle.intersect(&mut r).mask.any());
+
+        // behind the origin, and past tmax
+        let mut r = ray([0.25, 0.25, 2.0], [0.0, 0.0, 1.0]);
+        assert!(!triangle.intersect(&mut r).mask.any());
+
+        let mut r = ray([0.25, 0.25, 0.0], [0.0, 0.0, 1.0]);
+        r.tmax = Vf32::splat(0.5);
+        assert!(!triangle.intersect(&mut r).mask.any());
+        assert_eq!(r.tmax.extract(0), 0.5);
+    }
+
+    #[test]
+    fn test_hit_point_error() {
+        let vertices = [[1000.1, -300.7, 52.3], [1003.9, -299.2, 50.8], [999.4, -296.3, 55.1]];
+        let triangle = Triangle::<AVX2>::splat(vertices);
+
+        // a ray from far away, so evaluating it at `t` would be much less accurate
+        let mut r = ray([0.0, 0.0, 0.0], [1001.0, -298.9, 52.6]);
+        let hit = triangle.intersect(&mut r);
+        assert!(hit.mask.all());
+
+        let (point, error) = hit.point(&triangle);
+
+        let point = [point.x.extract(0), point.y.extract(0), point.z.extract(0)];
+        let error = [error.x.extract(0), error.y.extract(0), error.z.extract(0)];
+        let b = [hit.b0.extract(0) as f64, hit.b1.extract(0) as f64, hit.b2.extract(0) as f64];
+
+        for i in 0..3 {
+            let exact: f64 = (0..3).map(|v| b[v] * vertices[v][i] as f64).sum();
+
+            assert!(
+                (point[i] as f64 - exact).abs() <= error[i] as f64,
+                "{} != {} +- {}",
+                point[i],
+                exact,
+                error[i]
+            );
+            assert!(error[i] < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_watertight_shared_edge() {
+        // two triangles sharing the diagonal of a quad, hit by rays exactly through that diagonal
+        let a = Triangle::<AVX2>::splat([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]]);
+        let b = Triangle::<AVX2>::splat([[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]]);
+
+        for i in 1..1000 {
+            let u = i as f32 / 1000.0;
+
+            for &(dx, dy) in &[(0.0, 0.0), (0.3, 0.3), (-0.2, 0.7), (0.5, -0.1)] {
+                let origin = [u - dx, u - dy, 1.0];
+                let direction = [dx, dy, -1.0];
+
+                let hit_a = a.intersect(&mut ray(origin, direction)).mask.all();
+                let hit_b = b.intersect(&mut ray(origin, direction)).mask.all();
+
+                assert!(hit_a || hit_b, "ray through ({0}, {0}) fell through the shared edge", u);
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangle_packet() {
+        let triangles: Vec<[[f32; 3]; 3]> = (0..LANES - 1)
+            .map(|i| {
+                let z = 5.0 - i as f32 * 0.5;
+                [[-1.0, -1.0, z], [1.0, -1.0, z], [0.0, 1.0, z]]
+            })
+            .collect();
+
+        let packet = Triangle::<AVX2>::load(&triangles);
+        let hit = packet.intersect(&mut ray([0.0, 0.0, 0.0], [0.0, 0.0, 1.0]));
+
+        // the padding lane is never hit
+        assert!(hit.mask.select(Vf32::one(), Vf32::zero()).extract(LANES - 1) == 0.0);
+        assert_eq!(hit.nearest_lane(), Some(LANES - 2));
+        assert!((hit.t.extract(LANES - 2) - (5.0 - (LANES - 2) as f32 * 0.5)).abs() < 1e-6);
+    }
+}
@@ -4,6 +4,10 @@ use thermite::*;
 
 use crate::math::soa::*;
 
+use crate::scalar;
+
+use super::load_lanes;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Vector3<S: Simd> {
     pub x: Vf32<S>,
@@ -12,6 +16,34 @@ pub struct Vector3<S: Simd> {
 }
 
 impl<S: Simd> Vector3<S> {
+    /// The same vector in every lane
+    #[inline(always)]
+    pub fn splat(v: &scalar::Vector3) -> Self {
+        Vector3 {
+            x: Vf32::<S>::splat(v.x),
+            y: Vf32::<S>::splat(v.y),
+            z: Vf32::<S>::splat(v.z),
+        }
+    }
+
+    /// Loads one vector per lane from `vectors`, filling any remaining lanes with zero vectors
+    #[inline]
+    pub fn load(vectors: &[scalar::Vector3]) -> Self {
+        let n = vectors.len();
+
+        Vector3 {
+            x: load_lanes::<S>(n, 0.0, |i| vectors[i].x),
+            y: load_lanes::<S>(n, 0.0, |i| vectors[i].y),
+            z: load_lanes::<S>(n, 0.0, |i| vectors[i].z),
+        }
+    }
+
+    /// Vector in lane `lane`
+    #[inline]
+    pub fn extract(&self, lane: usize) -> scalar::Vector3 {
+        scalar::Vector3::new(self.x.extract(lane), self.y.extract(lane), self.z.extract(lane))
+    }
+
     /// Creates a vector where all dimensions are the same value
     #[inline(always)]
     pub fn diag(xyz: Vf32<S>) -> Self {
@@ -79,6 +111,70 @@ impl<S: Simd> Vector3<S> {
     pub fn face_forward(self, v: &Self) -> Self {
         self * Self::diag(self.dot(v).signum())
     }
+
+    /// Multiplies every component by `s`, which can differ in each lane
+    ///
+    /// `Vector3 * Vf32` can't be an operator, as it would overlap with `Vector3 * Vector3` for all the compiler knows.
+    #[inline(always)]
+    pub fn scale(self, s: Vf32<S>) -> Self {
+        self * Self::diag(s)
+    }
+
+    /// Componentwise minimum
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        Vector3 {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Componentwise maximum
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        Vector3 {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// `self` at `t = 0` to `other` at `t = 1`
+    #[inline(always)]
+    pub fn lerp(self, other: Self, t: Vf32<S>) -> Self {
+        Vector3 {
+            x: t.lerp(self.x, other.x),
+            y: t.lerp(self.y, other.y),
+            z: t.lerp(self.z, other.z),
+        }
+    }
+}
+
+impl<S: Simd> Index<usize> for Vector3<S> {
+    type Output = Vf32<S>;
+
+    #[inline(always)]
+    fn index(&self, axis: usize) -> &Vf32<S> {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vector3 axis {} out of range", axis),
+        }
+    }
+}
+
+impl<S: Simd> IndexMut<usize> for Vector3<S> {
+    #[inline(always)]
+    fn index_mut(&mut self, axis: usize) -> &mut Vf32<S> {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vector3 axis {} out of range", axis),
+        }
+    }
 }
 
 impl<S: Simd> Neg for Vector3<S> {
@@ -104,3 +200,185 @@ impl<S: Simd> Mul<Vector3<S>> for Vector3<S> {
         self
     }
 }
+
+impl<S: Simd> Add<Vector3<S>> for Vector3<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(mut self, rhs: Self) -> Self {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self
+    }
+}
+
+impl<S: Simd> Sub<Vector3<S>> for Vector3<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(mut self, rhs: Self) -> Self {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self
+    }
+}
+
+impl<S: Simd> Div<Vector3<S>> for Vector3<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(mut self, rhs: Self) -> Self {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+        self
+    }
+}
+
+impl<S: Simd> Mul<f32> for Vector3<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: f32) -> Self {
+        self * Vector3::diag(Vf32::<S>::splat(rhs))
+    }
+}
+
+impl<S: Simd> Div<f32> for Vector3<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: f32) -> Self {
+        self * (1.0 / rhs)
+    }
+}
+
+impl<S: Simd> AddAssign<Vector3<S>> for Vector3<S> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Vector3<S>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<S: Simd> SubAssign<Vector3<S>> for Vector3<S> {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Vector3<S>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<S: Simd> MulAssign<Vector3<S>> for Vector3<S> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Vector3<S>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<S: Simd> MulAssign<f32> for Vector3<S> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl<S: Simd> DivAssign<Vector3<S>> for Vector3<S> {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: Vector3<S>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<S: Simd> DivAssign<f32> for Vector3<S> {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::soa::{Bounds3, Point3, Ray3};
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    #[test]
+    fn test_operators_match_scalar() {
+        let (a, b) = (scalar::Vector3::new(1.0, -2.0, 4.0), scalar::Vector3::new(0.5, 8.0, -2.0));
+        let (va, vb) = (Vector3::<AVX2>::splat(&a), Vector3::<AVX2>::splat(&b));
+
+        assert_eq!((va / vb).extract(0), a / b);
+        assert_eq!((va * 3.0).extract(1), a * 3.0);
+        assert_eq!((va / 4.0).extract(2), a / 4.0);
+        assert_eq!(va.scale(Vf32::splat(3.0)).extract(3), 3.0 * a);
+        assert_eq!(va.min(vb).extract(0), a.min(b));
+        assert_eq!(va.max(vb).extract(0), a.max(b));
+        assert_eq!(va.lerp(vb, Vf32::splat(0.25)).extract(0), a.lerp(b, 0.25));
+
+        let (mut v, mut s) = (va, a);
+        v += vb;
+        v -= va * 2.0;
+        v *= vb;
+        v /= 2.0;
+        s += b;
+        s -= a * 2.0;
+        s *= b;
+        s /= 2.0;
+        assert_eq!(v.extract(0), s);
+
+        v[1] = Vf32::splat(7.0);
+        s[1] = 7.0;
+        assert_eq!(v[1].extract(0), s[1]);
+        assert_eq!(v.extract(0), s);
+
+        let (p, q) = (scalar::Point3::new(1.0, 2.0, 3.0), scalar::Point3::new(-3.0, 0.0, 1.0));
+        let (vp, vq) = (Point3::<AVX2>::splat(&p), Point3::<AVX2>::splat(&q));
+
+        assert_eq!((vp - vb).extract(0), p - b);
+        assert_eq!(vp.lerp(vq, Vf32::splat(0.5)).extract(0), p.lerp(q, 0.5));
+
+        let mut vp = vp;
+        vp += vb;
+        vp[2] = Vf32::zero();
+        assert_eq!(vp.extract(0), scalar::Point3::new(1.5, 10.0, 0.0));
+    }
+
+    #[test]
+    fn test_load_and_extract() {
+        let vectors: Vec<scalar::Vector3> = (0..LANES - 2).map(|i| scalar::Vector3::new(i as f32, -(i as f32), 0.5)).collect();
+        let points: Vec<scalar::Point3> = vectors.iter().map(|v| scalar::Point3::new(v.z, v.y, v.x)).collect();
+        let rays: Vec<scalar::Ray3> = points.iter().zip(&vectors).map(|(&p, &v)| scalar::Ray3::new(p, v)).collect();
+
+        let (v, p, r) = (
+            Vector3::<AVX2>::load(&vectors),
+            Point3::<AVX2>::load(&points),
+            Ray3::<AVX2>::load(&rays),
+        );
+
+        for lane in 0..vectors.len() {
+            assert_eq!(v.extract(lane), vectors[lane]);
+            assert_eq!(p.extract(lane), points[lane]);
+            assert_eq!(r.extract(lane), rays[lane]);
+        }
+
+        // left over lanes
+        assert_eq!(v.extract(LANES - 1), scalar::Vector3::diag(0.0));
+        assert_eq!(p.extract(LANES - 1), scalar::Point3::origin());
+        assert_eq!(r.extract(LANES - 1).tmax, -1.0);
+
+        // left over rays miss even a box around their origins
+        let around = scalar::Bounds3::new(scalar::Point3::new(-10.0, -10.0, -10.0), scalar::Point3::new(10.0, 10.0, 10.0));
+        let around = Bounds3::<AVX2>::load(&[around; LANES]);
+        let (hit, _, _) = around.intersect_p(&r, &(Vector3::diag(Vf32::one()) / r.direction));
+
+        for lane in 0..LANES {
+            assert_eq!(hit.extract(lane), lane < rays.len(), "lane {}", lane);
+        }
+    }
+}
@@ -1,3 +1,12 @@
+/// Unit roundoff of `f32` arithmetic, the largest relative error of a single correctly rounded operation
+pub const MACHINE_EPSILON: f32 = f32::EPSILON * 0.5;
+
+/// Conservative bound on the relative error accumulated by `n` floating-point operations, `n e / (1 - n e)`
+#[inline(always)]
+pub fn gamma(n: u32) -> f32 {
+    let ne = n as f32 * MACHINE_EPSILON;
+    ne / (1.0 - ne)
+}
 
 #[inline(always)]
 pub fn difference_of_products(a: f32, b: f32, c: f32, d: f32) -> f32 {
@@ -89,6 +89,11 @@ impl Profiler {
         self.total_count
     }
 
+    /// Get the total time, in nanoseconds, spent in profiled code segments.
+    pub fn total_time(&self) -> u64 {
+        self.total_time
+    }
+
     /// Get the average time, in nanoseconds, of profiled code segments.
     pub fn get_average(&self) -> u64 {
         if self.total_count == 0 {
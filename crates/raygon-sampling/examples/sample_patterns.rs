@@ -0,0 +1,57 @@
+//! Writes 2D projections of each sampler, with their power spectra, and prints their star discrepancy
+//!
+//! ```text
+//! cargo run --release --example sample_patterns -- [output directory] [first dimension] [second dimension]
+//! ```
+//!
+//! For each sampler, writes `<name>.ppm` with the points of one pixel and `<name>_spectrum.pfm` with their
+//! power spectrum.
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+
+use raygon_sampling::analysis::*;
+use raygon_sampling::*;
+
+const SAMPLES: u32 = 1024;
+const IMAGE_SIZE: usize = 512;
+const SPECTRUM_SIZE: usize = 64;
+const SEED: u32 = 0;
+
+fn main() -> io::Result<()> {
+    let mut args = std::env::args().skip(1);
+
+    let dir = PathBuf::from(args.next().unwrap_or_else(|| ".".to_owned()));
+    let first = args.next().map_or(0, |d| d.parse().expect("invalid dimension"));
+    let second = args.next().map_or(first + 1, |d| d.parse().expect("invalid dimension"));
+
+    let samplers: Vec<(&str, Box<dyn Sampler>)> = vec![
+        ("independent", Box::new(IndependentSampler::new(SAMPLES, SEED))),
+        ("stratified", Box::new(StratifiedSampler::new(32, 32, true, SEED))),
+        ("halton", Box::new(HaltonSampler::new(SAMPLES, Randomization::Owen, SEED))),
+        ("sobol", Box::new(SobolSampler::new(SAMPLES, Randomization::Owen, SEED))),
+    ];
+
+    for (name, mut sampler) in samplers {
+        let points = project(sampler.as_mut(), (0, 0), SAMPLES, (first, second));
+
+        write_points_ppm(
+            &mut BufWriter::new(File::create(dir.join(format!("{}.ppm", name)))?),
+            &points,
+            IMAGE_SIZE,
+        )?;
+
+        let spectrum = power_spectrum(&points, SPECTRUM_SIZE);
+        write_pfm(
+            &mut BufWriter::new(File::create(dir.join(format!("{}_spectrum.pfm", name)))?),
+            &spectrum,
+            SPECTRUM_SIZE,
+            SPECTRUM_SIZE,
+        )?;
+
+        println!("{:>12}: star discrepancy {:.5}", name, star_discrepancy(&points));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Generates the precomputed sample tables loaded at runtime
+//!
+//! ```text
+//! cargo run --release --example sample_tables -- [output directory]
+//! ```
+//!
+//! Writes `pmj02.bin`, with PMJ02 sequences, and `bluenoise.bin`, with a blue-noise texture.
+
+use std::io;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use raygon_sampling::{BlueNoiseTexture, Pmj02Table};
+
+const PMJ02_SETS: usize = 5;
+const PMJ02_SAMPLES_PER_SET: usize = 1 << 14;
+const BLUE_NOISE_SIZE: u32 = 128;
+const SEED: u32 = 0;
+
+fn main() -> io::Result<()> {
+    let dir = PathBuf::from(std::env::args().nth(1).unwrap_or_else(|| ".".to_owned()));
+
+    let start = Instant::now();
+    let pmj02 = Pmj02Table::generate(PMJ02_SETS, PMJ02_SAMPLES_PER_SET, SEED);
+    pmj02.save(dir.join("pmj02.bin"))?;
+    println!(
+        "{} PMJ02 sets of {} samples in {:?}",
+        PMJ02_SETS,
+        PMJ02_SAMPLES_PER_SET,
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let blue_noise = BlueNoiseTexture::generate(BLUE_NOISE_SIZE, SEED);
+    blue_noise.save(dir.join("bluenoise.bin"))?;
+    println!("{0}x{0} blue-noise texture in {1:?}", BLUE_NOISE_SIZE, start.elapsed());
+
+    Ok(())
+}
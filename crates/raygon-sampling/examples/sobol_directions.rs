@@ -0,0 +1,90 @@
+//! Generates the Sobol' direction number parameters in `src/sobol_directions.rs`
+//!
+//! ```text
+//! cargo run --release --example sobol_directions -- new-joe-kuo-6.21201 > src/sobol_directions.rs
+//! ```
+//!
+//! The parameters are Joe and Kuo's `new-joe-kuo-6.21201` table, available from
+//! <https://web.maths.unsw.edu.au/~fkuo/sobol/>, which picks the initial direction numbers of each
+//! dimension to minimize the t-values of its 2D projections. Each line after the header holds the
+//! dimension, the polynomial degree `s`, its interior coefficients `a` and the initial direction numbers
+//! `m_1 ... m_s`, in the same convention as `build_matrices`.
+
+use std::io::{self, Write};
+use std::{env, fs};
+
+const DIMENSIONS: usize = 1024;
+
+struct Parameters {
+    degree: u32,
+    coefficients: u32,
+    initial: Vec<u32>,
+}
+
+fn parse(line: &str) -> Parameters {
+    let fields = line
+        .split_whitespace()
+        .map(|field| field.parse().expect("Invalid direction number table"))
+        .collect::<Vec<u32>>();
+
+    let (degree, coefficients) = (fields[1], fields[2]);
+    let initial = fields[3..].to_vec();
+
+    assert_eq!(initial.len(), degree as usize, "Wrong number of initial direction numbers");
+
+    Parameters {
+        degree,
+        coefficients,
+        initial,
+    }
+}
+
+fn main() -> io::Result<()> {
+    let path = env::args().nth(1).expect("Usage: sobol_directions <new-joe-kuo-6.21201>");
+    let table = fs::read_to_string(path)?;
+
+    // skip the "d s a m_i" header
+    let parameters = table
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .take(DIMENSIONS - 1)
+        .map(parse)
+        .collect::<Vec<_>>();
+
+    assert_eq!(parameters.len(), DIMENSIONS - 1, "Not enough dimensions in the table");
+
+    let max_degree = parameters.iter().map(|p| p.degree).max().unwrap();
+
+    let out = io::stdout();
+    let mut out = out.lock();
+
+    writeln!(
+        out,
+        "//! Generated by `cargo run --release --example sobol_directions -- new-joe-kuo-6.21201`, do not edit"
+    )?;
+    writeln!(out)?;
+    writeln!(out, "pub const NUM_SOBOL_DIMENSIONS: usize = {};", DIMENSIONS)?;
+    writeln!(out, "pub(crate) const MAX_DEGREE: usize = {};", max_degree)?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "/// Polynomial degree, interior coefficients and initial direction numbers of each dimension after the first"
+    )?;
+    writeln!(out, "#[rustfmt::skip]")?;
+    writeln!(out, "#[allow(clippy::large_const_arrays)]")?;
+    writeln!(
+        out,
+        "pub(crate) const SOBOL_PARAMETERS: [(u32, u32, [u32; MAX_DEGREE]); NUM_SOBOL_DIMENSIONS - 1] = ["
+    )?;
+
+    for p in &parameters {
+        let mut initial = p.initial.clone();
+        initial.resize(max_degree as usize, 0);
+
+        let initial = initial.iter().map(|m| m.to_string()).collect::<Vec<_>>().join(", ");
+        writeln!(out, "    ({}, {}, [{}]),", p.degree, p.coefficients, initial)?;
+    }
+
+    writeln!(out, "];")
+}
@@ -0,0 +1,404 @@
+//! Tools for validating samplers and warping functions
+//!
+//! 2D projections of sampler dimensions can be written out as images to inspect by eye, and scored by their
+//! star discrepancy and power spectrum. Warping functions are checked against their densities with a chi-square test.
+
+use std::f64::consts::PI;
+use std::io::{self, Write};
+
+use crate::rng::Pcg32;
+use crate::sampler::{Pixel, Sampler};
+
+/// The first `count` samples of `pixel` in dimensions `dimensions.0` and `dimensions.1`
+pub fn project(sampler: &mut dyn Sampler, pixel: Pixel, count: u32, dimensions: (u32, u32)) -> Vec<(f32, f32)> {
+    (0..count)
+        .map(|index| {
+            sampler.start_pixel_sample_at(pixel, index, dimensions.0);
+            let x = sampler.next_1d();
+
+            sampler.start_pixel_sample_at(pixel, index, dimensions.1);
+            let y = sampler.next_1d();
+
+            (x, y)
+        })
+        .collect()
+}
+
+/// Writes a binary PPM image of `points` in the unit square, drawn as black pixels on white, with `y` pointing up
+pub fn write_points_ppm<W: Write>(writer: &mut W, points: &[(f32, f32)], size: usize) -> io::Result<()> {
+    let mut pixels = vec![255u8; size * size * 3];
+
+    for &(x, y) in points {
+        let px = ((x * size as f32) as usize).min(size - 1);
+        let py = ((y * size as f32) as usize).min(size - 1);
+
+        let offset = ((size - 1 - py) * size + px) * 3;
+        pixels[offset..offset + 3].fill(0);
+    }
+
+    write!(writer, "P6\n{} {}\n255\n", size, size)?;
+    writer.write_all(&pixels)
+}
+
+/// Writes a grayscale PFM image, with `values` given row by row from the top
+pub fn write_pfm<W: Write>(writer: &mut W, values: &[f32], width: usize, height: usize) -> io::Result<()> {
+    assert_eq!(values.len(), width * height);
+
+    // negative scale for little-endian, and rows are stored from the bottom
+    write!(writer, "Pf\n{} {}\n-1.0\n", width, height)?;
+
+    for row in values.chunks(width).rev() {
+        for value in row {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Exact star discrepancy of points in the unit square, the largest difference between the fraction of points
+/// in a box anchored at the origin and the area of that box
+///
+/// Takes `O(n^2)` time.
+pub fn star_discrepancy(points: &[(f32, f32)]) -> f64 {
+    let n = points.len();
+
+    if n == 0 {
+        return 1.0;
+    }
+
+    let mut by_x: Vec<(f64, f64)> = points.iter().map(|&(x, y)| (x as f64, y as f64)).collect();
+    by_x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    // the supremum is attained with corners at point coordinates, or at one
+    let mut corners_y: Vec<f64> = by_x.iter().map(|p| p.1).chain(Some(1.0)).collect();
+    corners_y.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    corners_y.dedup();
+
+    let mut discrepancy = 0.0f64;
+    let mut below: Vec<f64> = Vec::with_capacity(n);
+
+    let mut i = 0;
+    while i <= n {
+        let cx = if i < n { by_x[i].0 } else { 1.0 };
+
+        // points with x equal to the corner are outside the open box but inside the closed one
+        let mut j = i;
+        while j < n && by_x[j].0 == cx {
+            j += 1;
+        }
+
+        let mut closed = below.clone();
+        for p in &by_x[i..j] {
+            let at = closed.partition_point(|&y| y < p.1);
+            closed.insert(at, p.1);
+        }
+
+        let (mut open_count, mut closed_count) = (0, 0);
+
+        for &cy in &corners_y {
+            while open_count < below.len() && below[open_count] < cy {
+                open_count += 1;
+            }
+            while closed_count < closed.len() && closed[closed_count] <= cy {
+                closed_count += 1;
+            }
+
+            let area = cx * cy;
+
+            discrepancy = discrepancy
+                .max(area - open_count as f64 / n as f64)
+                .max(closed_count as f64 / n as f64 - area);
+        }
+
+        below = closed;
+        i = j.max(i + 1);
+    }
+
+    discrepancy
+}
+
+/// Periodogram of points in the unit square, `|sum exp(-2 pi i k.x)|^2 / n`, as a `size` by `size` image
+/// of integer frequencies `k` with zero at row and column `size / 2` and frequencies increasing right and up
+///
+/// Uniformly random points have an expected value of one at every nonzero frequency. Takes `O(n size^2)` time.
+pub fn power_spectrum(points: &[(f32, f32)], size: usize) -> Vec<f32> {
+    let half = (size / 2) as i64;
+    let mut spectrum = vec![0.0f32; size * size];
+
+    for row in 0..size {
+        let ky = half - row as i64;
+
+        for col in 0..size {
+            let kx = col as i64 - half;
+            let (mut re, mut im) = (0.0f64, 0.0f64);
+
+            for &(x, y) in points {
+                let (sin, cos) = (-2.0 * PI * (kx as f64 * x as f64 + ky as f64 * y as f64)).sin_cos();
+                re += cos;
+                im += sin;
+            }
+
+            spectrum[row * size + col] = ((re * re + im * im) / points.len() as f64) as f32;
+        }
+    }
+
+    spectrum
+}
+
+/// Average of a spectrum from `power_spectrum` over rings of equal frequency magnitude,
+/// from zero up to `size / 2 - 1`
+pub fn radial_average(spectrum: &[f32], size: usize) -> Vec<f32> {
+    let half = size / 2;
+    let (mut sums, mut counts) = (vec![0.0f64; half], vec![0u32; half]);
+
+    for row in 0..size {
+        for col in 0..size {
+            let (dx, dy) = (col as f64 - half as f64, row as f64 - half as f64);
+            let r = (dx * dx + dy * dy).sqrt().round() as usize;
+
+            if r < half {
+                sums[r] += spectrum[row * size + col] as f64;
+                counts[r] += 1;
+            }
+        }
+    }
+
+    sums.iter()
+        .zip(&counts)
+        .map(|(&s, &c)| if c > 0 { (s / c as f64) as f32 } else { 0.0 })
+        .collect()
+}
+
+/// Regularized upper incomplete gamma function `Q(a, x)`
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    let ln_prefix = a * x.ln() - x - ln_gamma(a);
+
+    if x < a + 1.0 {
+        // series for P(a, x)
+        let (mut term, mut sum, mut ap) = (1.0 / a, 1.0 / a, a);
+
+        for _ in 0..1000 {
+            ap += 1.0;
+            term *= x / ap;
+            sum += term;
+
+            if term.abs() < sum.abs() * 1e-15 {
+                break;
+            }
+        }
+
+        1.0 - sum * ln_prefix.exp()
+    } else {
+        // continued fraction for Q(a, x), with Lentz's method
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+
+        for i in 1..1000 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+
+            if (delta - 1.0).abs() < 1e-15 {
+                break;
+            }
+        }
+
+        ln_prefix.exp() * h
+    }
+}
+
+/// Lanczos approximation of `ln(gamma(x))` for `x > 0`
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+
+    let mut series = 1.000000000190015;
+    for (i, c) in COEFFICIENTS.iter().enumerate() {
+        series += c / (x + 1.0 + i as f64);
+    }
+
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+/// Outcome of a chi-square test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquareResult {
+    pub statistic: f64,
+    pub degrees_of_freedom: usize,
+    /// Probability of a statistic at least this large if the samples follow the density
+    pub p_value: f64,
+}
+
+/// Chi-square goodness-of-fit test of a warping function against its density
+///
+/// Warped samples are binned on a grid over the unit square, so warps to other domains should be composed
+/// with a mapping to the unit square, and their densities multiplied by the Jacobian of that mapping.
+/// Cells expecting fewer than five samples are pooled together, as the test is unreliable for them.
+#[derive(Debug, Clone, Copy)]
+pub struct ChiSquareTest {
+    pub resolution: (usize, usize),
+    pub samples: usize,
+    pub seed: u32,
+}
+
+impl Default for ChiSquareTest {
+    fn default() -> ChiSquareTest {
+        ChiSquareTest {
+            resolution: (32, 32),
+            samples: 1_000_000,
+            seed: 0,
+        }
+    }
+}
+
+impl ChiSquareTest {
+    /// Expected number of samples in each cell, integrating `density` numerically
+    pub fn expected<D>(&self, density: D) -> Vec<f64>
+    where
+        D: Fn((f32, f32)) -> f32,
+    {
+        const SUBDIVISIONS: usize = 16;
+
+        let (nx, ny) = self.resolution;
+        let mut expected = vec![0.0; nx * ny];
+
+        for (cell, e) in expected.iter_mut().enumerate() {
+            let (cx, cy) = (cell % nx, cell / nx);
+            let mut sum = 0.0f64;
+
+            for sy in 0..SUBDIVISIONS {
+                for sx in 0..SUBDIVISIONS {
+                    let x = (cx as f64 + (sx as f64 + 0.5) / SUBDIVISIONS as f64) / nx as f64;
+                    let y = (cy as f64 + (sy as f64 + 0.5) / SUBDIVISIONS as f64) / ny as f64;
+
+                    sum += density((x as f32, y as f32)) as f64;
+                }
+            }
+
+            *e = sum / (SUBDIVISIONS * SUBDIVISIONS) as f64 / (nx * ny) as f64 * self.samples as f64;
+        }
+
+        expected
+    }
+
+    /// Number of warped samples in each cell. Samples where `warp` returns `None` are discarded.
+    pub fn observed<F>(&self, mut warp: F) -> Vec<f64>
+    where
+        F: FnMut((f32, f32)) -> Option<(f32, f32)>,
+    {
+        let (nx, ny) = self.resolution;
+        let mut observed = vec![0.0; nx * ny];
+        let mut rng = Pcg32::new(0, self.seed as u64);
+
+        for _ in 0..self.samples {
+            if let Some((x, y)) = warp((rng.next_f32(), rng.next_f32())) {
+                if (0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y) {
+                    let cx = ((x * nx as f32) as usize).min(nx - 1);
+                    let cy = ((y * ny as f32) as usize).min(ny - 1);
+
+                    observed[cy * nx + cx] += 1.0;
+                }
+            }
+        }
+
+        observed
+    }
+
+    /// Runs the test of `warp` against `density`
+    pub fn run<F, D>(&self, warp: F, density: D) -> ChiSquareResult
+    where
+        F: FnMut((f32, f32)) -> Option<(f32, f32)>,
+        D: Fn((f32, f32)) -> f32,
+    {
+        chi_square(&self.observed(warp), &self.expected(density))
+    }
+}
+
+/// Chi-square statistic of observed against expected counts, pooling cells that expect fewer than five
+pub fn chi_square(observed: &[f64], expected: &[f64]) -> ChiSquareResult {
+    assert_eq!(observed.len(), expected.len());
+
+    let mut cells: Vec<(f64, f64)> = observed.iter().copied().zip(expected.iter().copied()).collect();
+    cells.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    let (mut statistic, mut dof) = (0.0, 0usize);
+    let (mut pooled_observed, mut pooled_expected) = (0.0, 0.0);
+
+    for (o, e) in cells {
+        if e == 0.0 {
+            // samples where none are expected can't be explained at all
+            if o > 0.0 {
+                return ChiSquareResult {
+                    statistic: f64::INFINITY,
+                    degrees_of_freedom: dof,
+                    p_value: 0.0,
+                };
+            }
+        } else if e < 5.0 {
+            pooled_observed += o;
+            pooled_expected += e;
+        } else {
+            statistic += (o - e) * (o - e) / e;
+            dof += 1;
+        }
+    }
+
+    if pooled_expected > 0.0 {
+        statistic += (pooled_observed - pooled_expected) * (pooled_observed - pooled_expected) / pooled_expected;
+        dof += 1;
+    }
+
+    // one degree of freedom is lost to the fixed total
+    let dof = dof.saturating_sub(1);
+
+    ChiSquareResult {
+        statistic,
+        degrees_of_freedom: dof,
+        p_value: if dof > 0 { gamma_q(dof as f64 * 0.5, statistic * 0.5) } else { 1.0 },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gamma_q() {
+        // chi-square with two degrees of freedom has Q = exp(-x/2)
+        for &x in &[0.1, 1.0, 3.0, 10.0] {
+            assert!((gamma_q(1.0, x * 0.5) - (-x * 0.5f64).exp()).abs() < 1e-10);
+        }
+
+        // median of chi-square with one degree of freedom
+        assert!((gamma_q(0.5, 0.454936 * 0.5) - 0.5).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,292 @@
+//! Blue-noise dithering of sample values
+//!
+//! A tileable blue-noise texture assigns each pixel an offset that is used to toroidally shift
+//! the samples of that pixel. When neighboring pixels share the same underlying point set, their errors
+//! become decorrelated in a high-frequency pattern, which looks far less objectionable than white noise
+//! at low sample counts and is easily removed by filtering.
+//!
+//! Textures are generated offline with the void-and-cluster method (see the `sample_tables` example)
+//! and loaded at runtime.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::hash::hash_combine;
+use crate::rng::{permutation_element, Pcg32, ONE_MINUS_EPSILON};
+use crate::sampler::{Pixel, Sampler};
+use crate::table_io::{invalid_data, read_header, write_header};
+
+const MAGIC: &[u8; 4] = b"BLUE";
+
+/// Largest supported texture size, so that ranks fit in 16 bits
+pub const MAX_BLUE_NOISE_SIZE: u32 = 256;
+
+/// Standard deviation of the Gaussian energy filter, in pixels
+const SIGMA: f64 = 1.5;
+
+/// Gaussian energy of a binary pattern on a torus, updated incrementally as pixels are toggled
+#[derive(Clone)]
+struct Energy {
+    size: usize,
+    kernel: Vec<f64>,
+    values: Vec<f64>,
+}
+
+impl Energy {
+    fn new(size: usize) -> Energy {
+        let mut kernel = vec![0.0; size * size];
+
+        for y in 0..size {
+            for x in 0..size {
+                let dx = x.min(size - x) as f64;
+                let dy = y.min(size - y) as f64;
+
+                kernel[y * size + x] = (-(dx * dx + dy * dy) / (2.0 * SIGMA * SIGMA)).exp();
+            }
+        }
+
+        Energy {
+            size,
+            kernel,
+            values: vec![0.0; size * size],
+        }
+    }
+
+    fn toggle(&mut self, pixel: usize, sign: f64) {
+        let size = self.size;
+        let (px, py) = (pixel % size, pixel / size);
+
+        for y in 0..size {
+            let ky = (y + size - py) % size;
+
+            for x in 0..size {
+                let kx = (x + size - px) % size;
+                self.values[y * size + x] += sign * self.kernel[ky * size + kx];
+            }
+        }
+    }
+
+    /// The set pixel with the most energy
+    fn tightest_cluster(&self, pattern: &[bool]) -> usize {
+        (0..pattern.len())
+            .filter(|&i| pattern[i])
+            .max_by(|&a, &b| self.values[a].partial_cmp(&self.values[b]).unwrap())
+            .unwrap()
+    }
+
+    /// The unset pixel with the least energy
+    fn largest_void(&self, pattern: &[bool]) -> usize {
+        (0..pattern.len())
+            .filter(|&i| !pattern[i])
+            .min_by(|&a, &b| self.values[a].partial_cmp(&self.values[b]).unwrap())
+            .unwrap()
+    }
+}
+
+/// Tileable square texture of blue-noise values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlueNoiseTexture {
+    size: u32,
+    ranks: Vec<u16>,
+}
+
+impl BlueNoiseTexture {
+    /// Generates a `size` by `size` texture with Ulichney's void-and-cluster method.
+    ///
+    /// Runs in `O(size^4)` time, so large textures should be generated offline.
+    pub fn generate(size: u32, seed: u32) -> BlueNoiseTexture {
+        assert!(size > 1 && size <= MAX_BLUE_NOISE_SIZE);
+
+        let n = (size * size) as usize;
+        let mut rng = Pcg32::new(0, seed as u64);
+
+        // start from a random pattern of a tenth of the pixels
+        let ones = (n / 10).max(1);
+        let order = rng.next_u32();
+
+        let mut energy = Energy::new(size as usize);
+        let mut pattern = vec![false; n];
+
+        for i in 0..ones {
+            let pixel = permutation_element(i as u32, n as u32, order) as usize;
+            pattern[pixel] = true;
+            energy.toggle(pixel, 1.0);
+        }
+
+        // move pixels from the tightest cluster to the largest void until that stops changing anything
+        for _ in 0..n {
+            let cluster = energy.tightest_cluster(&pattern);
+            pattern[cluster] = false;
+            energy.toggle(cluster, -1.0);
+
+            let void = energy.largest_void(&pattern);
+            pattern[void] = true;
+            energy.toggle(void, 1.0);
+
+            if void == cluster {
+                break;
+            }
+        }
+
+        let mut ranks = vec![0u16; n];
+
+        // rank the initial pattern by removing its tightest clusters first
+        {
+            let mut pattern = pattern.clone();
+            let mut energy = energy.clone();
+
+            for rank in (0..ones).rev() {
+                let cluster = energy.tightest_cluster(&pattern);
+                pattern[cluster] = false;
+                energy.toggle(cluster, -1.0);
+                ranks[cluster] = rank as u16;
+            }
+        }
+
+        // then rank the rest by filling the largest voids. Past half-full, this is equivalent to
+        // Ulichney's tightest cluster of unset pixels, since the total energy on a torus is constant.
+        for rank in ones..n {
+            let void = energy.largest_void(&pattern);
+            pattern[void] = true;
+            energy.toggle(void, 1.0);
+            ranks[void] = rank as u16;
+        }
+
+        BlueNoiseTexture { size, ranks }
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Rank of a pixel, from `0` to `size * size - 1`, with the texture tiled infinitely
+    #[inline]
+    pub fn rank(&self, x: u32, y: u32) -> u32 {
+        let (x, y) = (x % self.size, y % self.size);
+        self.ranks[(y * self.size + x) as usize] as u32
+    }
+
+    /// Value in `[0, 1)` of a pixel, with the texture tiled infinitely
+    #[inline]
+    pub fn value(&self, x: u32, y: u32) -> f32 {
+        (self.rank(x, y) as f32 + 0.5) / (self.size * self.size) as f32
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_header(writer, MAGIC, &[self.size])?;
+        self.ranks.iter().try_for_each(|rank| writer.write_all(&rank.to_le_bytes()))
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<BlueNoiseTexture> {
+        let [size] = read_header(reader, MAGIC)?;
+
+        if !(2..=MAX_BLUE_NOISE_SIZE).contains(&size) {
+            return Err(invalid_data("invalid blue-noise texture size"));
+        }
+
+        let n = size * size;
+        let mut ranks = Vec::with_capacity(n as usize);
+
+        for _ in 0..n {
+            let mut bytes = [0; 2];
+            reader.read_exact(&mut bytes)?;
+
+            let rank = u16::from_le_bytes(bytes);
+            if rank as u32 >= n {
+                return Err(invalid_data("blue-noise rank out of range"));
+            }
+
+            ranks.push(rank);
+        }
+
+        Ok(BlueNoiseTexture { size, ranks })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<BlueNoiseTexture> {
+        BlueNoiseTexture::read(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+/// Shifts the samples of another sampler by per-pixel blue-noise offsets
+///
+/// Each dimension reads the texture at a different random offset, so dimensions are decorrelated.
+/// This only produces blue-noise error if the inner sampler gives neighboring pixels the same points,
+/// such as `SobolSampler` with `Randomization::None`.
+pub struct BlueNoiseSampler {
+    inner: Box<dyn Sampler>,
+    texture: Arc<BlueNoiseTexture>,
+    seed: u32,
+    pixel: Pixel,
+}
+
+impl BlueNoiseSampler {
+    pub fn new(inner: Box<dyn Sampler>, texture: Arc<BlueNoiseTexture>, seed: u32) -> BlueNoiseSampler {
+        BlueNoiseSampler {
+            inner,
+            texture,
+            seed,
+            pixel: (0, 0),
+        }
+    }
+
+    #[inline]
+    fn shift(&self, u: f32, dimension: u32) -> f32 {
+        let offset = hash_combine(&[self.seed, dimension]);
+        let (px, py) = self.pixel;
+
+        let shift = self.texture.value(px.wrapping_add(offset & 0xffff), py.wrapping_add(offset >> 16));
+
+        let u = u + shift;
+        (if u >= 1.0 { u - 1.0 } else { u }).min(ONE_MINUS_EPSILON)
+    }
+}
+
+impl Sampler for BlueNoiseSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.inner.samples_per_pixel()
+    }
+
+    fn start_pixel_sample_at(&mut self, pixel: Pixel, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.inner.start_pixel_sample_at(pixel, sample_index, dimension);
+    }
+
+    fn dimension(&self) -> u32 {
+        self.inner.dimension()
+    }
+
+    fn next_1d(&mut self) -> f32 {
+        let dimension = self.inner.dimension();
+        let u = self.inner.next_1d();
+
+        self.shift(u, dimension)
+    }
+
+    fn next_2d(&mut self) -> (f32, f32) {
+        let dimension = self.inner.dimension();
+        let (u, v) = self.inner.next_2d();
+
+        (self.shift(u, dimension), self.shift(v, dimension + 1))
+    }
+
+    fn is_reproducible(&self) -> bool {
+        self.inner.is_reproducible()
+    }
+
+    fn clone_for_thread(&self, thread_index: u32) -> Box<dyn Sampler> {
+        Box::new(BlueNoiseSampler {
+            inner: self.inner.clone_for_thread(thread_index),
+            texture: self.texture.clone(),
+            seed: self.seed,
+            pixel: self.pixel,
+        })
+    }
+}
@@ -0,0 +1,494 @@
+//! Discrete and piecewise-constant distributions for importance sampling
+//!
+//! Each distribution can be sampled one value at a time, or for every lane of a SIMD vector at once.
+//! The SIMD versions still search for each lane's segment separately, but do the rest of the work lane-wise,
+//! and produce the same values as the scalar versions.
+
+use thermite::*;
+
+use crate::rng::ONE_MINUS_EPSILON;
+
+#[inline(always)]
+fn lerp(t: f32, a: f32, b: f32) -> f32 {
+    (1.0 - t) * a + t * b
+}
+
+/// Position within `[0, 1)` of `u` warped into the segment `offset` of a CDF with `n` segments,
+/// where `c0` and `c1` are the values of the CDF at either end of the segment
+#[inline(always)]
+fn remap_lanes<S: Simd>(u: Vf32<S>, offset: Vf32<S>, c0: Vf32<S>, c1: Vf32<S>, n: usize) -> Vf32<S> {
+    let du = u - c0;
+    let width = c1 - c0;
+    let du = width.gt(Vf32::<S>::zero()).select(du / width, du);
+
+    (offset + du) / Vf32::<S>::splat(n as f32)
+}
+
+/// Piecewise-constant function over `[min, max]`, sampled proportionally to its absolute value
+#[derive(Debug, Clone)]
+pub struct Distribution1D {
+    func: Vec<f32>,
+    cdf: Vec<f32>,
+    min: f32,
+    max: f32,
+    integral: f32,
+}
+
+impl Distribution1D {
+    /// Creates a distribution from `func.len()` equally sized segments of `[min, max]`.
+    ///
+    /// If the function is zero everywhere, it is sampled uniformly, but with a density of zero.
+    pub fn new(func: &[f32], min: f32, max: f32) -> Distribution1D {
+        assert!(!func.is_empty() && func.len() < (1 << 24));
+        assert!(min < max);
+
+        let n = func.len();
+        let func: Vec<f32> = func.iter().map(|f| f.abs()).collect();
+
+        // accumulate in double precision, so long functions don't drift
+        let mut cdf = Vec::with_capacity(n + 1);
+        let mut sum = 0.0f64;
+
+        cdf.push(0.0);
+        for &f in &func {
+            sum += f as f64 * (max - min) as f64 / n as f64;
+            cdf.push(sum);
+        }
+
+        let cdf = if sum > 0.0 {
+            cdf.iter().map(|&c| (c / sum) as f32).collect()
+        } else {
+            (0..=n).map(|i| i as f32 / n as f32).collect()
+        };
+
+        Distribution1D {
+            func,
+            cdf,
+            min,
+            max,
+            integral: sum as f32,
+        }
+    }
+
+    /// Number of segments
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.func.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.func.is_empty()
+    }
+
+    /// Integral of the absolute value of the function over `[min, max]`
+    #[inline]
+    pub fn integral(&self) -> f32 {
+        self.integral
+    }
+
+    #[inline]
+    pub fn func(&self) -> &[f32] {
+        &self.func
+    }
+
+    /// Segment containing `u` in the CDF
+    #[inline]
+    fn find(&self, u: f32) -> usize {
+        // the last entry of the CDF is one, which u never reaches
+        let above = raygon_core::upper_bound(self.cdf.len(), |i| self.cdf[i] > u).unwrap_or(self.cdf.len());
+
+        above.saturating_sub(1).min(self.len() - 1)
+    }
+
+    /// Returns the sampled value, its density and the segment it is in
+    #[inline]
+    pub fn sample(&self, u: f32) -> (f32, f32, usize) {
+        let offset = self.find(u);
+
+        let (c0, c1) = (self.cdf[offset], self.cdf[offset + 1]);
+        let du = if c1 > c0 { (u - c0) / (c1 - c0) } else { u - c0 };
+
+        let pdf = if self.integral > 0.0 {
+            self.func[offset] / self.integral
+        } else {
+            0.0
+        };
+
+        let t = (offset as f32 + du) / self.len() as f32;
+
+        (t * (self.max - self.min) + self.min, pdf, offset)
+    }
+
+    /// Density of `sample` at `x`
+    #[inline]
+    pub fn pdf(&self, x: f32) -> f32 {
+        if self.integral <= 0.0 || x < self.min || x > self.max {
+            return 0.0;
+        }
+
+        self.func[self.offset(x)] / self.integral
+    }
+
+    /// Inverse of `sample`, or `None` if `x` is outside of `[min, max]`
+    #[inline]
+    pub fn invert(&self, x: f32) -> Option<f32> {
+        if x < self.min || x > self.max {
+            return None;
+        }
+
+        let c = (x - self.min) / (self.max - self.min) * self.len() as f32;
+        let offset = self.offset(x);
+
+        Some(lerp(c - offset as f32, self.cdf[offset], self.cdf[offset + 1]))
+    }
+
+    #[inline]
+    fn offset(&self, x: f32) -> usize {
+        let c = (x - self.min) / (self.max - self.min) * self.len() as f32;
+        (c.max(0.0) as usize).min(self.len() - 1)
+    }
+
+    /// Lane-wise `sample`
+    #[inline]
+    pub fn sample_simd<S: Simd>(&self, u: Vf32<S>) -> (Vf32<S>, Vf32<S>, Vu32<S>) {
+        let offset = u.map_scalar(|_, u| self.find(u) as f32);
+
+        let c0 = offset.map_scalar(|_, o| self.cdf[o as usize]);
+        let c1 = offset.map_scalar(|_, o| self.cdf[o as usize + 1]);
+        let f = offset.map_scalar(|_, o| self.func[o as usize]);
+
+        let t = remap_lanes::<S>(u, offset, c0, c1, self.len());
+        let x = t * Vf32::<S>::splat(self.max - self.min) + Vf32::<S>::splat(self.min);
+
+        let pdf = if self.integral > 0.0 {
+            f / Vf32::<S>::splat(self.integral)
+        } else {
+            Vf32::<S>::zero()
+        };
+
+        (x, pdf, offset.cast::<Vu32<S>>())
+    }
+}
+
+/// Piecewise-constant function over the unit square, sampled proportionally to its absolute value
+///
+/// Samples the row from the marginal distribution first, then the column from that row's conditional distribution.
+#[derive(Debug, Clone)]
+pub struct Distribution2D {
+    conditional: Vec<Distribution1D>,
+    marginal: Distribution1D,
+}
+
+impl Distribution2D {
+    /// Creates a distribution from `nv` rows of `nu` values each
+    pub fn new(func: &[f32], nu: usize, nv: usize) -> Distribution2D {
+        assert_eq!(func.len(), nu * nv);
+
+        let conditional: Vec<_> = func.chunks(nu).map(|row| Distribution1D::new(row, 0.0, 1.0)).collect();
+        let marginal: Vec<_> = conditional.iter().map(|row| row.integral()).collect();
+
+        Distribution2D {
+            marginal: Distribution1D::new(&marginal, 0.0, 1.0),
+            conditional,
+        }
+    }
+
+    /// Integral of the absolute value of the function over the unit square
+    #[inline]
+    pub fn integral(&self) -> f32 {
+        self.marginal.integral()
+    }
+
+    /// Returns the sampled point and its density
+    #[inline]
+    pub fn sample(&self, u: (f32, f32)) -> ((f32, f32), f32) {
+        let (y, pdf_y, row) = self.marginal.sample(u.1);
+        let (x, pdf_x, _) = self.conditional[row].sample(u.0);
+
+        ((x, y), pdf_x * pdf_y)
+    }
+
+    /// Density of `sample` at `p`
+    #[inline]
+    pub fn pdf(&self, p: (f32, f32)) -> f32 {
+        if self.integral() <= 0.0 {
+            return 0.0;
+        }
+
+        let row = &self.conditional[self.marginal.offset(p.1)];
+        row.func[row.offset(p.0)] / self.integral()
+    }
+
+    /// Inverse of `sample`, or `None` if `p` is outside of the unit square
+    #[inline]
+    pub fn invert(&self, p: (f32, f32)) -> Option<(f32, f32)> {
+        let v = self.marginal.invert(p.1)?;
+        let u = self.conditional[self.marginal.offset(p.1)].invert(p.0)?;
+
+        Some((u, v))
+    }
+
+    /// Lane-wise `sample`
+    #[inline]
+    pub fn sample_simd<S: Simd>(&self, u: (Vf32<S>, Vf32<S>)) -> ((Vf32<S>, Vf32<S>), Vf32<S>) {
+        let (y, _, row) = self.marginal.sample_simd::<S>(u.1);
+
+        let rows = |lane: usize| &self.conditional[row.extract(lane) as usize];
+
+        let offset = u.0.map_scalar(|lane, u| rows(lane).find(u) as f32);
+
+        let c0 = offset.map_scalar(|lane, o| rows(lane).cdf[o as usize]);
+        let c1 = offset.map_scalar(|lane, o| rows(lane).cdf[o as usize + 1]);
+        let f = offset.map_scalar(|lane, o| rows(lane).func[o as usize]);
+
+        let x = remap_lanes::<S>(u.0, offset, c0, c1, self.conditional[0].len());
+
+        // the row integrals cancel out of the product of the marginal and conditional densities
+        let pdf = if self.integral() > 0.0 {
+            f / Vf32::<S>::splat(self.integral())
+        } else {
+            Vf32::<S>::zero()
+        };
+
+        ((x, y), pdf)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AliasBin {
+    /// Probability of keeping this bin instead of taking its alias
+    q: f32,
+    /// Probability of the bin's own outcome
+    p: f32,
+    alias: u32,
+}
+
+/// Discrete distribution sampled in constant time with Walker's alias method
+///
+/// Uses Vose's construction, which is numerically stable.
+#[derive(Debug, Clone)]
+pub struct AliasTable {
+    bins: Vec<AliasBin>,
+}
+
+impl AliasTable {
+    /// Creates a table sampling each index proportionally to its weight.
+    ///
+    /// If all weights are zero, indices are sampled uniformly, but with a probability of zero.
+    pub fn new(weights: &[f32]) -> AliasTable {
+        assert!(!weights.is_empty() && weights.len() < (1 << 24));
+
+        let n = weights.len();
+        let sum: f64 = weights.iter().map(|&w| w.abs() as f64).sum();
+
+        let mut bins: Vec<_> = (0..n)
+            .map(|i| AliasBin {
+                q: 1.0,
+                p: if sum > 0.0 { (weights[i].abs() as f64 / sum) as f32 } else { 0.0 },
+                alias: i as u32,
+            })
+            .collect();
+
+        if sum <= 0.0 {
+            return AliasTable { bins };
+        }
+
+        // probabilities scaled so that the average bin holds exactly one
+        let (mut under, mut over) = (Vec::new(), Vec::new());
+
+        for (i, &w) in weights.iter().enumerate() {
+            let p = w.abs() as f64 / sum * n as f64;
+
+            if p < 1.0 {
+                under.push((i, p));
+            } else {
+                over.push((i, p));
+            }
+        }
+
+        while let (Some(&(u, pu)), Some(&(o, po))) = (under.last(), over.last()) {
+            under.pop();
+            over.pop();
+
+            // fill the rest of the underfull bin with the overfull outcome
+            bins[u].q = pu as f32;
+            bins[u].alias = o as u32;
+
+            let excess = po + pu - 1.0;
+
+            if excess < 1.0 {
+                under.push((o, excess));
+            } else {
+                over.push((o, excess));
+            }
+        }
+
+        // whatever remains is one up to rounding error, and keeps its own bin
+        for (i, _) in under.into_iter().chain(over) {
+            bins[i].q = 1.0;
+            bins[i].alias = i as u32;
+        }
+
+        AliasTable { bins }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bins.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bins.is_empty()
+    }
+
+    /// Returns the sampled index, its probability, and `u` remapped to a new uniform value in `[0, 1)`
+    #[inline]
+    pub fn sample(&self, u: f32) -> (usize, f32, f32) {
+        let n = self.len();
+
+        let offset = ((u * n as f32) as usize).min(n - 1);
+        let up = (u * n as f32 - offset as f32).min(ONE_MINUS_EPSILON);
+
+        let bin = &self.bins[offset];
+
+        if up < bin.q {
+            (offset, bin.p, (up / bin.q).min(ONE_MINUS_EPSILON))
+        } else {
+            let alias = bin.alias as usize;
+            (alias, self.bins[alias].p, ((up - bin.q) / (1.0 - bin.q)).min(ONE_MINUS_EPSILON))
+        }
+    }
+
+    /// Probability of sampling `index`
+    #[inline]
+    pub fn pmf(&self, index: usize) -> f32 {
+        self.bins[index].p
+    }
+
+    /// A value of `u` for which `sample` returns `index` with `u_remapped` as its remapped value,
+    /// or `None` if `index` can never be sampled
+    ///
+    /// Indices that only ever occur as aliases of other bins are found with a linear search.
+    pub fn invert(&self, index: usize, u_remapped: f32) -> Option<f32> {
+        let n = self.len() as f32;
+        let bin = &self.bins[index];
+
+        if bin.p <= 0.0 {
+            return None;
+        }
+
+        if bin.q > 0.0 {
+            return Some((index as f32 + u_remapped * bin.q) / n);
+        }
+
+        self.bins
+            .iter()
+            .enumerate()
+            .find(|(_, b)| b.alias as usize == index && b.q < 1.0)
+            .map(|(i, b)| (i as f32 + b.q + u_remapped * (1.0 - b.q)) / n)
+    }
+
+    /// Lane-wise `sample`
+    #[inline]
+    pub fn sample_simd<S: Simd>(&self, u: Vf32<S>) -> (Vu32<S>, Vf32<S>, Vf32<S>) {
+        let n = Vf32::<S>::splat(self.len() as f32);
+        let one_minus_epsilon = Vf32::<S>::splat(ONE_MINUS_EPSILON);
+
+        let offset = (u * n).floor().min(n - Vf32::<S>::one());
+        let up = (u * n - offset).min(one_minus_epsilon);
+
+        let q = offset.map_scalar(|_, o| self.bins[o as usize].q);
+        let alias = offset.map_scalar(|_, o| self.bins[o as usize].alias as f32);
+
+        let keep = up.lt(q);
+
+        let index = keep.select(offset, alias);
+        let pmf = index.map_scalar(|_, i| self.bins[i as usize].p);
+        let remapped = keep.select(up / q, (up - q) / (Vf32::<S>::one() - q)).min(one_minus_epsilon);
+
+        (index.cast::<Vu32<S>>(), pmf, remapped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::rng::Pcg32;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    fn uniform(rng: &mut Pcg32) -> ([f32; LANES], Vf32) {
+        let mut u = [0.0; LANES];
+        u.iter_mut().for_each(|u| *u = rng.next_f32());
+        (u, Vf32::load_unaligned(&u))
+    }
+
+    fn func(n: usize) -> Vec<f32> {
+        (0..n)
+            .map(|i| if i % 7 == 3 { 0.0 } else { (i as f32 * 0.37).sin().abs() + 0.1 })
+            .collect()
+    }
+
+    #[test]
+    fn test_distribution1d_simd_matches_scalar() {
+        let mut rng = Pcg32::default();
+        let dist = Distribution1D::new(&func(37), -1.0, 3.0);
+
+        for _ in 0..1000 {
+            let (u, v) = uniform(&mut rng);
+            let (x, pdf, offset) = dist.sample_simd::<AVX2>(v);
+
+            for lane in 0..LANES {
+                let expected = dist.sample(u[lane]);
+
+                assert_eq!(x.extract(lane), expected.0);
+                assert_eq!(pdf.extract(lane), expected.1);
+                assert_eq!(offset.extract(lane) as usize, expected.2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distribution2d_simd_matches_scalar() {
+        let mut rng = Pcg32::default();
+        let dist = Distribution2D::new(&func(12 * 9), 12, 9);
+
+        for _ in 0..1000 {
+            let ((u0, v0), (u1, v1)) = (uniform(&mut rng), uniform(&mut rng));
+            let ((x, y), pdf) = dist.sample_simd::<AVX2>((v0, v1));
+
+            for lane in 0..LANES {
+                let ((ex, ey), epdf) = dist.sample((u0[lane], u1[lane]));
+
+                assert_eq!(x.extract(lane), ex);
+                assert_eq!(y.extract(lane), ey);
+                assert!((pdf.extract(lane) - epdf).abs() <= epdf * 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_alias_table_simd_matches_scalar() {
+        let mut rng = Pcg32::default();
+        let table = AliasTable::new(&func(29));
+
+        for _ in 0..1000 {
+            let (u, v) = uniform(&mut rng);
+            let (index, pmf, remapped) = table.sample_simd::<AVX2>(v);
+
+            for lane in 0..LANES {
+                let expected = table.sample(u[lane]);
+
+                assert_eq!(index.extract(lane) as usize, expected.0);
+                assert_eq!(pmf.extract(lane), expected.1);
+                assert_eq!(remapped.extract(lane), expected.2);
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Halton sequence and radical inverses
+
+use crate::hash::{hash_combine, pixel_dimension_hash};
+use crate::rng::{permutation_element, ONE_MINUS_EPSILON};
+use crate::sampler::{Pixel, Sampler};
+use crate::scramble::Randomization;
+
+/// Number of prime bases available, and so dimensions of the Halton sequence
+pub const NUM_PRIMES: usize = 1024;
+
+/// The first `NUM_PRIMES` primes, used as the base of each Halton dimension
+pub static PRIMES: [u32; NUM_PRIMES] = build_primes();
+
+const fn build_primes() -> [u32; NUM_PRIMES] {
+    let mut primes = [0; NUM_PRIMES];
+    let mut count = 0;
+    let mut n = 2;
+
+    while count < NUM_PRIMES {
+        let mut i = 0;
+        let mut is_prime = true;
+
+        while i < count && primes[i] * primes[i] <= n {
+            if n % primes[i] == 0 {
+                is_prime = false;
+                break;
+            }
+            i += 1;
+        }
+
+        if is_prime {
+            primes[count] = n;
+            count += 1;
+        }
+
+        n += 1;
+    }
+
+    primes
+}
+
+/// Reflects the digits of `a` in base `PRIMES[base_index]` about the radix point
+#[inline]
+pub fn radical_inverse(base_index: usize, mut a: u32) -> f32 {
+    let base = PRIMES[base_index] as u64;
+    let inv_base = 1.0 / base as f64;
+
+    let mut reversed_digits = 0u64;
+    let mut inv_base_m = 1.0;
+
+    while a != 0 {
+        let next = a as u64 / base;
+        let digit = a as u64 - next * base;
+
+        reversed_digits = reversed_digits * base + digit;
+        inv_base_m *= inv_base;
+        a = next as u32;
+    }
+
+    ((reversed_digits as f64 * inv_base_m) as f32).min(ONE_MINUS_EPSILON)
+}
+
+/// Radical inverse of `a` in base `PRIMES[base_index]`, with its digits randomly permuted
+///
+/// Unlike the plain radical inverse, digits are scrambled until the precision of an `f32` is exhausted,
+/// so the trailing zero digits are randomized as well.
+pub fn scrambled_radical_inverse(base_index: usize, mut a: u32, randomization: Randomization, seed: u32) -> f32 {
+    if randomization == Randomization::None {
+        return radical_inverse(base_index, a);
+    }
+
+    let base = PRIMES[base_index];
+    let inv_base = 1.0 / base as f32;
+
+    let mut reversed_digits = 0u64;
+    let mut inv_base_m = 1.0f32;
+    let mut digit_index = 0;
+
+    while 1.0 - inv_base_m < 1.0 {
+        let next = a / base;
+        let digit = a - next * base;
+
+        let digit_seed = match randomization {
+            Randomization::Owen => hash_combine(&[seed, digit_index, reversed_digits as u32]),
+            _ => hash_combine(&[seed, digit_index]),
+        };
+
+        reversed_digits = reversed_digits * base as u64 + permutation_element(digit, base, digit_seed) as u64;
+        inv_base_m *= inv_base;
+        digit_index += 1;
+        a = next;
+    }
+
+    (reversed_digits as f32 * inv_base_m).min(ONE_MINUS_EPSILON)
+}
+
+/// Samples from a Halton sequence, randomized independently for every pixel and dimension
+///
+/// Each pixel draws from the start of the sequence. Dimensions past `NUM_PRIMES` wrap around.
+#[derive(Debug, Clone)]
+pub struct HaltonSampler {
+    samples_per_pixel: u32,
+    randomization: Randomization,
+    seed: u32,
+    pixel: Pixel,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl HaltonSampler {
+    pub fn new(samples_per_pixel: u32, randomization: Randomization, seed: u32) -> HaltonSampler {
+        HaltonSampler {
+            samples_per_pixel,
+            randomization,
+            seed,
+            pixel: (0, 0),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    #[inline]
+    fn sample(&self, dimension: u32) -> f32 {
+        let hash = pixel_dimension_hash(self.seed, self.pixel, dimension);
+
+        scrambled_radical_inverse(dimension as usize % NUM_PRIMES, self.sample_index, self.randomization, hash)
+    }
+}
+
+impl Sampler for HaltonSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample_at(&mut self, pixel: Pixel, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn dimension(&self) -> u32 {
+        self.dimension
+    }
+
+    fn next_1d(&mut self) -> f32 {
+        let u = self.sample(self.dimension);
+        self.dimension += 1;
+        u
+    }
+
+    fn next_2d(&mut self) -> (f32, f32) {
+        let u = (self.sample(self.dimension), self.sample(self.dimension + 1));
+        self.dimension += 2;
+        u
+    }
+
+    fn clone_for_thread(&self, _thread_index: u32) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,85 @@
+//! Hashing for deterministic seeding
+//!
+//! Every random choice a sampler makes is hashed from the seed and the pixel, sample index and dimension
+//! it is made for, rather than drawn from state carried over from earlier samples. Values then only depend
+//! on `(pixel, sample_index)`, and are bit-identical however pixels are split between tiles, threads or
+//! machines, and in whatever order they are rendered.
+
+use crate::rng::Pcg32;
+use crate::sampler::Pixel;
+
+/// Number of values reserved for each sample in the generator from `sample_rng`,
+/// more dimensions than any integrator will use
+pub const VALUES_PER_SAMPLE: u32 = 1 << 16;
+
+/// 32-bit integer hash by Chris Wellons, chosen to only need 32-bit integer operations
+#[inline]
+pub fn mix32(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846ca68b);
+    x ^= x >> 16;
+    x
+}
+
+/// Hashes a sequence of 32-bit values by folding them through `mix32`
+#[inline]
+pub fn hash_combine(values: &[u32]) -> u32 {
+    values.iter().fold(0x9e3779b9, |h, &v| mix32(h ^ v))
+}
+
+/// Seed shared by every sample of `pixel`
+#[inline]
+pub fn pixel_hash(seed: u32, pixel: Pixel) -> u32 {
+    hash_combine(&[seed, pixel.0, pixel.1])
+}
+
+/// Seed shared by every sample of `pixel` in `dimension`, used to randomize a pixel's sequence per dimension
+#[inline]
+pub fn pixel_dimension_hash(seed: u32, pixel: Pixel, dimension: u32) -> u32 {
+    hash_combine(&[seed, pixel.0, pixel.1, dimension])
+}
+
+/// Seed unique to one dimension of one sample
+#[inline]
+pub fn sample_hash(seed: u32, pixel: Pixel, sample_index: u32, dimension: u32) -> u32 {
+    hash_combine(&[seed, pixel.0, pixel.1, sample_index, dimension])
+}
+
+/// Generator positioned at `dimension` of sample `sample_index` of `pixel`
+///
+/// Each pixel has its own sequence, split into blocks of `VALUES_PER_SAMPLE` values per sample,
+/// so the generator is reconstructed in logarithmic time without replaying earlier samples.
+pub fn sample_rng(seed: u32, pixel: Pixel, sample_index: u32, dimension: u32) -> Pcg32 {
+    debug_assert!(dimension < VALUES_PER_SAMPLE);
+
+    let mut rng = Pcg32::new(pixel_hash(seed, pixel) as u64, seed as u64);
+    rng.advance(sample_index as i64 * VALUES_PER_SAMPLE as i64 + dimension as i64);
+    rng
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_rng_continues_sequence() {
+        let mut sequential = sample_rng(3, (5, 7), 2, 0);
+
+        for dimension in 0..100 {
+            let mut restarted = sample_rng(3, (5, 7), 2, dimension);
+            assert_eq!(restarted.next_u32(), sequential.next_u32());
+        }
+    }
+
+    #[test]
+    fn test_hashes_distinguish_inputs() {
+        let base = sample_hash(1, (2, 3), 4, 5);
+
+        assert_ne!(base, sample_hash(0, (2, 3), 4, 5));
+        assert_ne!(base, sample_hash(1, (3, 2), 4, 5));
+        assert_ne!(base, sample_hash(1, (2, 3), 5, 4));
+        assert_ne!(pixel_hash(1, (2, 3)), pixel_hash(1, (3, 2)));
+    }
+}
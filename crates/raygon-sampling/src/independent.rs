@@ -0,0 +1,65 @@
+use crate::hash::sample_hash;
+use crate::rng::u32_to_unit_f32;
+use crate::sampler::{Pixel, Sampler};
+
+/// Uniform random samples with no stratification
+///
+/// Every dimension of every sample is hashed from the seed, pixel, sample index and dimension,
+/// so any value can be regenerated in isolation regardless of the order samples are taken in.
+#[derive(Debug, Clone)]
+pub struct IndependentSampler {
+    samples_per_pixel: u32,
+    seed: u32,
+    pixel: Pixel,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl IndependentSampler {
+    pub fn new(samples_per_pixel: u32, seed: u32) -> IndependentSampler {
+        IndependentSampler {
+            samples_per_pixel,
+            seed,
+            pixel: (0, 0),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    #[inline]
+    fn sample(&self, dimension: u32) -> f32 {
+        u32_to_unit_f32(sample_hash(self.seed, self.pixel, self.sample_index, dimension))
+    }
+}
+
+impl Sampler for IndependentSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample_at(&mut self, pixel: Pixel, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn dimension(&self) -> u32 {
+        self.dimension
+    }
+
+    fn next_1d(&mut self) -> f32 {
+        let u = self.sample(self.dimension);
+        self.dimension += 1;
+        u
+    }
+
+    fn next_2d(&mut self) -> (f32, f32) {
+        let u = (self.sample(self.dimension), self.sample(self.dimension + 1));
+        self.dimension += 2;
+        u
+    }
+
+    fn clone_for_thread(&self, _thread_index: u32) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
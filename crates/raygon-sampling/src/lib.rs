@@ -1 +1,33 @@
+extern crate raygon_geometry as geometry;
+extern crate raygon_math as math;
 
+pub mod analysis;
+pub mod bluenoise;
+pub mod distribution;
+pub mod halton;
+pub mod hash;
+pub mod mis;
+pub mod pmj02;
+pub mod rng;
+pub mod scramble;
+pub mod soa;
+pub mod sobol;
+pub mod warp;
+
+mod independent;
+mod random;
+mod sampler;
+mod sobol_directions;
+mod stratified;
+mod table_io;
+
+pub use self::bluenoise::{BlueNoiseSampler, BlueNoiseTexture};
+pub use self::distribution::{AliasTable, Distribution1D, Distribution2D};
+pub use self::halton::HaltonSampler;
+pub use self::independent::IndependentSampler;
+pub use self::pmj02::{Pmj02Sampler, Pmj02Table};
+pub use self::random::RandomSampler;
+pub use self::sampler::{Pixel, Sampler};
+pub use self::scramble::Randomization;
+pub use self::sobol::SobolSampler;
+pub use self::stratified::StratifiedSampler;
@@ -0,0 +1,299 @@
+//! Multiple importance sampling
+//!
+//! Weights for combining samples from several techniques, in Veach's multi-sample and one-sample models.
+//! The two-technique heuristics take the number of samples taken with each technique and that technique's
+//! density at the sampled point. Infinite densities, as from delta distributions, get all of the weight.
+
+use thermite::*;
+
+/// Weighting heuristic for combining sampling techniques
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heuristic {
+    /// Weights proportional to each technique's density
+    Balance,
+    /// Weights proportional to the square of each technique's density, which reduces variance
+    /// further when one technique is much better than the others
+    Power,
+}
+
+impl Heuristic {
+    #[inline(always)]
+    fn apply(self, x: f32) -> f32 {
+        match self {
+            Heuristic::Balance => x,
+            Heuristic::Power => x * x,
+        }
+    }
+
+    /// Weight of a sample taken with `technique`, where `counts` holds the number of samples taken
+    /// with each technique and `pdfs` each technique's density at the sampled point
+    pub fn weight(self, technique: usize, counts: &[u32], pdfs: &[f32]) -> f32 {
+        debug_assert_eq!(counts.len(), pdfs.len());
+
+        let f = counts[technique] as f32 * pdfs[technique];
+
+        if f.is_infinite() {
+            return 1.0;
+        }
+
+        let sum: f32 = counts.iter().zip(pdfs).map(|(&n, &pdf)| self.apply(n as f32 * pdf)).sum();
+
+        if sum > 0.0 {
+            self.apply(f) / sum
+        } else {
+            0.0
+        }
+    }
+
+    #[inline(always)]
+    fn apply_simd<S: Simd>(self, x: Vf32<S>) -> Vf32<S> {
+        match self {
+            Heuristic::Balance => x,
+            Heuristic::Power => x * x,
+        }
+    }
+
+    /// Lane-wise `weight`
+    pub fn weight_simd<S: Simd>(self, technique: usize, counts: &[u32], pdfs: &[Vf32<S>]) -> Vf32<S> {
+        debug_assert_eq!(counts.len(), pdfs.len());
+
+        let f = Vf32::<S>::splat(counts[technique] as f32) * pdfs[technique];
+
+        let sum = counts.iter().zip(pdfs).fold(Vf32::<S>::zero(), |sum, (&n, &pdf)| {
+            sum + self.apply_simd::<S>(Vf32::<S>::splat(n as f32) * pdf)
+        });
+
+        heuristic_weight::<S>(self.apply_simd::<S>(f), sum)
+    }
+}
+
+/// Balance heuristic weight of technique `f` when combined with technique `g`
+#[inline]
+pub fn balance_heuristic(nf: u32, f_pdf: f32, ng: u32, g_pdf: f32) -> f32 {
+    let (f, g) = (nf as f32 * f_pdf, ng as f32 * g_pdf);
+
+    if f.is_infinite() {
+        1.0
+    } else if f + g > 0.0 {
+        f / (f + g)
+    } else {
+        0.0
+    }
+}
+
+/// Power heuristic weight, with an exponent of two, of technique `f` when combined with technique `g`
+#[inline]
+pub fn power_heuristic(nf: u32, f_pdf: f32, ng: u32, g_pdf: f32) -> f32 {
+    let (f, g) = (nf as f32 * f_pdf, ng as f32 * g_pdf);
+    let (f2, g2) = (f * f, g * g);
+
+    if f2.is_infinite() {
+        1.0
+    } else if f2 + g2 > 0.0 {
+        f2 / (f2 + g2)
+    } else {
+        0.0
+    }
+}
+
+/// Lane-wise `balance_heuristic`
+#[inline]
+pub fn balance_heuristic_simd<S: Simd>(nf: u32, f_pdf: Vf32<S>, ng: u32, g_pdf: Vf32<S>) -> Vf32<S> {
+    let f = Vf32::<S>::splat(nf as f32) * f_pdf;
+    let g = Vf32::<S>::splat(ng as f32) * g_pdf;
+
+    heuristic_weight::<S>(f, f + g)
+}
+
+/// Lane-wise `power_heuristic`
+#[inline]
+pub fn power_heuristic_simd<S: Simd>(nf: u32, f_pdf: Vf32<S>, ng: u32, g_pdf: Vf32<S>) -> Vf32<S> {
+    let f = Vf32::<S>::splat(nf as f32) * f_pdf;
+    let g = Vf32::<S>::splat(ng as f32) * g_pdf;
+
+    let f2 = f * f;
+
+    heuristic_weight::<S>(f2, g.mul_add(g, f2))
+}
+
+/// `f / sum`, or one where `f` is infinite and zero where `sum` is zero
+#[inline(always)]
+fn heuristic_weight<S: Simd>(f: Vf32<S>, sum: Vf32<S>) -> Vf32<S> {
+    let zero = Vf32::<S>::zero();
+    let weight = sum.gt(zero).select(f / sum, zero);
+
+    f.eq(Vf32::<S>::splat(f32::INFINITY)).select(Vf32::<S>::one(), weight)
+}
+
+/// Factor to multiply `f(x) / pdf` by in the one-sample model, where `technique` was chosen
+/// with probability `selection[technique]` and then sampled `x` with density `pdfs[technique]`
+///
+/// With the balance heuristic, which is optimal in this model, `f(x)` is simply divided by the
+/// mixture density of all techniques.
+pub fn one_sample_weight(heuristic: Heuristic, technique: usize, selection: &[f32], pdfs: &[f32]) -> f32 {
+    debug_assert_eq!(selection.len(), pdfs.len());
+
+    let c = selection[technique];
+    let f = c * pdfs[technique];
+
+    if f.is_infinite() {
+        return 1.0 / c;
+    }
+
+    let sum: f32 = selection.iter().zip(pdfs).map(|(&c, &pdf)| heuristic.apply(c * pdf)).sum();
+
+    if sum > 0.0 {
+        heuristic.apply(f) / sum / c
+    } else {
+        0.0
+    }
+}
+
+/// Lane-wise `one_sample_weight`, with the same selection probabilities in every lane
+pub fn one_sample_weight_simd<S: Simd>(heuristic: Heuristic, technique: usize, selection: &[f32], pdfs: &[Vf32<S>]) -> Vf32<S> {
+    debug_assert_eq!(selection.len(), pdfs.len());
+
+    let c = selection[technique];
+    let f = Vf32::<S>::splat(c) * pdfs[technique];
+
+    let sum = selection.iter().zip(pdfs).fold(Vf32::<S>::zero(), |sum, (&c, &pdf)| {
+        sum + heuristic.apply_simd::<S>(Vf32::<S>::splat(c) * pdf)
+    });
+
+    heuristic_weight::<S>(heuristic.apply_simd::<S>(f), sum) / Vf32::<S>::splat(c)
+}
+
+/// Multi-sample MIS estimator, combining a fixed number of samples from each technique
+#[derive(Debug, Clone)]
+pub struct MisEstimator {
+    heuristic: Heuristic,
+    counts: Vec<u32>,
+    sum: f64,
+}
+
+impl MisEstimator {
+    /// Creates an estimator taking `counts[i]` samples from technique `i`
+    pub fn new(heuristic: Heuristic, counts: &[u32]) -> MisEstimator {
+        MisEstimator {
+            heuristic,
+            counts: counts.to_vec(),
+            sum: 0.0,
+        }
+    }
+
+    /// Adds the value `f` of a sample taken with `technique`, where `pdfs` holds the density of
+    /// every technique at the sampled point
+    pub fn add(&mut self, technique: usize, f: f32, pdfs: &[f32]) {
+        let pdf = pdfs[technique];
+
+        if pdf > 0.0 && f != 0.0 {
+            let weight = self.heuristic.weight(technique, &self.counts, pdfs);
+
+            self.sum += (weight * f) as f64 / (self.counts[technique] as f64 * pdf as f64);
+        }
+    }
+
+    /// Estimate of the integral, once every sample has been added
+    pub fn estimate(&self) -> f32 {
+        self.sum as f32
+    }
+
+    /// Clears all samples, keeping the heuristic and sample counts
+    pub fn reset(&mut self) {
+        self.sum = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::rng::Pcg32;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    #[test]
+    fn test_heuristics_match_scalar() {
+        let mut rng = Pcg32::default();
+
+        let mut pdfs = |zeros: bool| {
+            let mut pdfs = [0.0; LANES];
+            for pdf in &mut pdfs {
+                let u = rng.next_f32();
+                *pdf = if zeros && u < 0.25 {
+                    0.0
+                } else if u > 0.95 {
+                    f32::INFINITY
+                } else {
+                    u * 10.0
+                };
+            }
+            pdfs
+        };
+
+        for _ in 0..1000 {
+            let (f, g) = (pdfs(false), pdfs(true));
+            let (vf, vg) = (Vf32::load_unaligned(&f), Vf32::load_unaligned(&g));
+
+            let balance = balance_heuristic_simd::<AVX2>(1, vf, 3, vg);
+            let power = power_heuristic_simd::<AVX2>(2, vf, 1, vg);
+
+            for lane in 0..LANES {
+                let (b, p) = (balance.extract(lane), power.extract(lane));
+
+                assert!((b - balance_heuristic(1, f[lane], 3, g[lane])).abs() < 1e-6);
+                assert!((p - power_heuristic(2, f[lane], 1, g[lane])).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weights_match_scalar() {
+        let mut rng = Pcg32::default();
+
+        let counts = [2, 1, 3];
+        let selection = [0.2, 0.5, 0.3];
+
+        for _ in 0..1000 {
+            let mut lanes = [[0.0; 3]; LANES];
+            for pdf in lanes.iter_mut().flat_map(|pdfs| pdfs.iter_mut()) {
+                let u = rng.next_f32();
+                *pdf = if u < 0.2 {
+                    0.0
+                } else if u > 0.95 {
+                    f32::INFINITY
+                } else {
+                    u * 10.0
+                };
+            }
+
+            let technique_pdfs = |technique: usize| {
+                let mut pdfs = [0.0; LANES];
+                for (pdf, lane) in pdfs.iter_mut().zip(&lanes) {
+                    *pdf = lane[technique];
+                }
+                Vf32::load_unaligned(&pdfs)
+            };
+
+            let vpdfs = [technique_pdfs(0), technique_pdfs(1), technique_pdfs(2)];
+
+            for &heuristic in &[Heuristic::Balance, Heuristic::Power] {
+                for technique in 0..3 {
+                    let weight = heuristic.weight_simd::<AVX2>(technique, &counts, &vpdfs);
+                    let one_sample = one_sample_weight_simd::<AVX2>(heuristic, technique, &selection, &vpdfs);
+
+                    for (lane, pdfs) in lanes.iter().enumerate() {
+                        let w = heuristic.weight(technique, &counts, pdfs);
+                        let o = one_sample_weight(heuristic, technique, &selection, pdfs);
+
+                        assert!((weight.extract(lane) - w).abs() < 1e-5);
+                        assert!((one_sample.extract(lane) - o).abs() < 1e-5);
+                    }
+                }
+            }
+        }
+    }
+}
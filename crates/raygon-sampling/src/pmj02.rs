@@ -0,0 +1,320 @@
+//! Progressive multi-jittered (0,2) sequences
+//!
+//! Every power-of-two prefix of a PMJ02 sequence is a (0, m, 2)-net in base 2, so each elementary
+//! interval holds exactly one point, and new points always fill the gaps left by earlier ones.
+//! Generating them is too slow to do while rendering, so sets are generated offline
+//! (see the `sample_tables` example) and loaded at runtime.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::hash::{hash_combine, pixel_dimension_hash};
+use crate::rng::{permutation_element, u32_to_unit_f32, Pcg32};
+use crate::sampler::{Pixel, Sampler};
+use crate::scramble::Randomization;
+use crate::table_io::{invalid_data, read_header, read_u32, write_header, write_u32};
+
+const MAGIC: &[u8; 4] = b"PMJ2";
+
+/// Top `bits` bits of a fixed-point value
+#[inline]
+fn top_bits(v: u32, bits: u32) -> u32 {
+    v.checked_shr(32 - bits).unwrap_or(0)
+}
+
+/// Random permutation of `0..n`
+struct Shuffle {
+    n: u32,
+    seed: u32,
+}
+
+impl Shuffle {
+    fn get(&self, i: u32) -> u32 {
+        permutation_element(i, self.n, self.seed)
+    }
+}
+
+/// Occupancy of every elementary interval of a `2^m` point net
+struct Intervals {
+    m: u32,
+    occupied: Vec<bool>,
+}
+
+impl Intervals {
+    fn new(m: u32) -> Intervals {
+        Intervals {
+            m,
+            occupied: vec![false; (m as usize + 1) << m],
+        }
+    }
+
+    /// Index of the interval of shape `2^-a` by `2^-(m-a)` containing the finest strata `(x, y)`
+    #[inline]
+    fn key(&self, a: u32, x: u32, y: u32) -> usize {
+        let m = self.m;
+        ((a as usize) << m) | (((x >> (m - a)) << (m - a)) | (y >> a)) as usize
+    }
+
+    fn is_free(&self, x: u32, y: u32) -> bool {
+        (0..=self.m).all(|a| !self.occupied[self.key(a, x, y)])
+    }
+
+    fn insert(&mut self, x: u32, y: u32) {
+        for a in 0..=self.m {
+            let key = self.key(a, x, y);
+            self.occupied[key] = true;
+        }
+    }
+}
+
+/// Generates one PMJ02 sequence of `n` fixed-point points, with `n` a power of two
+fn generate_set(n: usize, rng: &mut Pcg32) -> Vec<[u32; 2]> {
+    let mut points = Vec::with_capacity(n);
+    points.push([rng.next_u32(), rng.next_u32()]);
+
+    while points.len() < n {
+        let count = points.len();
+        let k = count.trailing_zeros();
+        let m = k + 1;
+
+        // retrying a doubling is almost never necessary, but nothing guarantees random choices can't get stuck
+        let added = 'retry: loop {
+            let mut intervals = Intervals::new(m);
+            for &[x, y] in &points {
+                intervals.insert(top_bits(x, m), top_bits(y, m));
+            }
+
+            // resolution of the subquadrants each new point is placed in
+            let res_bits = k / 2 + 1;
+            let per_bits = m - res_bits;
+            let per = 1 << per_bits;
+
+            let mut flips = vec![None; 1 << (2 * (res_bits - 1))];
+            let mut added = Vec::with_capacity(count);
+
+            for &[x, y] in &points {
+                let (sx, sy) = (top_bits(x, res_bits), top_bits(y, res_bits));
+
+                let (tx, ty) = if k % 2 == 0 {
+                    // the diagonally opposite subquadrant
+                    (sx ^ 1, sy ^ 1)
+                } else {
+                    // each cell holds a diagonal pair, so fill the other diagonal, consistently per cell
+                    let cell = ((sy >> 1) << (res_bits - 1) | (sx >> 1)) as usize;
+                    let flip = *flips[cell].get_or_insert_with(|| rng.next_u32() & 1 != 0);
+
+                    if flip {
+                        (sx ^ 1, sy)
+                    } else {
+                        (sx, sy ^ 1)
+                    }
+                };
+
+                // try the finest strata within the subquadrant in random order
+                let xs = Shuffle {
+                    n: per,
+                    seed: rng.next_u32(),
+                };
+                let ys = Shuffle {
+                    n: per,
+                    seed: rng.next_u32(),
+                };
+
+                let stratum = (0..per)
+                    .flat_map(|i| (0..per).map(move |j| (i, j)))
+                    .map(|(i, j)| ((tx << per_bits) + xs.get(i), (ty << per_bits) + ys.get(j)))
+                    .find(|&(x, y)| intervals.is_free(x, y));
+
+                let (x, y) = match stratum {
+                    Some(stratum) => stratum,
+                    None => continue 'retry,
+                };
+
+                intervals.insert(x, y);
+
+                // jitter within the finest strata
+                let jitter = |rng: &mut Pcg32| rng.next_u32().checked_shr(m).unwrap_or(0);
+                added.push([(x << (32 - m)) | jitter(rng), (y << (32 - m)) | jitter(rng)]);
+            }
+
+            break added;
+        };
+
+        points.extend(added);
+    }
+
+    points
+}
+
+/// Precomputed sets of PMJ02 points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pmj02Table {
+    sets: usize,
+    samples_per_set: usize,
+    points: Vec<[u32; 2]>,
+}
+
+impl Pmj02Table {
+    /// Generates `sets` independent sequences of `samples_per_set` points each.
+    ///
+    /// `samples_per_set` must be a power of two.
+    pub fn generate(sets: usize, samples_per_set: usize, seed: u32) -> Pmj02Table {
+        assert!(sets > 0 && samples_per_set.is_power_of_two());
+
+        let mut points = Vec::with_capacity(sets * samples_per_set);
+
+        for set in 0..sets {
+            let mut rng = Pcg32::new(set as u64, seed as u64);
+            points.extend(generate_set(samples_per_set, &mut rng));
+        }
+
+        Pmj02Table {
+            sets,
+            samples_per_set,
+            points,
+        }
+    }
+
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    pub fn samples_per_set(&self) -> usize {
+        self.samples_per_set
+    }
+
+    /// Point `index` of sequence `set`, as base-2 fixed-point values
+    #[inline]
+    pub fn point_bits(&self, set: usize, index: usize) -> [u32; 2] {
+        self.points[set * self.samples_per_set + index]
+    }
+
+    /// Point `index` of sequence `set`
+    #[inline]
+    pub fn point(&self, set: usize, index: usize) -> (f32, f32) {
+        let [x, y] = self.point_bits(set, index);
+        (u32_to_unit_f32(x), u32_to_unit_f32(y))
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_header(writer, MAGIC, &[self.sets as u32, self.samples_per_set as u32])?;
+
+        for &[x, y] in &self.points {
+            write_u32(writer, x)?;
+            write_u32(writer, y)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Pmj02Table> {
+        let [sets, samples_per_set] = read_header(reader, MAGIC)?;
+        let (sets, samples_per_set) = (sets as usize, samples_per_set as usize);
+
+        let len = match sets.checked_mul(samples_per_set) {
+            Some(len) if sets > 0 && samples_per_set.is_power_of_two() => len,
+            _ => return Err(invalid_data("invalid PMJ02 table dimensions")),
+        };
+
+        // not preallocated, so a corrupt header fails on the missing data instead of a huge allocation
+        let mut points = Vec::new();
+        for _ in 0..len {
+            points.push([read_u32(reader)?, read_u32(reader)?]);
+        }
+
+        Ok(Pmj02Table {
+            sets,
+            samples_per_set,
+            points,
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Pmj02Table> {
+        Pmj02Table::read(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+/// Samples from precomputed PMJ02 sequences
+///
+/// Every pixel and dimension uses a randomly chosen sequence from the table, randomized further
+/// with digit scrambling, which preserves the elementary intervals. 1D samples use the first coordinate.
+/// Sample indices past the length of the sequences wrap around.
+#[derive(Debug, Clone)]
+pub struct Pmj02Sampler {
+    table: Arc<Pmj02Table>,
+    samples_per_pixel: u32,
+    randomization: Randomization,
+    seed: u32,
+    pixel: Pixel,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl Pmj02Sampler {
+    pub fn new(table: Arc<Pmj02Table>, samples_per_pixel: u32, randomization: Randomization, seed: u32) -> Pmj02Sampler {
+        Pmj02Sampler {
+            table,
+            samples_per_pixel,
+            randomization,
+            seed,
+            pixel: (0, 0),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    #[inline]
+    fn sample(&self) -> (f32, f32) {
+        let hash = pixel_dimension_hash(self.seed, self.pixel, self.dimension);
+
+        let set = hash_combine(&[hash]) as usize % self.table.sets();
+        let index = self.sample_index as usize % self.table.samples_per_set();
+
+        let [x, y] = self.table.point_bits(set, index);
+
+        (
+            u32_to_unit_f32(self.randomization.scramble_bits(x, hash)),
+            u32_to_unit_f32(self.randomization.scramble_bits(y, hash_combine(&[hash, 1]))),
+        )
+    }
+}
+
+impl Sampler for Pmj02Sampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample_at(&mut self, pixel: Pixel, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn dimension(&self) -> u32 {
+        self.dimension
+    }
+
+    fn next_1d(&mut self) -> f32 {
+        let (u, _) = self.sample();
+        self.dimension += 1;
+        u
+    }
+
+    fn next_2d(&mut self) -> (f32, f32) {
+        let u = self.sample();
+        self.dimension += 2;
+        u
+    }
+
+    fn clone_for_thread(&self, _thread_index: u32) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::rng::Pcg32;
+use crate::sampler::{Pixel, Sampler};
+
+/// Samples drawn from one continuous random stream
+///
+/// Values do not depend on the pixel or sample index, only on the seed, thread and how many values
+/// were drawn before, so results are only reproducible if work is assigned to threads in the same order.
+/// This is the cheapest sampler, useful as a baseline.
+#[derive(Debug, Clone)]
+pub struct RandomSampler {
+    samples_per_pixel: u32,
+    seed: u32,
+    rng: Pcg32,
+    dimension: u32,
+}
+
+impl RandomSampler {
+    pub fn new(samples_per_pixel: u32, seed: u32) -> RandomSampler {
+        RandomSampler::with_stream(samples_per_pixel, seed, 0)
+    }
+
+    fn with_stream(samples_per_pixel: u32, seed: u32, stream: u32) -> RandomSampler {
+        RandomSampler {
+            samples_per_pixel,
+            seed,
+            rng: Pcg32::new(stream as u64, seed as u64),
+            dimension: 0,
+        }
+    }
+}
+
+impl Sampler for RandomSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample_at(&mut self, _pixel: Pixel, _sample_index: u32, dimension: u32) {
+        self.dimension = dimension;
+    }
+
+    fn dimension(&self) -> u32 {
+        self.dimension
+    }
+
+    fn next_1d(&mut self) -> f32 {
+        self.dimension += 1;
+        self.rng.next_f32()
+    }
+
+    fn next_2d(&mut self) -> (f32, f32) {
+        self.dimension += 2;
+        (self.rng.next_f32(), self.rng.next_f32())
+    }
+
+    fn is_reproducible(&self) -> bool {
+        false
+    }
+
+    fn clone_for_thread(&self, thread_index: u32) -> Box<dyn Sampler> {
+        Box::new(RandomSampler::with_stream(self.samples_per_pixel, self.seed, thread_index))
+    }
+}
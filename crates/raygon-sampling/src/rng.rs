@@ -0,0 +1,220 @@
+//! Scalar random number generation
+
+use crate::hash::hash_combine;
+
+/// Largest `f32` less than one, used to keep uniform samples in `[0, 1)`
+pub const ONE_MINUS_EPSILON: f32 = 0.99999994;
+
+const PCG32_DEFAULT_STATE: u64 = 0x853c49e6748fea9b;
+const PCG32_DEFAULT_STREAM: u64 = 0xda3e39cb94b95bdb;
+const PCG32_MULT: u64 = 0x5851f42d4c957f2d;
+
+/// Converts 32 random bits into a uniform float in `[0, 1)`
+#[inline]
+pub fn u32_to_unit_f32(x: u32) -> f32 {
+    // only 24 bits fit in the mantissa, so the result never rounds up to 1
+    (x >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+}
+
+/// PCG32 random number generator, by Melissa O'Neill
+///
+/// Each generator has a 64-bit state and one of 2^63 independent sequences,
+/// and can jump forwards or backwards in its sequence in logarithmic time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+    inc: u64,
+}
+
+impl Default for Pcg32 {
+    fn default() -> Pcg32 {
+        Pcg32 {
+            state: PCG32_DEFAULT_STATE,
+            inc: PCG32_DEFAULT_STREAM,
+        }
+    }
+}
+
+impl Pcg32 {
+    /// Creates a generator on sequence `sequence`, starting from `seed`
+    pub fn new(sequence: u64, seed: u64) -> Pcg32 {
+        let mut rng = Pcg32::default();
+        rng.set_sequence(sequence, seed);
+        rng
+    }
+
+    /// Moves to the start of `sequence`, with an offset given by `seed`
+    pub fn set_sequence(&mut self, sequence: u64, seed: u64) {
+        self.state = 0;
+        self.inc = (sequence << 1) | 1;
+        self.next_u32();
+        self.state = self.state.wrapping_add(seed);
+        self.next_u32();
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old.wrapping_mul(PCG32_MULT).wrapping_add(self.inc);
+
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rot = (old >> 59) as u32;
+
+        xorshifted.rotate_right(rot)
+    }
+
+    /// Uniform float in `[0, 1)`
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        u32_to_unit_f32(self.next_u32())
+    }
+
+    /// Skips ahead `delta` values, or back if negative
+    pub fn advance(&mut self, delta: i64) {
+        let mut cur_mult = PCG32_MULT;
+        let mut cur_plus = self.inc;
+        let mut acc_mult = 1u64;
+        let mut acc_plus = 0u64;
+
+        // two's complement makes negative deltas wrap around the 2^64 period
+        let mut delta = delta as u64;
+
+        while delta > 0 {
+            if delta & 1 != 0 {
+                acc_mult = acc_mult.wrapping_mul(cur_mult);
+                acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+            }
+
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+            delta >>= 1;
+        }
+
+        self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
+    }
+}
+
+/// xoshiro128** random number generator, by David Blackman and Sebastiano Vigna
+///
+/// Only needs 32-bit integer operations, so a vector of independent generators can run
+/// in SIMD lanes and produce exactly the same values as this scalar version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro128 {
+    s: [u32; 4],
+}
+
+impl Xoshiro128 {
+    /// Creates a generator for `stream`, with distinct streams of the same seed being independent
+    pub fn new(seed: u32, stream: u32) -> Xoshiro128 {
+        Xoshiro128 {
+            s: [
+                hash_combine(&[seed, stream, 0]),
+                hash_combine(&[seed, stream, 1]),
+                hash_combine(&[seed, stream, 2]),
+                hash_combine(&[seed, stream, 3]),
+            ],
+        }
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        let s = &mut self.s;
+
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 9;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(11);
+
+        result
+    }
+
+    /// Uniform float in `[0, 1)`
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        u32_to_unit_f32(self.next_u32())
+    }
+}
+
+/// Returns element `i` of a random permutation of `0..n`, selected by `seed`
+///
+/// This is the hash-based permutation from Kensler's "Correlated Multi-Jittered Sampling",
+/// so no permutation table has to be stored.
+pub fn permutation_element(mut i: u32, n: u32, seed: u32) -> u32 {
+    debug_assert!(i < n);
+
+    let p = seed;
+
+    let mut w = n - 1;
+    w |= w >> 1;
+    w |= w >> 2;
+    w |= w >> 4;
+    w |= w >> 8;
+    w |= w >> 16;
+
+    loop {
+        i ^= p;
+        i = i.wrapping_mul(0xe170893d);
+        i ^= p >> 16;
+        i ^= (i & w) >> 4;
+        i ^= p >> 8;
+        i = i.wrapping_mul(0x0929eb3f);
+        i ^= p >> 23;
+        i ^= (i & w) >> 1;
+        i = i.wrapping_mul(1 | p >> 27);
+        i = i.wrapping_mul(0x6935fa69);
+        i ^= (i & w) >> 11;
+        i = i.wrapping_mul(0x74dcb303);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0x9e501cc3);
+        i ^= (i & w) >> 2;
+        i = i.wrapping_mul(0xc860a3df);
+        i &= w;
+        i ^= i >> 5;
+
+        // cycle-walk until the value lands inside the range
+        if i < n {
+            break;
+        }
+    }
+
+    (i.wrapping_add(p)) % n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcg_advance() {
+        let mut a = Pcg32::new(7, 42);
+        let mut b = a.clone();
+
+        for _ in 0..1000 {
+            a.next_u32();
+        }
+
+        b.advance(1000);
+        assert_eq!(a, b);
+
+        b.advance(-1000);
+        assert_eq!(b, Pcg32::new(7, 42));
+    }
+
+    #[test]
+    fn test_permutation_element() {
+        for &n in &[1, 2, 7, 16, 100] {
+            let mut seen = vec![false; n as usize];
+
+            for i in 0..n {
+                let j = permutation_element(i, n, 0xdeadbeef) as usize;
+                assert!(!seen[j], "{} repeated in permutation of {}", j, n);
+                seen[j] = true;
+            }
+        }
+    }
+}
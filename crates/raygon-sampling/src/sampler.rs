@@ -0,0 +1,43 @@
+/// Integer pixel coordinates
+pub type Pixel = (u32, u32);
+
+/// Generates the sample values used to render each pixel
+///
+/// A sampler is started at a pixel, sample index and dimension, then each call to `next_1d`
+/// or `next_2d` consumes the next one or two dimensions of that sample. Integrators must
+/// consume dimensions in the same order for every sample so each dimension has a consistent meaning.
+pub trait Sampler: Send {
+    /// Number of samples taken for each pixel
+    fn samples_per_pixel(&self) -> u32;
+
+    /// Begins generating values for sample `sample_index` of `pixel`, starting at `dimension`
+    fn start_pixel_sample_at(&mut self, pixel: Pixel, sample_index: u32, dimension: u32);
+
+    /// Begins generating values for sample `sample_index` of `pixel`, starting from the first dimension
+    #[inline]
+    fn start_pixel_sample(&mut self, pixel: Pixel, sample_index: u32) {
+        self.start_pixel_sample_at(pixel, sample_index, 0);
+    }
+
+    /// The dimension that will be used by the next call to `next_1d` or `next_2d`
+    fn dimension(&self) -> u32;
+
+    /// Uniform value in `[0, 1)` for the next dimension
+    fn next_1d(&mut self) -> f32;
+
+    /// Uniform point in `[0, 1)^2` for the next two dimensions
+    fn next_2d(&mut self) -> (f32, f32);
+
+    /// Whether values only depend on the pixel, sample index and dimension, so renders are bit-identical
+    /// however pixels are divided between tiles, threads or machines
+    #[inline]
+    fn is_reproducible(&self) -> bool {
+        true
+    }
+
+    /// Creates a sampler with the same configuration for use on another thread
+    ///
+    /// Samplers whose values only depend on the pixel and sample index produce identical values
+    /// on every thread, while stream-based samplers use `thread_index` to select an independent stream.
+    fn clone_for_thread(&self, thread_index: u32) -> Box<dyn Sampler>;
+}
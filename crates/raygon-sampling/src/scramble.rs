@@ -0,0 +1,40 @@
+//! Randomization of low-discrepancy sequences
+
+/// How a low-discrepancy sequence is randomized for each pixel and dimension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Randomization {
+    /// Unrandomized, so every pixel sees exactly the same points
+    None,
+    /// Random digit scrambling, which applies the same permutation to every digit at a given position.
+    /// For base 2 this is an XOR with the seed.
+    Xor,
+    /// Nested uniform scrambling, where the permutation of each digit depends on all the digits before it
+    Owen,
+}
+
+/// Nested uniform (Owen) scrambling of a base-2 fixed-point value in `[0, 1)`
+///
+/// Uses the hash-based scrambler of Laine and Karras, as refined by Vegdahl, which flips each
+/// bit depending only on the bits above it.
+#[inline]
+pub fn owen_scramble(mut v: u32, seed: u32) -> u32 {
+    v = v.reverse_bits();
+    v ^= v.wrapping_mul(0x3d20adea);
+    v = v.wrapping_add(seed);
+    v = v.wrapping_mul((seed >> 16) | 1);
+    v ^= v.wrapping_mul(0x05526c56);
+    v ^= v.wrapping_mul(0x53a22864);
+    v.reverse_bits()
+}
+
+impl Randomization {
+    /// Randomizes a base-2 fixed-point value in `[0, 1)`
+    #[inline]
+    pub fn scramble_bits(self, v: u32, seed: u32) -> u32 {
+        match self {
+            Randomization::None => v,
+            Randomization::Xor => v ^ seed,
+            Randomization::Owen => owen_scramble(v, seed),
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Sample generation for every lane of a SIMD vector at once
+//!
+//! Each lane produces exactly the same value as the scalar samplers and generators would for
+//! that lane's pixel, sample index or stream, so SoA code can be mixed freely with scalar code.
+
+use thermite::*;
+
+use crate::scramble::Randomization;
+use crate::sobol::{NUM_SOBOL_DIMENSIONS, SOBOL_MATRICES, SOBOL_MATRIX_SIZE};
+
+#[inline(always)]
+fn rotate_left<S: Simd>(x: Vu32<S>, n: u32) -> Vu32<S> {
+    (x << n) | (x >> (32 - n))
+}
+
+#[inline(always)]
+fn reverse_bits<S: Simd>(mut x: Vu32<S>) -> Vu32<S> {
+    let c = Vu32::<S>::splat;
+
+    x = ((x >> 1) & c(0x55555555)) | ((x & c(0x55555555)) << 1);
+    x = ((x >> 2) & c(0x33333333)) | ((x & c(0x33333333)) << 2);
+    x = ((x >> 4) & c(0x0f0f0f0f)) | ((x & c(0x0f0f0f0f)) << 4);
+    x = ((x >> 8) & c(0x00ff00ff)) | ((x & c(0x00ff00ff)) << 8);
+
+    (x >> 16) | (x << 16)
+}
+
+/// Lane-wise `hash::mix32`
+#[inline(always)]
+pub fn mix32<S: Simd>(mut x: Vu32<S>) -> Vu32<S> {
+    x ^= x >> 16;
+    x *= Vu32::<S>::splat(0x7feb352d);
+    x ^= x >> 15;
+    x *= Vu32::<S>::splat(0x846ca68b);
+    x ^= x >> 16;
+    x
+}
+
+/// Lane-wise `hash::hash_combine`
+#[inline]
+pub fn hash_combine<S: Simd>(values: &[Vu32<S>]) -> Vu32<S> {
+    values.iter().fold(Vu32::<S>::splat(0x9e3779b9), |h, &v| mix32::<S>(h ^ v))
+}
+
+/// Lane-wise `rng::u32_to_unit_f32`
+#[inline(always)]
+pub fn u32_to_unit_f32<S: Simd>(x: Vu32<S>) -> Vf32<S> {
+    // 24-bit integers convert to float exactly, regardless of signedness
+    (x >> 8).cast::<Vf32<S>>() * Vf32::<S>::splat(1.0 / (1u32 << 24) as f32)
+}
+
+/// Lane-wise `scramble::owen_scramble`
+#[inline]
+pub fn owen_scramble<S: Simd>(mut v: Vu32<S>, seed: Vu32<S>) -> Vu32<S> {
+    let c = Vu32::<S>::splat;
+
+    v = reverse_bits::<S>(v);
+    v ^= v * c(0x3d20adea);
+    v += seed;
+    v *= (seed >> 16) | c(1);
+    v ^= v * c(0x05526c56);
+    v ^= v * c(0x53a22864);
+
+    reverse_bits::<S>(v)
+}
+
+/// Lane-wise `Randomization::scramble_bits`
+#[inline]
+pub fn scramble_bits<S: Simd>(randomization: Randomization, v: Vu32<S>, seed: Vu32<S>) -> Vu32<S> {
+    match randomization {
+        Randomization::None => v,
+        Randomization::Xor => v ^ seed,
+        Randomization::Owen => owen_scramble::<S>(v, seed),
+    }
+}
+
+/// Dimension `dimension` of the Sobol' points given by each lane of `index`, as base-2 fixed-point values
+#[inline]
+pub fn sobol_sample_bits<S: Simd>(index: Vu32<S>, dimension: u32) -> Vu32<S> {
+    let offset = (dimension as usize % NUM_SOBOL_DIMENSIONS) * SOBOL_MATRIX_SIZE;
+    let matrix = &SOBOL_MATRICES[offset..offset + SOBOL_MATRIX_SIZE];
+
+    let zero = Vu32::<S>::zero();
+    let one = Vu32::<S>::one();
+
+    let mut v = zero;
+    for (i, &m) in matrix.iter().enumerate() {
+        let bits = index >> i as u32;
+
+        if bits.eq(zero).all() {
+            break;
+        }
+
+        // all ones where the bit is set, so the direction number is kept
+        v ^= Vu32::<S>::splat(m) & (zero - (bits & one));
+    }
+
+    v
+}
+
+/// Lane-wise `sobol::sobol_sample`
+#[inline]
+pub fn sobol_sample<S: Simd>(index: Vu32<S>, dimension: u32, randomization: Randomization, seed: Vu32<S>) -> Vf32<S> {
+    u32_to_unit_f32::<S>(scramble_bits::<S>(randomization, sobol_sample_bits::<S>(index, dimension), seed))
+}
+
+/// Pixel coordinates and sample index for each lane
+#[derive(Clone, Copy)]
+pub struct PixelSamples<S: Simd> {
+    pub px: Vu32<S>,
+    pub py: Vu32<S>,
+    pub sample_index: Vu32<S>,
+}
+
+impl<S: Simd> PixelSamples<S> {
+    pub fn new(px: Vu32<S>, py: Vu32<S>, sample_index: Vu32<S>) -> Self {
+        PixelSamples { px, py, sample_index }
+    }
+
+    /// Consecutive samples `first_index..` of a single pixel
+    pub fn consecutive(pixel: (u32, u32), first_index: u32) -> Self {
+        PixelSamples {
+            px: Vu32::<S>::splat(pixel.0),
+            py: Vu32::<S>::splat(pixel.1),
+            sample_index: Vu32::<S>::indexed() + Vu32::<S>::splat(first_index),
+        }
+    }
+
+    /// Lane-wise `hash::pixel_dimension_hash`
+    #[inline]
+    pub fn pixel_dimension_hash(&self, seed: u32, dimension: u32) -> Vu32<S> {
+        hash_combine::<S>(&[Vu32::<S>::splat(seed), self.px, self.py, Vu32::<S>::splat(dimension)])
+    }
+
+    /// Lane-wise `hash::sample_hash`
+    #[inline]
+    pub fn sample_hash(&self, seed: u32, dimension: u32) -> Vu32<S> {
+        let (seed, dimension) = (Vu32::<S>::splat(seed), Vu32::<S>::splat(dimension));
+
+        hash_combine::<S>(&[seed, self.px, self.py, self.sample_index, dimension])
+    }
+
+    /// Values of `IndependentSampler` at `dimension`
+    #[inline]
+    pub fn independent(&self, seed: u32, dimension: u32) -> Vf32<S> {
+        u32_to_unit_f32::<S>(self.sample_hash(seed, dimension))
+    }
+
+    /// Values of `SobolSampler` at `dimension`
+    #[inline]
+    pub fn sobol(&self, randomization: Randomization, seed: u32, dimension: u32) -> Vf32<S> {
+        sobol_sample::<S>(
+            self.sample_index,
+            dimension,
+            randomization,
+            self.pixel_dimension_hash(seed, dimension),
+        )
+    }
+}
+
+/// One `rng::Xoshiro128` generator per lane
+#[derive(Clone, Copy)]
+pub struct Xoshiro128<S: Simd> {
+    s: [Vu32<S>; 4],
+}
+
+impl<S: Simd> Xoshiro128<S> {
+    /// Creates generators for each lane of `streams`, matching `rng::Xoshiro128::new(seed, stream)`
+    pub fn new(seed: u32, streams: Vu32<S>) -> Self {
+        let seed = Vu32::<S>::splat(seed);
+        let word = |k| hash_combine::<S>(&[seed, streams, Vu32::<S>::splat(k)]);
+
+        Xoshiro128 {
+            s: [word(0), word(1), word(2), word(3)],
+        }
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> Vu32<S> {
+        let s = &mut self.s;
+
+        let result = rotate_left::<S>(s[1] * Vu32::<S>::splat(5), 7) * Vu32::<S>::splat(9);
+        let t = s[1] << 9;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = rotate_left::<S>(s[3], 11);
+
+        result
+    }
+
+    /// Uniform floats in `[0, 1)`
+    #[inline]
+    pub fn next_f32(&mut self) -> Vf32<S> {
+        u32_to_unit_f32::<S>(self.next_u32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::{rng, sobol, IndependentSampler, Pixel, Sampler, SobolSampler};
+
+    type Vu32 = <AVX2 as Simd>::Vu32;
+
+    const LANES: usize = <AVX2 as Simd>::Vu32::NUM_ELEMENTS;
+
+    fn pixels(i: u32) -> [Pixel; LANES] {
+        let mut pixels = [(0, 0); LANES];
+        for (lane, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = (i * 7 + lane as u32, i * 3 + lane as u32 / 2);
+        }
+        pixels
+    }
+
+    fn lanes(pixels: &[Pixel; LANES], index: u32) -> PixelSamples<AVX2> {
+        let (mut px, mut py) = ([0; LANES], [0; LANES]);
+        for lane in 0..LANES {
+            px[lane] = pixels[lane].0;
+            py[lane] = pixels[lane].1;
+        }
+
+        PixelSamples::new(Vu32::load_unaligned(&px), Vu32::load_unaligned(&py), Vu32::splat(index))
+    }
+
+    #[test]
+    fn test_independent_matches_scalar() {
+        let mut sampler = IndependentSampler::new(64, 5);
+
+        for i in 0..64 {
+            let pixels = pixels(i);
+            let samples = lanes(&pixels, i);
+
+            for dim in 0..8 {
+                let v = samples.independent(5, dim);
+
+                for (lane, &pixel) in pixels.iter().enumerate() {
+                    sampler.start_pixel_sample_at(pixel, i, dim);
+                    assert_eq!(v.extract(lane), sampler.next_1d());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_matches_scalar() {
+        for &randomization in &[Randomization::None, Randomization::Xor, Randomization::Owen] {
+            let mut sampler = SobolSampler::new(1 << 20, randomization, 11);
+
+            for i in 0..64 {
+                let pixels = pixels(i);
+                let samples = lanes(&pixels, i * 4099);
+
+                for dim in (0..sobol::NUM_SOBOL_DIMENSIONS as u32).step_by(53) {
+                    let v = samples.sobol(randomization, 11, dim);
+
+                    for (lane, &pixel) in pixels.iter().enumerate() {
+                        sampler.start_pixel_sample_at(pixel, i * 4099, dim);
+                        assert_eq!(v.extract(lane), sampler.next_1d());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_xoshiro_matches_scalar() {
+        let streams = Vu32::indexed() * Vu32::splat(3);
+
+        let mut simd = Xoshiro128::<AVX2>::new(42, streams);
+        let mut scalar: Vec<_> = (0..LANES).map(|lane| rng::Xoshiro128::new(42, lane as u32 * 3)).collect();
+
+        for _ in 0..1000 {
+            let v = simd.next_f32();
+
+            for (lane, rng) in scalar.iter_mut().enumerate() {
+                assert_eq!(v.extract(lane), rng.next_f32());
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Sobol' sequence
+
+use crate::hash::pixel_dimension_hash;
+use crate::rng::u32_to_unit_f32;
+use crate::sampler::{Pixel, Sampler};
+use crate::scramble::Randomization;
+use crate::sobol_directions::SOBOL_PARAMETERS;
+
+pub use crate::sobol_directions::NUM_SOBOL_DIMENSIONS;
+
+/// Number of direction numbers per dimension, one for each bit of the sample index
+pub const SOBOL_MATRIX_SIZE: usize = 32;
+
+/// Generator matrices of every dimension, stored as `SOBOL_MATRIX_SIZE` direction numbers each
+pub static SOBOL_MATRICES: [u32; NUM_SOBOL_DIMENSIONS * SOBOL_MATRIX_SIZE] = build_matrices();
+
+const fn build_matrices() -> [u32; NUM_SOBOL_DIMENSIONS * SOBOL_MATRIX_SIZE] {
+    let mut matrices = [0; NUM_SOBOL_DIMENSIONS * SOBOL_MATRIX_SIZE];
+
+    // the first dimension is the van der Corput sequence
+    let mut k = 0;
+    while k < SOBOL_MATRIX_SIZE {
+        matrices[k] = 1 << (31 - k);
+        k += 1;
+    }
+
+    let mut dim = 1;
+    while dim < NUM_SOBOL_DIMENSIONS {
+        let (s, a, m) = SOBOL_PARAMETERS[dim - 1];
+        let (s, v) = (s as usize, dim * SOBOL_MATRIX_SIZE);
+
+        let mut k = 0;
+        while k < SOBOL_MATRIX_SIZE {
+            matrices[v + k] = if k < s {
+                m[k] << (31 - k)
+            } else {
+                let mut x = matrices[v + k - s] ^ (matrices[v + k - s] >> s);
+
+                let mut i = 1;
+                while i < s {
+                    if (a >> (s - 1 - i)) & 1 != 0 {
+                        x ^= matrices[v + k - i];
+                    }
+                    i += 1;
+                }
+
+                x
+            };
+
+            k += 1;
+        }
+
+        dim += 1;
+    }
+
+    matrices
+}
+
+/// Dimension `dimension` of Sobol' point `index`, as a base-2 fixed-point value
+///
+/// Dimensions past `NUM_SOBOL_DIMENSIONS` wrap around.
+#[inline]
+pub fn sobol_sample_bits(mut index: u32, dimension: u32) -> u32 {
+    let matrix = &SOBOL_MATRICES[(dimension as usize % NUM_SOBOL_DIMENSIONS) * SOBOL_MATRIX_SIZE..];
+
+    let mut v = 0;
+    let mut i = 0;
+    while index != 0 {
+        if index & 1 != 0 {
+            v ^= matrix[i];
+        }
+
+        index >>= 1;
+        i += 1;
+    }
+
+    v
+}
+
+/// Dimension `dimension` of Sobol' point `index`, randomized with `seed`
+#[inline]
+pub fn sobol_sample(index: u32, dimension: u32, randomization: Randomization, seed: u32) -> f32 {
+    u32_to_unit_f32(randomization.scramble_bits(sobol_sample_bits(index, dimension), seed))
+}
+
+/// Samples from a Sobol' sequence, randomized independently for every pixel and dimension
+///
+/// Each pixel draws from the start of the sequence, so sample counts should be powers of two
+/// for the best stratification.
+#[derive(Debug, Clone)]
+pub struct SobolSampler {
+    samples_per_pixel: u32,
+    randomization: Randomization,
+    seed: u32,
+    pixel: Pixel,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl SobolSampler {
+    pub fn new(samples_per_pixel: u32, randomization: Randomization, seed: u32) -> SobolSampler {
+        SobolSampler {
+            samples_per_pixel,
+            randomization,
+            seed,
+            pixel: (0, 0),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    #[inline]
+    fn sample(&self, dimension: u32) -> f32 {
+        let hash = pixel_dimension_hash(self.seed, self.pixel, dimension);
+
+        sobol_sample(self.sample_index, dimension, self.randomization, hash)
+    }
+}
+
+impl Sampler for SobolSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample_at(&mut self, pixel: Pixel, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn dimension(&self) -> u32 {
+        self.dimension
+    }
+
+    fn next_1d(&mut self) -> f32 {
+        let u = self.sample(self.dimension);
+        self.dimension += 1;
+        u
+    }
+
+    fn next_2d(&mut self) -> (f32, f32) {
+        let u = (self.sample(self.dimension), self.sample(self.dimension + 1));
+        self.dimension += 2;
+        u
+    }
+
+    fn clone_for_thread(&self, _thread_index: u32) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
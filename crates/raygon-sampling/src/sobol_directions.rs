@@ -0,0 +1,1033 @@
+//! Generated by `cargo run --release --example sobol_directions`, do not edit
+
+pub const NUM_SOBOL_DIMENSIONS: usize = 1024;
+pub(crate) const MAX_DEGREE: usize = 13;
+
+/// Polynomial degree, interior coefficients and initial direction numbers of each dimension after the first
+#[rustfmt::skip]
+#[allow(clippy::large_const_arrays)]
+pub(crate) const SOBOL_PARAMETERS: [(u32, u32, [u32; MAX_DEGREE]); NUM_SOBOL_DIMENSIONS - 1] = [
+    (1, 0, [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (2, 1, [1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (3, 1, [1, 3, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (3, 2, [1, 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (4, 1, [1, 3, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (4, 4, [1, 1, 3, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (5, 2, [1, 1, 5, 13, 7, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (5, 4, [1, 3, 7, 7, 21, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (5, 7, [1, 1, 1, 7, 27, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (5, 11, [1, 1, 3, 13, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (5, 13, [1, 3, 3, 15, 29, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (5, 14, [1, 3, 5, 11, 9, 0, 0, 0, 0, 0, 0, 0, 0]),
+    (6, 1, [1, 1, 1, 3, 11, 41, 0, 0, 0, 0, 0, 0, 0]),
+    (6, 13, [1, 1, 7, 3, 13, 3, 0, 0, 0, 0, 0, 0, 0]),
+    (6, 16, [1, 1, 1, 1, 5, 9, 0, 0, 0, 0, 0, 0, 0]),
+    (6, 19, [1, 1, 7, 5, 31, 27, 0, 0, 0, 0, 0, 0, 0]),
+    (6, 22, [1, 3, 1, 9, 21, 55, 0, 0, 0, 0, 0, 0, 0]),
+    (6, 25, [1, 3, 3, 5, 1, 11, 0, 0, 0, 0, 0, 0, 0]),
+    (7, 1, [1, 3, 7, 9, 13, 9, 93, 0, 0, 0, 0, 0, 0]),
+    (7, 4, [1, 1, 3, 13, 21, 53, 71, 0, 0, 0, 0, 0, 0]),
+    (7, 7, [1, 1, 5, 5, 15, 21, 3, 0, 0, 0, 0, 0, 0]),
+    (7, 8, [1, 1, 1, 7, 13, 23, 103, 0, 0, 0, 0, 0, 0]),
+    (7, 14, [1, 3, 7, 1, 9, 37, 25, 0, 0, 0, 0, 0, 0]),
+    (7, 19, [1, 1, 7, 7, 11, 43, 21, 0, 0, 0, 0, 0, 0]),
+    (7, 21, [1, 3, 5, 7, 21, 43, 109, 0, 0, 0, 0, 0, 0]),
+    (7, 28, [1, 3, 7, 11, 1, 11, 41, 0, 0, 0, 0, 0, 0]),
+    (7, 31, [1, 1, 7, 11, 1, 53, 21, 0, 0, 0, 0, 0, 0]),
+    (7, 32, [1, 1, 7, 13, 19, 45, 111, 0, 0, 0, 0, 0, 0]),
+    (7, 37, [1, 3, 1, 5, 29, 11, 79, 0, 0, 0, 0, 0, 0]),
+    (7, 41, [1, 3, 7, 9, 3, 47, 87, 0, 0, 0, 0, 0, 0]),
+    (7, 42, [1, 3, 5, 7, 15, 7, 23, 0, 0, 0, 0, 0, 0]),
+    (7, 50, [1, 1, 1, 1, 21, 39, 107, 0, 0, 0, 0, 0, 0]),
+    (7, 55, [1, 3, 1, 11, 29, 7, 101, 0, 0, 0, 0, 0, 0]),
+    (7, 56, [1, 3, 3, 13, 13, 37, 81, 0, 0, 0, 0, 0, 0]),
+    (7, 59, [1, 1, 1, 5, 5, 31, 89, 0, 0, 0, 0, 0, 0]),
+    (7, 62, [1, 3, 7, 9, 17, 23, 115, 0, 0, 0, 0, 0, 0]),
+    (8, 14, [1, 1, 3, 13, 17, 25, 107, 53, 0, 0, 0, 0, 0]),
+    (8, 21, [1, 3, 5, 7, 31, 3, 91, 179, 0, 0, 0, 0, 0]),
+    (8, 22, [1, 3, 3, 3, 9, 15, 37, 251, 0, 0, 0, 0, 0]),
+    (8, 38, [1, 3, 5, 9, 9, 51, 5, 177, 0, 0, 0, 0, 0]),
+    (8, 47, [1, 1, 1, 1, 27, 49, 31, 45, 0, 0, 0, 0, 0]),
+    (8, 49, [1, 3, 5, 15, 25, 21, 85, 109, 0, 0, 0, 0, 0]),
+    (8, 50, [1, 3, 3, 13, 23, 49, 57, 177, 0, 0, 0, 0, 0]),
+    (8, 52, [1, 3, 3, 13, 27, 21, 37, 125, 0, 0, 0, 0, 0]),
+    (8, 56, [1, 1, 7, 5, 31, 35, 93, 71, 0, 0, 0, 0, 0]),
+    (8, 67, [1, 3, 1, 7, 25, 15, 61, 3, 0, 0, 0, 0, 0]),
+    (8, 70, [1, 3, 5, 3, 31, 63, 85, 213, 0, 0, 0, 0, 0]),
+    (8, 84, [1, 1, 5, 13, 3, 61, 5, 47, 0, 0, 0, 0, 0]),
+    (8, 97, [1, 3, 5, 7, 15, 33, 111, 113, 0, 0, 0, 0, 0]),
+    (8, 103, [1, 1, 5, 13, 1, 37, 17, 183, 0, 0, 0, 0, 0]),
+    (8, 115, [1, 3, 5, 5, 23, 19, 55, 237, 0, 0, 0, 0, 0]),
+    (8, 122, [1, 3, 3, 13, 29, 45, 109, 161, 0, 0, 0, 0, 0]),
+    (9, 8, [1, 1, 3, 5, 25, 9, 37, 219, 193, 0, 0, 0, 0]),
+    (9, 13, [1, 3, 7, 11, 9, 9, 83, 3, 185, 0, 0, 0, 0]),
+    (9, 16, [1, 1, 7, 13, 21, 1, 39, 37, 413, 0, 0, 0, 0]),
+    (9, 22, [1, 1, 3, 1, 7, 27, 19, 179, 167, 0, 0, 0, 0]),
+    (9, 25, [1, 1, 1, 9, 5, 15, 87, 167, 473, 0, 0, 0, 0]),
+    (9, 44, [1, 1, 5, 15, 15, 33, 99, 27, 267, 0, 0, 0, 0]),
+    (9, 47, [1, 3, 7, 13, 21, 31, 69, 113, 299, 0, 0, 0, 0]),
+    (9, 52, [1, 1, 3, 1, 15, 41, 57, 219, 183, 0, 0, 0, 0]),
+    (9, 55, [1, 1, 5, 11, 7, 21, 121, 217, 419, 0, 0, 0, 0]),
+    (9, 59, [1, 1, 5, 5, 23, 7, 21, 231, 233, 0, 0, 0, 0]),
+    (9, 62, [1, 3, 7, 11, 5, 1, 125, 199, 407, 0, 0, 0, 0]),
+    (9, 67, [1, 1, 7, 3, 5, 9, 69, 137, 503, 0, 0, 0, 0]),
+    (9, 74, [1, 1, 7, 3, 3, 27, 35, 7, 373, 0, 0, 0, 0]),
+    (9, 81, [1, 3, 1, 9, 17, 31, 123, 43, 487, 0, 0, 0, 0]),
+    (9, 82, [1, 3, 5, 5, 9, 23, 49, 87, 269, 0, 0, 0, 0]),
+    (9, 87, [1, 3, 7, 1, 25, 61, 59, 143, 339, 0, 0, 0, 0]),
+    (9, 91, [1, 3, 7, 5, 1, 41, 95, 253, 301, 0, 0, 0, 0]),
+    (9, 94, [1, 1, 1, 7, 7, 49, 1, 43, 197, 0, 0, 0, 0]),
+    (9, 103, [1, 3, 5, 15, 25, 41, 35, 183, 387, 0, 0, 0, 0]),
+    (9, 104, [1, 1, 7, 15, 23, 19, 19, 33, 381, 0, 0, 0, 0]),
+    (9, 109, [1, 3, 7, 3, 11, 15, 125, 33, 25, 0, 0, 0, 0]),
+    (9, 122, [1, 3, 3, 5, 27, 47, 31, 255, 55, 0, 0, 0, 0]),
+    (9, 124, [1, 3, 1, 9, 19, 43, 71, 161, 501, 0, 0, 0, 0]),
+    (9, 137, [1, 3, 7, 1, 17, 29, 9, 11, 489, 0, 0, 0, 0]),
+    (9, 138, [1, 3, 3, 9, 1, 25, 79, 69, 31, 0, 0, 0, 0]),
+    (9, 143, [1, 3, 1, 9, 23, 9, 15, 91, 219, 0, 0, 0, 0]),
+    (9, 145, [1, 1, 3, 7, 23, 15, 107, 125, 339, 0, 0, 0, 0]),
+    (9, 152, [1, 3, 5, 13, 31, 19, 39, 59, 295, 0, 0, 0, 0]),
+    (9, 157, [1, 1, 5, 7, 5, 5, 93, 201, 323, 0, 0, 0, 0]),
+    (9, 167, [1, 1, 1, 9, 9, 11, 39, 91, 285, 0, 0, 0, 0]),
+    (9, 173, [1, 3, 5, 11, 1, 53, 73, 181, 139, 0, 0, 0, 0]),
+    (9, 176, [1, 1, 7, 5, 29, 23, 21, 187, 179, 0, 0, 0, 0]),
+    (9, 181, [1, 1, 3, 3, 17, 5, 35, 203, 417, 0, 0, 0, 0]),
+    (9, 182, [1, 3, 1, 3, 31, 21, 59, 195, 9, 0, 0, 0, 0]),
+    (9, 185, [1, 1, 1, 9, 11, 43, 15, 35, 505, 0, 0, 0, 0]),
+    (9, 191, [1, 1, 7, 5, 5, 45, 73, 63, 47, 0, 0, 0, 0]),
+    (9, 194, [1, 1, 7, 11, 29, 13, 15, 209, 27, 0, 0, 0, 0]),
+    (9, 199, [1, 1, 5, 9, 11, 33, 107, 43, 341, 0, 0, 0, 0]),
+    (9, 218, [1, 3, 1, 1, 1, 1, 51, 127, 385, 0, 0, 0, 0]),
+    (9, 220, [1, 3, 3, 9, 31, 43, 121, 49, 89, 0, 0, 0, 0]),
+    (9, 227, [1, 3, 3, 5, 23, 33, 63, 211, 73, 0, 0, 0, 0]),
+    (9, 229, [1, 3, 5, 7, 9, 35, 37, 207, 267, 0, 0, 0, 0]),
+    (9, 230, [1, 1, 3, 3, 5, 63, 65, 203, 451, 0, 0, 0, 0]),
+    (9, 234, [1, 3, 1, 1, 9, 13, 115, 15, 87, 0, 0, 0, 0]),
+    (9, 236, [1, 1, 3, 15, 1, 57, 97, 13, 163, 0, 0, 0, 0]),
+    (9, 241, [1, 3, 1, 7, 15, 17, 45, 91, 313, 0, 0, 0, 0]),
+    (9, 244, [1, 3, 5, 9, 25, 21, 57, 79, 49, 0, 0, 0, 0]),
+    (9, 253, [1, 3, 7, 15, 9, 15, 13, 55, 303, 0, 0, 0, 0]),
+    (10, 4, [1, 3, 5, 7, 25, 21, 115, 145, 213, 403, 0, 0, 0]),
+    (10, 13, [1, 1, 3, 9, 23, 21, 91, 5, 251, 821, 0, 0, 0]),
+    (10, 19, [1, 1, 1, 15, 9, 15, 23, 51, 287, 799, 0, 0, 0]),
+    (10, 22, [1, 3, 1, 3, 11, 53, 55, 59, 497, 187, 0, 0, 0]),
+    (10, 50, [1, 1, 7, 1, 3, 7, 15, 159, 457, 171, 0, 0, 0]),
+    (10, 55, [1, 3, 1, 15, 7, 7, 43, 57, 309, 91, 0, 0, 0]),
+    (10, 64, [1, 3, 5, 11, 27, 11, 87, 61, 113, 545, 0, 0, 0]),
+    (10, 69, [1, 1, 3, 5, 25, 57, 35, 147, 157, 5, 0, 0, 0]),
+    (10, 98, [1, 3, 1, 13, 5, 63, 99, 35, 183, 303, 0, 0, 0]),
+    (10, 107, [1, 1, 1, 9, 23, 13, 63, 97, 479, 359, 0, 0, 0]),
+    (10, 115, [1, 1, 3, 3, 5, 33, 5, 71, 293, 315, 0, 0, 0]),
+    (10, 121, [1, 3, 5, 7, 19, 49, 51, 253, 433, 991, 0, 0, 0]),
+    (10, 127, [1, 3, 7, 5, 7, 7, 109, 97, 493, 385, 0, 0, 0]),
+    (10, 134, [1, 3, 3, 5, 7, 9, 119, 169, 135, 615, 0, 0, 0]),
+    (10, 140, [1, 1, 7, 3, 31, 25, 77, 227, 33, 997, 0, 0, 0]),
+    (10, 145, [1, 1, 3, 9, 13, 51, 107, 139, 251, 315, 0, 0, 0]),
+    (10, 152, [1, 1, 5, 5, 21, 33, 99, 63, 335, 335, 0, 0, 0]),
+    (10, 158, [1, 3, 5, 5, 17, 51, 3, 115, 295, 1017, 0, 0, 0]),
+    (10, 161, [1, 3, 3, 1, 17, 19, 105, 107, 399, 581, 0, 0, 0]),
+    (10, 171, [1, 3, 5, 5, 13, 41, 121, 95, 399, 709, 0, 0, 0]),
+    (10, 181, [1, 1, 5, 11, 17, 23, 71, 93, 349, 489, 0, 0, 0]),
+    (10, 194, [1, 1, 5, 11, 11, 29, 109, 139, 127, 509, 0, 0, 0]),
+    (10, 199, [1, 3, 1, 11, 1, 55, 81, 11, 257, 861, 0, 0, 0]),
+    (10, 203, [1, 1, 7, 1, 5, 5, 117, 19, 489, 151, 0, 0, 0]),
+    (10, 208, [1, 1, 5, 11, 5, 5, 1, 49, 343, 679, 0, 0, 0]),
+    (10, 227, [1, 3, 1, 11, 15, 3, 113, 239, 443, 501, 0, 0, 0]),
+    (10, 242, [1, 1, 7, 3, 3, 51, 17, 115, 65, 477, 0, 0, 0]),
+    (10, 251, [1, 3, 7, 13, 29, 7, 75, 197, 41, 49, 0, 0, 0]),
+    (10, 253, [1, 1, 7, 11, 17, 5, 93, 61, 59, 211, 0, 0, 0]),
+    (10, 265, [1, 1, 1, 15, 13, 55, 49, 129, 371, 627, 0, 0, 0]),
+    (10, 266, [1, 3, 1, 13, 9, 3, 63, 111, 471, 917, 0, 0, 0]),
+    (10, 274, [1, 3, 5, 1, 25, 3, 5, 85, 69, 35, 0, 0, 0]),
+    (10, 283, [1, 3, 5, 7, 7, 31, 39, 245, 223, 505, 0, 0, 0]),
+    (10, 289, [1, 1, 3, 7, 31, 45, 87, 49, 113, 833, 0, 0, 0]),
+    (10, 295, [1, 1, 3, 9, 25, 49, 71, 99, 193, 889, 0, 0, 0]),
+    (10, 301, [1, 1, 1, 13, 21, 31, 19, 195, 427, 221, 0, 0, 0]),
+    (10, 316, [1, 1, 3, 5, 11, 53, 3, 233, 289, 705, 0, 0, 0]),
+    (10, 319, [1, 1, 3, 7, 19, 27, 87, 113, 99, 531, 0, 0, 0]),
+    (10, 324, [1, 1, 5, 13, 17, 37, 23, 63, 481, 595, 0, 0, 0]),
+    (10, 346, [1, 3, 3, 3, 11, 57, 127, 151, 175, 847, 0, 0, 0]),
+    (10, 352, [1, 1, 5, 1, 5, 13, 41, 91, 189, 811, 0, 0, 0]),
+    (10, 361, [1, 3, 5, 5, 7, 33, 109, 205, 493, 425, 0, 0, 0]),
+    (10, 367, [1, 1, 5, 13, 25, 23, 109, 69, 209, 759, 0, 0, 0]),
+    (10, 382, [1, 3, 3, 13, 15, 59, 45, 145, 39, 11, 0, 0, 0]),
+    (10, 395, [1, 3, 3, 7, 13, 17, 49, 11, 275, 585, 0, 0, 0]),
+    (10, 398, [1, 1, 5, 11, 31, 13, 9, 159, 69, 13, 0, 0, 0]),
+    (10, 400, [1, 3, 5, 1, 25, 63, 63, 23, 189, 451, 0, 0, 0]),
+    (10, 412, [1, 1, 3, 9, 5, 61, 85, 231, 25, 439, 0, 0, 0]),
+    (10, 419, [1, 3, 1, 11, 1, 55, 109, 167, 99, 989, 0, 0, 0]),
+    (10, 422, [1, 3, 7, 9, 15, 39, 123, 183, 291, 161, 0, 0, 0]),
+    (10, 426, [1, 1, 7, 1, 21, 3, 55, 121, 45, 361, 0, 0, 0]),
+    (10, 428, [1, 3, 7, 13, 17, 35, 65, 47, 333, 137, 0, 0, 0]),
+    (10, 433, [1, 3, 1, 15, 31, 13, 61, 157, 117, 755, 0, 0, 0]),
+    (10, 446, [1, 1, 3, 13, 9, 47, 51, 143, 185, 303, 0, 0, 0]),
+    (10, 454, [1, 1, 5, 15, 13, 1, 11, 143, 239, 823, 0, 0, 0]),
+    (10, 457, [1, 3, 1, 1, 3, 21, 109, 95, 307, 179, 0, 0, 0]),
+    (10, 472, [1, 3, 5, 11, 21, 17, 65, 99, 37, 687, 0, 0, 0]),
+    (10, 493, [1, 1, 5, 13, 25, 53, 3, 127, 33, 909, 0, 0, 0]),
+    (10, 505, [1, 1, 3, 9, 7, 23, 37, 55, 79, 295, 0, 0, 0]),
+    (10, 508, [1, 3, 3, 5, 27, 43, 17, 77, 159, 605, 0, 0, 0]),
+    (11, 2, [1, 1, 7, 1, 21, 37, 61, 165, 159, 447, 1901, 0, 0]),
+    (11, 11, [1, 1, 7, 1, 25, 27, 41, 53, 477, 909, 1255, 0, 0]),
+    (11, 21, [1, 3, 3, 13, 15, 1, 111, 153, 447, 865, 1557, 0, 0]),
+    (11, 22, [1, 3, 7, 7, 17, 63, 3, 113, 205, 963, 1193, 0, 0]),
+    (11, 35, [1, 3, 3, 13, 7, 1, 123, 101, 195, 733, 249, 0, 0]),
+    (11, 49, [1, 3, 5, 15, 7, 25, 85, 47, 397, 617, 1501, 0, 0]),
+    (11, 50, [1, 1, 1, 3, 7, 11, 119, 49, 291, 217, 1153, 0, 0]),
+    (11, 56, [1, 1, 5, 15, 9, 7, 79, 123, 339, 13, 1613, 0, 0]),
+    (11, 61, [1, 3, 5, 5, 1, 31, 51, 51, 349, 29, 951, 0, 0]),
+    (11, 70, [1, 3, 3, 15, 25, 23, 39, 25, 203, 175, 725, 0, 0]),
+    (11, 74, [1, 3, 7, 3, 13, 55, 81, 163, 487, 137, 1791, 0, 0]),
+    (11, 79, [1, 3, 1, 11, 29, 43, 75, 89, 25, 741, 1237, 0, 0]),
+    (11, 84, [1, 1, 3, 5, 27, 33, 23, 161, 461, 15, 121, 0, 0]),
+    (11, 88, [1, 1, 1, 15, 23, 49, 43, 67, 499, 79, 1491, 0, 0]),
+    (11, 103, [1, 1, 7, 9, 3, 17, 31, 231, 203, 687, 1587, 0, 0]),
+    (11, 104, [1, 1, 1, 1, 31, 41, 121, 65, 271, 455, 955, 0, 0]),
+    (11, 112, [1, 1, 1, 15, 29, 51, 35, 157, 331, 645, 247, 0, 0]),
+    (11, 115, [1, 3, 1, 1, 15, 17, 27, 219, 401, 227, 1981, 0, 0]),
+    (11, 117, [1, 3, 7, 9, 17, 51, 95, 205, 39, 865, 1789, 0, 0]),
+    (11, 122, [1, 3, 3, 11, 25, 55, 27, 215, 407, 113, 1013, 0, 0]),
+    (11, 134, [1, 1, 1, 3, 19, 23, 9, 209, 153, 779, 1819, 0, 0]),
+    (11, 137, [1, 1, 7, 3, 7, 21, 91, 175, 43, 551, 1365, 0, 0]),
+    (11, 146, [1, 1, 5, 7, 21, 37, 55, 187, 441, 953, 721, 0, 0]),
+    (11, 148, [1, 1, 7, 5, 27, 43, 65, 113, 87, 711, 1483, 0, 0]),
+    (11, 157, [1, 3, 5, 1, 15, 61, 85, 119, 453, 969, 1885, 0, 0]),
+    (11, 158, [1, 3, 7, 15, 7, 47, 75, 115, 147, 1023, 1619, 0, 0]),
+    (11, 162, [1, 1, 3, 15, 19, 1, 117, 191, 29, 209, 1847, 0, 0]),
+    (11, 164, [1, 3, 5, 3, 23, 41, 49, 95, 495, 87, 113, 0, 0]),
+    (11, 168, [1, 1, 7, 11, 7, 45, 79, 23, 431, 571, 203, 0, 0]),
+    (11, 173, [1, 3, 3, 11, 17, 1, 45, 89, 139, 443, 1735, 0, 0]),
+    (11, 185, [1, 3, 1, 1, 21, 33, 57, 143, 435, 309, 317, 0, 0]),
+    (11, 186, [1, 3, 1, 3, 17, 63, 123, 223, 181, 647, 165, 0, 0]),
+    (11, 191, [1, 1, 3, 11, 5, 1, 37, 65, 239, 257, 657, 0, 0]),
+    (11, 193, [1, 1, 1, 1, 13, 15, 45, 43, 177, 107, 331, 0, 0]),
+    (11, 199, [1, 1, 5, 9, 21, 53, 37, 113, 105, 131, 1595, 0, 0]),
+    (11, 213, [1, 3, 7, 11, 19, 53, 47, 245, 193, 189, 1961, 0, 0]),
+    (11, 214, [1, 1, 5, 11, 27, 29, 119, 35, 185, 157, 1473, 0, 0]),
+    (11, 220, [1, 3, 3, 1, 3, 53, 113, 19, 367, 621, 307, 0, 0]),
+    (11, 227, [1, 1, 1, 3, 29, 51, 97, 51, 231, 807, 963, 0, 0]),
+    (11, 236, [1, 1, 5, 3, 11, 43, 67, 143, 463, 691, 129, 0, 0]),
+    (11, 242, [1, 1, 5, 15, 11, 1, 37, 137, 247, 563, 141, 0, 0]),
+    (11, 251, [1, 3, 5, 3, 7, 23, 3, 217, 263, 631, 843, 0, 0]),
+    (11, 256, [1, 3, 7, 9, 5, 39, 109, 35, 399, 405, 245, 0, 0]),
+    (11, 259, [1, 3, 5, 15, 21, 59, 103, 245, 41, 861, 1239, 0, 0]),
+    (11, 265, [1, 1, 7, 7, 5, 29, 23, 139, 109, 825, 1163, 0, 0]),
+    (11, 266, [1, 3, 1, 15, 11, 53, 111, 209, 409, 247, 1275, 0, 0]),
+    (11, 276, [1, 1, 1, 5, 15, 41, 29, 73, 497, 63, 1605, 0, 0]),
+    (11, 292, [1, 3, 7, 7, 23, 1, 65, 141, 171, 891, 1613, 0, 0]),
+    (11, 304, [1, 3, 1, 15, 23, 37, 125, 59, 53, 387, 955, 0, 0]),
+    (11, 310, [1, 3, 7, 1, 15, 1, 61, 243, 183, 945, 131, 0, 0]),
+    (11, 316, [1, 3, 5, 11, 31, 27, 77, 211, 295, 561, 377, 0, 0]),
+    (11, 319, [1, 3, 5, 7, 15, 57, 11, 227, 337, 487, 1861, 0, 0]),
+    (11, 322, [1, 3, 3, 3, 29, 49, 81, 133, 223, 543, 143, 0, 0]),
+    (11, 328, [1, 1, 3, 9, 29, 23, 61, 99, 71, 705, 733, 0, 0]),
+    (11, 334, [1, 1, 7, 3, 23, 27, 51, 193, 33, 563, 423, 0, 0]),
+    (11, 339, [1, 3, 3, 3, 7, 23, 41, 53, 499, 1, 1393, 0, 0]),
+    (11, 341, [1, 3, 1, 15, 7, 19, 37, 177, 41, 775, 1455, 0, 0]),
+    (11, 345, [1, 1, 3, 9, 1, 61, 11, 93, 267, 881, 1335, 0, 0]),
+    (11, 346, [1, 3, 1, 15, 21, 17, 17, 155, 245, 603, 495, 0, 0]),
+    (11, 362, [1, 3, 3, 5, 15, 39, 105, 79, 265, 165, 415, 0, 0]),
+    (11, 367, [1, 1, 5, 9, 19, 39, 109, 251, 383, 935, 1527, 0, 0]),
+    (11, 372, [1, 1, 7, 3, 19, 29, 115, 177, 37, 385, 1879, 0, 0]),
+    (11, 375, [1, 1, 5, 9, 11, 17, 117, 79, 31, 695, 1653, 0, 0]),
+    (11, 376, [1, 3, 1, 1, 21, 21, 25, 225, 35, 821, 1703, 0, 0]),
+    (11, 381, [1, 1, 7, 5, 27, 39, 59, 7, 225, 777, 1985, 0, 0]),
+    (11, 385, [1, 1, 1, 5, 1, 9, 7, 241, 31, 119, 1961, 0, 0]),
+    (11, 388, [1, 1, 3, 1, 1, 1, 105, 111, 45, 765, 1275, 0, 0]),
+    (11, 392, [1, 1, 3, 15, 7, 11, 73, 183, 499, 519, 897, 0, 0]),
+    (11, 409, [1, 3, 3, 1, 29, 9, 113, 89, 439, 147, 391, 0, 0]),
+    (11, 415, [1, 3, 1, 1, 21, 57, 121, 225, 201, 67, 487, 0, 0]),
+    (11, 416, [1, 1, 5, 15, 23, 15, 99, 249, 163, 239, 233, 0, 0]),
+    (11, 421, [1, 1, 3, 15, 13, 63, 61, 121, 75, 771, 1435, 0, 0]),
+    (11, 428, [1, 3, 5, 13, 11, 17, 75, 115, 139, 41, 1617, 0, 0]),
+    (11, 431, [1, 3, 5, 9, 25, 25, 41, 235, 327, 297, 1809, 0, 0]),
+    (11, 434, [1, 3, 1, 11, 7, 37, 79, 221, 313, 1007, 531, 0, 0]),
+    (11, 439, [1, 1, 3, 11, 19, 23, 15, 131, 251, 477, 775, 0, 0]),
+    (11, 446, [1, 1, 7, 5, 23, 11, 33, 145, 31, 581, 1773, 0, 0]),
+    (11, 451, [1, 1, 3, 3, 19, 13, 109, 225, 209, 799, 651, 0, 0]),
+    (11, 453, [1, 1, 1, 13, 29, 39, 109, 37, 281, 761, 137, 0, 0]),
+    (11, 457, [1, 1, 5, 9, 29, 41, 121, 217, 145, 55, 1017, 0, 0]),
+    (11, 458, [1, 1, 5, 1, 7, 21, 3, 21, 373, 217, 1527, 0, 0]),
+    (11, 471, [1, 1, 7, 9, 3, 31, 63, 219, 449, 495, 1879, 0, 0]),
+    (11, 475, [1, 3, 7, 3, 27, 51, 67, 109, 479, 341, 1373, 0, 0]),
+    (11, 478, [1, 3, 1, 13, 29, 13, 43, 217, 253, 541, 1021, 0, 0]),
+    (11, 484, [1, 3, 7, 3, 29, 33, 3, 235, 451, 765, 1241, 0, 0]),
+    (11, 493, [1, 1, 5, 1, 3, 51, 85, 107, 145, 903, 1645, 0, 0]),
+    (11, 494, [1, 1, 1, 9, 17, 59, 107, 29, 77, 951, 1919, 0, 0]),
+    (11, 499, [1, 3, 1, 1, 13, 27, 113, 187, 235, 103, 1397, 0, 0]),
+    (11, 502, [1, 1, 3, 3, 13, 9, 15, 141, 265, 231, 1541, 0, 0]),
+    (11, 517, [1, 1, 3, 5, 17, 53, 9, 21, 449, 31, 1451, 0, 0]),
+    (11, 518, [1, 1, 5, 1, 7, 27, 39, 229, 509, 887, 67, 0, 0]),
+    (11, 524, [1, 3, 3, 5, 25, 25, 47, 215, 365, 485, 1681, 0, 0]),
+    (11, 527, [1, 1, 1, 11, 27, 13, 87, 229, 289, 557, 1277, 0, 0]),
+    (11, 555, [1, 3, 7, 9, 3, 35, 85, 31, 15, 55, 371, 0, 0]),
+    (11, 560, [1, 1, 7, 5, 19, 31, 29, 251, 143, 807, 1581, 0, 0]),
+    (11, 565, [1, 3, 5, 13, 9, 23, 127, 81, 335, 137, 967, 0, 0]),
+    (11, 569, [1, 3, 5, 11, 9, 63, 123, 207, 491, 137, 1195, 0, 0]),
+    (11, 578, [1, 1, 3, 1, 23, 3, 109, 113, 173, 545, 355, 0, 0]),
+    (11, 580, [1, 1, 7, 11, 15, 25, 29, 183, 143, 637, 1039, 0, 0]),
+    (11, 587, [1, 1, 1, 15, 13, 25, 103, 25, 465, 91, 1027, 0, 0]),
+    (11, 589, [1, 3, 3, 13, 9, 45, 123, 89, 93, 583, 1843, 0, 0]),
+    (11, 590, [1, 1, 1, 3, 31, 19, 79, 211, 91, 111, 973, 0, 0]),
+    (11, 601, [1, 3, 7, 5, 15, 15, 71, 63, 143, 509, 1363, 0, 0]),
+    (11, 607, [1, 3, 3, 11, 9, 27, 49, 131, 105, 233, 467, 0, 0]),
+    (11, 611, [1, 1, 7, 1, 5, 41, 123, 211, 443, 959, 1635, 0, 0]),
+    (11, 614, [1, 1, 3, 15, 21, 63, 43, 89, 59, 589, 1349, 0, 0]),
+    (11, 617, [1, 3, 3, 13, 19, 15, 5, 59, 47, 671, 631, 0, 0]),
+    (11, 618, [1, 1, 3, 7, 3, 37, 23, 87, 265, 271, 251, 0, 0]),
+    (11, 625, [1, 3, 7, 1, 5, 57, 29, 21, 153, 273, 997, 0, 0]),
+    (11, 628, [1, 1, 3, 15, 25, 13, 111, 151, 359, 225, 1767, 0, 0]),
+    (11, 635, [1, 1, 5, 13, 5, 9, 17, 245, 489, 511, 661, 0, 0]),
+    (11, 641, [1, 3, 1, 3, 25, 55, 81, 167, 243, 411, 255, 0, 0]),
+    (11, 647, [1, 1, 1, 13, 7, 51, 59, 87, 257, 643, 283, 0, 0]),
+    (11, 654, [1, 3, 1, 3, 3, 33, 37, 15, 327, 793, 1573, 0, 0]),
+    (11, 659, [1, 3, 1, 13, 7, 37, 59, 185, 9, 781, 1499, 0, 0]),
+    (11, 662, [1, 1, 5, 13, 17, 19, 13, 67, 215, 303, 1653, 0, 0]),
+    (11, 672, [1, 3, 7, 11, 1, 55, 27, 147, 45, 515, 1289, 0, 0]),
+    (11, 675, [1, 3, 1, 5, 27, 21, 3, 115, 461, 813, 2011, 0, 0]),
+    (11, 682, [1, 3, 5, 9, 7, 25, 17, 73, 99, 53, 1549, 0, 0]),
+    (11, 684, [1, 3, 7, 11, 21, 15, 73, 235, 387, 449, 249, 0, 0]),
+    (11, 689, [1, 1, 7, 7, 19, 33, 57, 193, 101, 455, 1023, 0, 0]),
+    (11, 695, [1, 1, 7, 15, 31, 7, 123, 213, 471, 459, 1919, 0, 0]),
+    (11, 696, [1, 3, 7, 13, 23, 21, 85, 247, 245, 517, 1167, 0, 0]),
+    (11, 713, [1, 1, 5, 1, 17, 23, 41, 101, 409, 679, 599, 0, 0]),
+    (11, 719, [1, 3, 3, 11, 5, 29, 29, 199, 149, 903, 1911, 0, 0]),
+    (11, 724, [1, 1, 5, 1, 11, 55, 39, 109, 127, 807, 1571, 0, 0]),
+    (11, 733, [1, 3, 7, 1, 13, 55, 65, 105, 453, 441, 1467, 0, 0]),
+    (11, 734, [1, 3, 3, 13, 19, 31, 39, 153, 361, 473, 1743, 0, 0]),
+    (11, 740, [1, 3, 7, 9, 27, 53, 53, 97, 413, 801, 913, 0, 0]),
+    (11, 747, [1, 1, 3, 15, 7, 17, 1, 61, 259, 377, 285, 0, 0]),
+    (11, 749, [1, 1, 5, 1, 31, 23, 103, 93, 99, 713, 877, 0, 0]),
+    (11, 752, [1, 3, 3, 11, 11, 19, 117, 61, 375, 1021, 515, 0, 0]),
+    (11, 755, [1, 1, 1, 1, 1, 21, 121, 131, 79, 779, 1667, 0, 0]),
+    (11, 762, [1, 3, 1, 9, 1, 33, 17, 189, 153, 605, 1413, 0, 0]),
+    (11, 770, [1, 3, 1, 3, 5, 13, 111, 243, 497, 311, 367, 0, 0]),
+    (11, 782, [1, 1, 5, 13, 7, 5, 39, 217, 59, 69, 1745, 0, 0]),
+    (11, 784, [1, 1, 1, 15, 21, 17, 105, 211, 425, 235, 1641, 0, 0]),
+    (11, 787, [1, 3, 5, 3, 25, 55, 63, 41, 419, 169, 1701, 0, 0]),
+    (11, 789, [1, 3, 3, 5, 21, 1, 41, 109, 41, 845, 461, 0, 0]),
+    (11, 793, [1, 3, 1, 3, 7, 23, 79, 1, 355, 437, 765, 0, 0]),
+    (11, 796, [1, 1, 1, 1, 11, 35, 85, 105, 63, 961, 929, 0, 0]),
+    (11, 803, [1, 3, 7, 9, 15, 33, 13, 49, 203, 17, 855, 0, 0]),
+    (11, 805, [1, 1, 3, 13, 25, 3, 109, 81, 217, 9, 1203, 0, 0]),
+    (11, 810, [1, 3, 5, 15, 5, 25, 17, 11, 205, 271, 1441, 0, 0]),
+    (11, 815, [1, 3, 5, 1, 17, 27, 67, 65, 311, 551, 873, 0, 0]),
+    (11, 824, [1, 3, 5, 7, 29, 19, 23, 115, 81, 545, 1165, 0, 0]),
+    (11, 829, [1, 3, 3, 13, 9, 43, 107, 35, 163, 127, 1143, 0, 0]),
+    (11, 830, [1, 3, 7, 3, 31, 63, 127, 113, 109, 427, 1477, 0, 0]),
+    (11, 832, [1, 3, 3, 3, 15, 61, 3, 203, 293, 603, 1777, 0, 0]),
+    (11, 841, [1, 3, 1, 13, 29, 9, 49, 93, 305, 781, 833, 0, 0]),
+    (11, 847, [1, 1, 1, 15, 17, 25, 59, 15, 257, 449, 431, 0, 0]),
+    (11, 849, [1, 1, 5, 5, 29, 25, 9, 33, 213, 263, 1931, 0, 0]),
+    (11, 861, [1, 1, 1, 7, 29, 57, 41, 245, 371, 875, 509, 0, 0]),
+    (11, 871, [1, 3, 3, 13, 29, 57, 71, 237, 143, 35, 1355, 0, 0]),
+    (11, 878, [1, 3, 7, 7, 7, 49, 41, 55, 167, 913, 109, 0, 0]),
+    (11, 889, [1, 1, 7, 11, 31, 49, 31, 61, 473, 729, 1793, 0, 0]),
+    (11, 892, [1, 3, 3, 5, 29, 27, 87, 49, 459, 399, 795, 0, 0]),
+    (11, 901, [1, 3, 7, 15, 25, 43, 39, 217, 329, 649, 1991, 0, 0]),
+    (11, 908, [1, 3, 3, 7, 19, 25, 123, 175, 149, 71, 495, 0, 0]),
+    (11, 920, [1, 3, 1, 15, 1, 55, 19, 247, 203, 805, 1907, 0, 0]),
+    (11, 923, [1, 3, 7, 9, 21, 17, 43, 115, 139, 479, 1121, 0, 0]),
+    (11, 942, [1, 1, 7, 13, 7, 1, 71, 177, 447, 73, 281, 0, 0]),
+    (11, 949, [1, 3, 5, 9, 27, 25, 81, 145, 387, 867, 517, 0, 0]),
+    (11, 950, [1, 1, 3, 11, 3, 3, 41, 127, 335, 127, 1391, 0, 0]),
+    (11, 954, [1, 1, 7, 3, 19, 35, 3, 67, 429, 691, 1583, 0, 0]),
+    (11, 961, [1, 3, 7, 1, 25, 37, 23, 85, 229, 495, 945, 0, 0]),
+    (11, 968, [1, 1, 3, 13, 7, 63, 127, 103, 61, 163, 189, 0, 0]),
+    (11, 971, [1, 3, 7, 11, 27, 43, 101, 29, 153, 947, 1373, 0, 0]),
+    (11, 973, [1, 3, 5, 3, 9, 3, 105, 63, 331, 789, 1251, 0, 0]),
+    (11, 979, [1, 3, 3, 15, 9, 45, 95, 217, 329, 785, 659, 0, 0]),
+    (11, 982, [1, 1, 5, 15, 17, 17, 93, 95, 325, 939, 997, 0, 0]),
+    (11, 986, [1, 3, 5, 7, 7, 57, 67, 25, 35, 233, 1689, 0, 0]),
+    (11, 998, [1, 1, 1, 7, 9, 61, 85, 133, 395, 677, 1853, 0, 0]),
+    (11, 1001, [1, 1, 7, 13, 5, 47, 13, 149, 267, 297, 3, 0, 0]),
+    (11, 1010, [1, 3, 7, 11, 15, 33, 5, 25, 373, 707, 583, 0, 0]),
+    (11, 1012, [1, 1, 1, 7, 31, 5, 95, 215, 17, 185, 1207, 0, 0]),
+    (12, 41, [1, 1, 5, 3, 19, 29, 39, 207, 389, 697, 725, 75, 0]),
+    (12, 52, [1, 3, 3, 1, 31, 25, 31, 7, 111, 851, 1613, 407, 0]),
+    (12, 61, [1, 3, 1, 3, 7, 13, 27, 155, 465, 989, 961, 2025, 0]),
+    (12, 62, [1, 1, 7, 9, 25, 53, 77, 89, 173, 447, 291, 3683, 0]),
+    (12, 76, [1, 3, 3, 15, 25, 47, 95, 239, 159, 45, 331, 87, 0]),
+    (12, 104, [1, 1, 3, 1, 3, 19, 101, 147, 233, 929, 929, 1327, 0]),
+    (12, 117, [1, 1, 1, 11, 3, 17, 21, 229, 143, 53, 953, 3189, 0]),
+    (12, 131, [1, 3, 7, 13, 27, 17, 35, 213, 85, 1023, 413, 3677, 0]),
+    (12, 143, [1, 3, 1, 5, 25, 53, 89, 127, 299, 743, 1537, 2465, 0]),
+    (12, 145, [1, 1, 7, 7, 25, 37, 47, 49, 349, 373, 1571, 1127, 0]),
+    (12, 157, [1, 1, 7, 5, 15, 31, 79, 19, 67, 513, 455, 2659, 0]),
+    (12, 167, [1, 3, 5, 1, 13, 1, 115, 25, 69, 695, 743, 635, 0]),
+    (12, 171, [1, 3, 1, 9, 27, 23, 31, 103, 101, 347, 297, 441, 0]),
+    (12, 176, [1, 3, 5, 7, 29, 25, 101, 19, 19, 601, 1881, 2341, 0]),
+    (12, 181, [1, 1, 1, 5, 23, 35, 45, 179, 461, 485, 285, 39, 0]),
+    (12, 194, [1, 1, 5, 1, 27, 53, 27, 155, 261, 359, 1607, 1151, 0]),
+    (12, 217, [1, 1, 1, 15, 15, 47, 123, 61, 465, 305, 375, 787, 0]),
+    (12, 236, [1, 1, 5, 13, 25, 37, 3, 245, 397, 729, 1317, 2271, 0]),
+    (12, 239, [1, 3, 1, 3, 3, 11, 95, 231, 65, 121, 419, 367, 0]),
+    (12, 262, [1, 3, 7, 9, 23, 43, 61, 55, 305, 115, 119, 2005, 0]),
+    (12, 283, [1, 3, 5, 9, 21, 53, 81, 175, 57, 359, 1705, 2581, 0]),
+    (12, 286, [1, 3, 7, 9, 11, 25, 1, 67, 291, 875, 135, 3533, 0]),
+    (12, 307, [1, 1, 7, 5, 17, 19, 75, 179, 95, 931, 613, 1039, 0]),
+    (12, 313, [1, 1, 3, 13, 17, 21, 93, 93, 59, 247, 1737, 1503, 0]),
+    (12, 319, [1, 1, 5, 13, 3, 21, 111, 229, 511, 861, 423, 4061, 0]),
+    (12, 348, [1, 1, 7, 15, 15, 63, 59, 223, 61, 273, 591, 2095, 0]),
+    (12, 352, [1, 1, 3, 1, 9, 1, 31, 89, 301, 181, 1737, 1435, 0]),
+    (12, 357, [1, 3, 7, 11, 7, 51, 119, 97, 35, 373, 1759, 237, 0]),
+    (12, 391, [1, 1, 7, 7, 25, 33, 71, 123, 321, 97, 37, 3749, 0]),
+    (12, 398, [1, 1, 5, 1, 5, 27, 107, 221, 121, 235, 1585, 2759, 0]),
+    (12, 400, [1, 1, 1, 13, 3, 39, 75, 151, 495, 585, 1461, 2665, 0]),
+    (12, 412, [1, 1, 7, 9, 17, 39, 99, 103, 231, 71, 995, 1337, 0]),
+    (12, 415, [1, 1, 3, 1, 25, 61, 63, 31, 349, 677, 1117, 1767, 0]),
+    (12, 422, [1, 3, 1, 13, 5, 33, 99, 51, 47, 763, 707, 2777, 0]),
+    (12, 440, [1, 3, 1, 11, 17, 1, 91, 43, 143, 871, 471, 1049, 0]),
+    (12, 460, [1, 1, 5, 3, 13, 29, 107, 183, 371, 683, 921, 951, 0]),
+    (12, 465, [1, 1, 1, 7, 11, 31, 81, 119, 283, 199, 267, 3167, 0]),
+    (12, 468, [1, 1, 5, 15, 29, 33, 43, 109, 151, 967, 1617, 1371, 0]),
+    (12, 515, [1, 1, 1, 13, 23, 21, 87, 133, 99, 65, 71, 3199, 0]),
+    (12, 536, [1, 3, 5, 15, 21, 35, 7, 225, 197, 67, 1253, 105, 0]),
+    (12, 539, [1, 1, 1, 13, 15, 45, 109, 237, 451, 47, 747, 2425, 0]),
+    (12, 551, [1, 3, 5, 3, 27, 43, 13, 127, 113, 205, 781, 1207, 0]),
+    (12, 558, [1, 1, 1, 1, 9, 55, 87, 63, 243, 307, 221, 4081, 0]),
+    (12, 563, [1, 1, 1, 1, 25, 5, 127, 205, 287, 753, 485, 1865, 0]),
+    (12, 570, [1, 3, 5, 11, 9, 7, 35, 53, 335, 317, 283, 3441, 0]),
+    (12, 595, [1, 1, 3, 7, 1, 17, 47, 29, 439, 961, 705, 3231, 0]),
+    (12, 598, [1, 3, 5, 11, 25, 19, 83, 131, 451, 201, 587, 1015, 0]),
+    (12, 617, [1, 1, 5, 7, 25, 29, 1, 57, 7, 527, 1447, 1987, 0]),
+    (12, 647, [1, 1, 7, 15, 9, 21, 51, 223, 463, 917, 869, 2119, 0]),
+    (12, 654, [1, 3, 1, 11, 7, 39, 101, 149, 275, 185, 789, 1397, 0]),
+    (12, 678, [1, 3, 5, 3, 17, 25, 51, 63, 181, 355, 1497, 3861, 0]),
+    (12, 713, [1, 3, 5, 11, 7, 9, 67, 205, 13, 747, 275, 931, 0]),
+    (12, 738, [1, 1, 5, 15, 19, 19, 37, 27, 323, 613, 17, 2531, 0]),
+    (12, 747, [1, 1, 7, 7, 13, 39, 113, 69, 259, 379, 1583, 251, 0]),
+    (12, 750, [1, 1, 1, 13, 13, 5, 73, 91, 327, 677, 1959, 3599, 0]),
+    (12, 757, [1, 3, 7, 3, 11, 23, 23, 119, 269, 789, 1493, 3133, 0]),
+    (12, 772, [1, 1, 7, 7, 31, 57, 75, 255, 279, 713, 1283, 707, 0]),
+    (12, 803, [1, 1, 7, 11, 15, 47, 39, 199, 353, 845, 1407, 2253, 0]),
+    (12, 810, [1, 1, 3, 11, 19, 29, 85, 207, 3, 401, 1447, 1253, 0]),
+    (12, 812, [1, 1, 1, 3, 13, 33, 87, 77, 485, 219, 841, 647, 0]),
+    (12, 850, [1, 1, 3, 1, 11, 21, 107, 95, 343, 21, 895, 223, 0]),
+    (12, 862, [1, 3, 5, 15, 13, 55, 117, 95, 103, 627, 1873, 1173, 0]),
+    (12, 906, [1, 3, 1, 7, 13, 19, 91, 201, 153, 705, 2009, 1431, 0]),
+    (12, 908, [1, 3, 7, 13, 15, 5, 79, 19, 391, 263, 1477, 1065, 0]),
+    (12, 929, [1, 3, 5, 9, 19, 27, 51, 127, 169, 235, 1683, 751, 0]),
+    (12, 930, [1, 1, 1, 11, 7, 41, 25, 17, 177, 177, 741, 627, 0]),
+    (12, 954, [1, 1, 1, 13, 29, 29, 1, 135, 499, 69, 1019, 2055, 0]),
+    (12, 964, [1, 1, 3, 1, 31, 33, 101, 131, 313, 425, 731, 3603, 0]),
+    (12, 982, [1, 1, 3, 3, 3, 29, 37, 237, 111, 647, 937, 1853, 0]),
+    (12, 985, [1, 1, 5, 11, 25, 21, 27, 99, 227, 637, 1277, 3897, 0]),
+    (12, 991, [1, 3, 7, 13, 21, 35, 39, 35, 89, 571, 1089, 3853, 0]),
+    (12, 992, [1, 1, 3, 3, 29, 29, 107, 5, 293, 49, 1959, 613, 0]),
+    (12, 1067, [1, 3, 1, 11, 1, 63, 117, 199, 413, 593, 1763, 155, 0]),
+    (12, 1070, [1, 3, 3, 13, 31, 47, 107, 115, 29, 873, 327, 1421, 0]),
+    (12, 1096, [1, 3, 5, 1, 23, 31, 5, 215, 355, 459, 491, 723, 0]),
+    (12, 1099, [1, 3, 3, 9, 29, 5, 85, 187, 85, 689, 1605, 231, 0]),
+    (12, 1116, [1, 3, 1, 5, 5, 61, 25, 93, 135, 349, 1685, 1685, 0]),
+    (12, 1143, [1, 1, 3, 9, 5, 9, 83, 111, 419, 793, 1815, 435, 0]),
+    (12, 1165, [1, 3, 1, 5, 23, 45, 55, 125, 487, 167, 999, 3357, 0]),
+    (12, 1178, [1, 1, 5, 1, 9, 21, 93, 171, 437, 801, 1175, 449, 0]),
+    (12, 1184, [1, 1, 1, 3, 13, 39, 47, 119, 351, 149, 111, 3599, 0]),
+    (12, 1202, [1, 1, 7, 15, 21, 1, 95, 5, 457, 563, 1857, 115, 0]),
+    (12, 1213, [1, 1, 1, 15, 5, 57, 49, 199, 153, 461, 2013, 3147, 0]),
+    (12, 1221, [1, 1, 5, 3, 13, 17, 31, 101, 163, 143, 999, 421, 0]),
+    (12, 1240, [1, 3, 5, 1, 19, 7, 75, 203, 353, 593, 237, 3711, 0]),
+    (12, 1246, [1, 3, 3, 13, 17, 17, 11, 201, 495, 283, 1305, 3585, 0]),
+    (12, 1252, [1, 1, 3, 7, 7, 27, 67, 233, 443, 233, 323, 641, 0]),
+    (12, 1255, [1, 3, 3, 5, 15, 9, 29, 151, 117, 575, 1331, 2945, 0]),
+    (12, 1267, [1, 3, 5, 13, 11, 17, 55, 87, 263, 779, 265, 2917, 0]),
+    (12, 1293, [1, 1, 7, 9, 1, 21, 69, 75, 115, 695, 23, 2299, 0]),
+    (12, 1301, [1, 1, 7, 7, 27, 59, 75, 137, 177, 753, 1063, 1813, 0]),
+    (12, 1305, [1, 1, 1, 1, 23, 11, 7, 7, 351, 853, 329, 2701, 0]),
+    (12, 1332, [1, 1, 3, 3, 7, 33, 125, 187, 293, 977, 1287, 1819, 0]),
+    (12, 1349, [1, 1, 3, 5, 23, 51, 51, 217, 19, 365, 1673, 2339, 0]),
+    (12, 1384, [1, 3, 7, 11, 13, 9, 101, 159, 445, 875, 1753, 619, 0]),
+    (12, 1392, [1, 3, 3, 11, 29, 7, 83, 191, 89, 685, 217, 3379, 0]),
+    (12, 1402, [1, 3, 5, 9, 25, 7, 103, 163, 191, 531, 205, 327, 0]),
+    (12, 1413, [1, 1, 7, 5, 21, 33, 21, 239, 303, 775, 193, 181, 0]),
+    (12, 1417, [1, 1, 1, 13, 29, 39, 17, 89, 437, 901, 333, 555, 0]),
+    (12, 1423, [1, 3, 7, 1, 3, 13, 55, 219, 265, 467, 1517, 1379, 0]),
+    (12, 1451, [1, 3, 3, 5, 31, 37, 31, 107, 47, 417, 1763, 3745, 0]),
+    (12, 1480, [1, 1, 5, 3, 5, 23, 67, 29, 181, 823, 691, 3719, 0]),
+    (12, 1491, [1, 1, 3, 13, 17, 57, 67, 171, 39, 623, 905, 2085, 0]),
+    (12, 1503, [1, 1, 7, 15, 21, 51, 73, 231, 137, 697, 185, 395, 0]),
+    (12, 1504, [1, 1, 7, 9, 7, 9, 13, 147, 231, 437, 195, 451, 0]),
+    (12, 1513, [1, 3, 1, 13, 5, 63, 121, 117, 305, 635, 1017, 2071, 0]),
+    (12, 1538, [1, 1, 7, 9, 21, 21, 65, 83, 287, 805, 229, 2333, 0]),
+    (12, 1544, [1, 1, 3, 15, 23, 15, 81, 255, 469, 3, 1931, 3647, 0]),
+    (12, 1547, [1, 1, 5, 1, 11, 27, 35, 85, 169, 179, 1057, 1133, 0]),
+    (12, 1555, [1, 3, 7, 3, 9, 49, 9, 179, 63, 949, 1335, 3635, 0]),
+    (12, 1574, [1, 3, 3, 13, 13, 53, 87, 207, 81, 319, 685, 3055, 0]),
+    (12, 1603, [1, 1, 1, 15, 5, 21, 85, 95, 345, 721, 1261, 3047, 0]),
+    (12, 1615, [1, 1, 5, 11, 3, 39, 13, 123, 129, 651, 1077, 1905, 0]),
+    (12, 1618, [1, 3, 7, 9, 23, 61, 113, 73, 79, 703, 715, 3203, 0]),
+    (12, 1629, [1, 1, 1, 3, 17, 7, 77, 159, 337, 123, 259, 2717, 0]),
+    (12, 1634, [1, 3, 5, 13, 11, 3, 111, 21, 149, 267, 739, 2503, 0]),
+    (12, 1636, [1, 3, 1, 13, 19, 13, 67, 57, 335, 175, 425, 2185, 0]),
+    (12, 1639, [1, 1, 7, 3, 9, 35, 71, 131, 479, 983, 525, 2473, 0]),
+    (12, 1657, [1, 1, 1, 7, 29, 59, 97, 87, 307, 969, 513, 2911, 0]),
+    (12, 1667, [1, 1, 5, 1, 27, 47, 63, 131, 171, 315, 1287, 3753, 0]),
+    (12, 1681, [1, 3, 5, 13, 1, 1, 39, 253, 15, 383, 1301, 3691, 0]),
+    (12, 1697, [1, 1, 7, 13, 9, 3, 37, 233, 211, 315, 1577, 1767, 0]),
+    (12, 1704, [1, 1, 1, 3, 5, 29, 109, 101, 255, 603, 1997, 289, 0]),
+    (12, 1709, [1, 1, 1, 7, 29, 47, 81, 127, 507, 385, 821, 329, 0]),
+    (12, 1722, [1, 1, 3, 13, 31, 49, 43, 59, 141, 947, 1423, 2057, 0]),
+    (12, 1730, [1, 3, 3, 15, 27, 63, 47, 211, 305, 347, 1389, 3993, 0]),
+    (12, 1732, [1, 1, 5, 9, 29, 25, 69, 97, 315, 919, 831, 1767, 0]),
+    (12, 1802, [1, 1, 3, 15, 19, 21, 65, 107, 83, 789, 179, 1279, 0]),
+    (12, 1804, [1, 1, 3, 13, 23, 51, 43, 49, 485, 461, 1253, 493, 0]),
+    (12, 1815, [1, 1, 7, 15, 29, 7, 75, 199, 457, 903, 593, 461, 0]),
+    (12, 1826, [1, 3, 3, 5, 23, 45, 93, 69, 101, 777, 319, 823, 0]),
+    (12, 1832, [1, 1, 5, 15, 13, 25, 3, 87, 225, 45, 383, 3935, 0]),
+    (12, 1843, [1, 3, 7, 11, 15, 55, 121, 91, 363, 365, 1179, 1473, 0]),
+    (12, 1849, [1, 1, 1, 15, 1, 37, 95, 47, 135, 709, 1653, 3211, 0]),
+    (12, 1863, [1, 3, 1, 13, 17, 7, 47, 253, 375, 755, 231, 1495, 0]),
+    (12, 1905, [1, 3, 5, 3, 27, 45, 81, 81, 433, 857, 701, 533, 0]),
+    (12, 1928, [1, 1, 1, 1, 27, 25, 115, 241, 249, 559, 29, 997, 0]),
+    (12, 1933, [1, 3, 1, 15, 31, 37, 1, 115, 179, 685, 127, 1819, 0]),
+    (12, 1939, [1, 3, 5, 11, 17, 37, 87, 221, 51, 15, 1583, 3541, 0]),
+    (12, 1976, [1, 3, 5, 9, 29, 11, 19, 169, 41, 603, 517, 2199, 0]),
+    (12, 1996, [1, 1, 1, 1, 31, 15, 1, 187, 67, 991, 1743, 3101, 0]),
+    (12, 2013, [1, 3, 5, 13, 9, 1, 117, 87, 61, 127, 1909, 1171, 0]),
+    (12, 2014, [1, 1, 7, 1, 1, 43, 119, 79, 325, 323, 535, 1245, 0]),
+    (12, 2020, [1, 1, 5, 1, 17, 55, 81, 35, 163, 601, 347, 2173, 0]),
+    (13, 13, [1, 3, 1, 1, 13, 9, 9, 121, 361, 629, 789, 2995, 6403]),
+    (13, 19, [1, 3, 7, 5, 7, 57, 49, 137, 337, 317, 151, 3605, 7003]),
+    (13, 26, [1, 3, 3, 15, 7, 57, 11, 57, 113, 635, 1139, 803, 1507]),
+    (13, 41, [1, 3, 3, 5, 29, 1, 37, 93, 195, 231, 373, 3173, 2067]),
+    (13, 50, [1, 1, 7, 9, 29, 31, 127, 247, 61, 743, 845, 1507, 8191]),
+    (13, 55, [1, 3, 7, 7, 19, 15, 123, 53, 139, 725, 437, 227, 3931]),
+    (13, 69, [1, 1, 1, 7, 9, 63, 3, 81, 261, 867, 1887, 735, 2537]),
+    (13, 70, [1, 1, 5, 7, 27, 29, 33, 65, 487, 237, 2003, 2671, 7613]),
+    (13, 79, [1, 1, 1, 15, 9, 49, 41, 129, 437, 551, 927, 975, 3837]),
+    (13, 82, [1, 3, 1, 7, 13, 41, 67, 195, 419, 783, 459, 3015, 7703]),
+    (13, 87, [1, 1, 1, 9, 15, 19, 17, 13, 271, 401, 1989, 549, 2127]),
+    (13, 93, [1, 1, 7, 9, 15, 43, 7, 83, 233, 107, 2017, 799, 3377]),
+    (13, 94, [1, 1, 7, 7, 13, 31, 13, 211, 425, 991, 41, 3445, 7539]),
+    (13, 97, [1, 1, 3, 3, 15, 29, 103, 39, 191, 869, 1165, 3103, 3325]),
+    (13, 100, [1, 3, 5, 9, 13, 55, 79, 41, 243, 1001, 1011, 2129, 8147]),
+    (13, 112, [1, 1, 1, 1, 17, 39, 21, 85, 427, 895, 865, 1023, 5683]),
+    (13, 121, [1, 1, 5, 15, 3, 47, 39, 185, 5, 79, 1167, 553, 785]),
+    (13, 134, [1, 1, 5, 5, 3, 45, 113, 9, 489, 445, 43, 3495, 6595]),
+    (13, 138, [1, 3, 5, 11, 13, 15, 123, 171, 235, 321, 1545, 3875, 6953]),
+    (13, 148, [1, 1, 7, 11, 25, 43, 7, 251, 179, 293, 1057, 3245, 4211]),
+    (13, 151, [1, 1, 5, 1, 25, 7, 101, 25, 323, 445, 1089, 953, 1151]),
+    (13, 157, [1, 3, 1, 3, 27, 7, 57, 93, 323, 767, 1043, 2509, 807]),
+    (13, 161, [1, 3, 7, 3, 27, 3, 3, 139, 135, 93, 1539, 1783, 2735]),
+    (13, 179, [1, 1, 3, 13, 31, 23, 87, 25, 285, 823, 403, 3629, 5885]),
+    (13, 181, [1, 1, 5, 3, 9, 51, 41, 45, 25, 921, 1699, 3539, 1723]),
+    (13, 188, [1, 1, 7, 13, 1, 11, 13, 173, 129, 663, 1691, 1681, 8129]),
+    (13, 196, [1, 1, 7, 11, 25, 47, 47, 25, 83, 155, 189, 3181, 1001]),
+    (13, 203, [1, 3, 5, 13, 23, 47, 25, 157, 327, 919, 639, 2761, 4381]),
+    (13, 206, [1, 3, 3, 1, 13, 59, 33, 255, 283, 319, 123, 1119, 6659]),
+    (13, 223, [1, 1, 1, 5, 21, 59, 45, 195, 317, 149, 593, 3261, 7673]),
+    (13, 224, [1, 3, 7, 3, 17, 49, 49, 187, 251, 447, 263, 1505, 2397]),
+    (13, 227, [1, 3, 5, 15, 1, 31, 83, 133, 231, 857, 1319, 3221, 7003]),
+    (13, 230, [1, 1, 3, 13, 1, 41, 75, 221, 455, 63, 1605, 2063, 6067]),
+    (13, 239, [1, 3, 1, 15, 31, 43, 11, 179, 283, 209, 1873, 191, 6021]),
+    (13, 241, [1, 1, 3, 15, 21, 43, 103, 25, 29, 403, 589, 715, 4185]),
+    (13, 248, [1, 1, 3, 3, 9, 15, 35, 67, 387, 623, 993, 3955, 7383]),
+    (13, 253, [1, 1, 1, 11, 31, 49, 17, 207, 291, 245, 1333, 681, 4565]),
+    (13, 268, [1, 3, 5, 13, 27, 39, 119, 127, 501, 179, 125, 3421, 2119]),
+    (13, 274, [1, 1, 7, 13, 17, 59, 83, 111, 75, 739, 373, 3213, 2761]),
+    (13, 283, [1, 1, 1, 7, 29, 37, 29, 185, 315, 1023, 1355, 3253, 2641]),
+    (13, 286, [1, 1, 3, 15, 17, 3, 3, 107, 285, 255, 817, 4013, 4879]),
+    (13, 289, [1, 3, 3, 1, 15, 41, 69, 213, 247, 165, 877, 107, 2877]),
+    (13, 301, [1, 1, 7, 5, 19, 59, 21, 157, 83, 55, 567, 945, 865]),
+    (13, 302, [1, 1, 5, 15, 3, 25, 37, 171, 77, 233, 417, 3923, 7095]),
+    (13, 316, [1, 3, 7, 9, 31, 29, 25, 101, 337, 861, 141, 117, 1477]),
+    (13, 319, [1, 3, 5, 7, 11, 29, 23, 155, 319, 33, 657, 3593, 6913]),
+    (13, 324, [1, 3, 1, 1, 3, 49, 123, 53, 187, 97, 867, 1579, 1525]),
+    (13, 331, [1, 3, 3, 1, 21, 61, 119, 55, 17, 797, 1327, 3951, 5995]),
+    (13, 333, [1, 3, 7, 15, 11, 47, 69, 33, 287, 123, 1537, 2747, 4647]),
+    (13, 345, [1, 3, 7, 15, 15, 53, 89, 211, 505, 99, 883, 35, 6601]),
+    (13, 351, [1, 1, 3, 11, 9, 53, 87, 231, 449, 35, 1595, 559, 6441]),
+    (13, 358, [1, 1, 5, 11, 3, 25, 103, 205, 109, 775, 1993, 491, 1723]),
+    (13, 375, [1, 3, 3, 11, 21, 31, 67, 171, 381, 169, 767, 1523, 6111]),
+    (13, 379, [1, 1, 1, 15, 13, 5, 59, 45, 469, 627, 1121, 3889, 371]),
+    (13, 381, [1, 3, 7, 5, 31, 51, 89, 15, 469, 163, 1039, 379, 2297]),
+    (13, 386, [1, 3, 5, 1, 31, 61, 37, 129, 321, 17, 1725, 2101, 2733]),
+    (13, 403, [1, 3, 7, 11, 9, 3, 87, 65, 95, 467, 663, 1887, 4877]),
+    (13, 405, [1, 1, 7, 11, 11, 61, 59, 47, 191, 63, 877, 359, 5899]),
+    (13, 419, [1, 1, 1, 5, 17, 23, 53, 65, 5, 35, 97, 1983, 3727]),
+    (13, 426, [1, 1, 7, 1, 19, 43, 113, 215, 243, 503, 483, 1693, 5371]),
+    (13, 428, [1, 3, 7, 13, 15, 33, 97, 89, 241, 551, 873, 3521, 8043]),
+    (13, 439, [1, 1, 1, 1, 1, 15, 7, 177, 309, 733, 1597, 2013, 3209]),
+    (13, 440, [1, 1, 7, 1, 15, 45, 101, 53, 125, 647, 1729, 2059, 2433]),
+    (13, 446, [1, 1, 7, 9, 7, 45, 95, 153, 391, 773, 1545, 3685, 6265]),
+    (13, 451, [1, 1, 1, 1, 3, 63, 63, 25, 19, 867, 139, 985, 5981]),
+    (13, 454, [1, 1, 5, 3, 7, 43, 41, 253, 213, 881, 3, 3971, 273]),
+    (13, 458, [1, 1, 5, 15, 25, 55, 59, 193, 201, 749, 1589, 247, 2629]),
+    (13, 465, [1, 1, 1, 15, 1, 41, 57, 1, 279, 239, 1967, 3307, 2883]),
+    (13, 468, [1, 1, 3, 7, 11, 47, 47, 45, 303, 1017, 873, 3837, 3055]),
+    (13, 472, [1, 3, 5, 7, 7, 51, 19, 215, 203, 635, 2033, 837, 6969]),
+    (13, 475, [1, 3, 1, 9, 17, 29, 57, 97, 37, 483, 1891, 1927, 23]),
+    (13, 477, [1, 3, 1, 1, 9, 47, 109, 149, 33, 249, 1619, 3739, 117]),
+    (13, 496, [1, 1, 7, 11, 15, 41, 85, 97, 189, 259, 765, 3513, 6761]),
+    (13, 502, [1, 1, 1, 9, 5, 11, 81, 203, 3, 945, 221, 81, 4249]),
+    (13, 508, [1, 3, 3, 3, 23, 11, 43, 131, 141, 767, 1117, 2191, 3593]),
+    (13, 517, [1, 3, 7, 15, 1, 1, 47, 103, 67, 903, 1751, 2497, 3639]),
+    (13, 521, [1, 3, 7, 7, 1, 1, 93, 235, 311, 609, 217, 2797, 819]),
+    (13, 527, [1, 1, 5, 5, 9, 39, 115, 121, 149, 213, 1279, 1439, 1115]),
+    (13, 530, [1, 1, 3, 11, 19, 59, 109, 37, 461, 737, 625, 2721, 1949]),
+    (13, 532, [1, 1, 1, 11, 5, 37, 61, 215, 301, 515, 1353, 367, 5565]),
+    (13, 542, [1, 3, 5, 15, 11, 51, 7, 7, 87, 17, 1795, 471, 3559]),
+    (13, 552, [1, 3, 5, 5, 11, 41, 59, 11, 13, 397, 845, 3953, 5663]),
+    (13, 555, [1, 3, 5, 13, 7, 29, 65, 79, 259, 531, 763, 3115, 7113]),
+    (13, 560, [1, 1, 7, 1, 17, 53, 47, 37, 265, 111, 1441, 3101, 2227]),
+    (13, 566, [1, 1, 7, 13, 25, 53, 33, 165, 259, 1011, 281, 2563, 2337]),
+    (13, 575, [1, 1, 7, 11, 15, 29, 15, 245, 379, 119, 309, 1023, 4837]),
+    (13, 577, [1, 1, 3, 5, 19, 53, 75, 71, 17, 727, 1357, 4057, 1569]),
+    (13, 589, [1, 1, 7, 13, 11, 37, 59, 69, 403, 5, 1867, 3597, 6603]),
+    (13, 590, [1, 1, 7, 11, 17, 7, 127, 241, 255, 533, 1759, 3069, 165]),
+    (13, 602, [1, 1, 1, 5, 19, 11, 113, 117, 335, 137, 1763, 1451, 5199]),
+    (13, 607, [1, 3, 5, 13, 15, 55, 41, 151, 487, 625, 1797, 2729, 5527]),
+    (13, 608, [1, 1, 3, 11, 25, 19, 123, 13, 241, 743, 849, 1989, 2393]),
+    (13, 611, [1, 3, 3, 3, 31, 45, 21, 43, 503, 83, 425, 1143, 2611]),
+    (13, 613, [1, 1, 3, 15, 1, 45, 37, 9, 179, 265, 711, 3443, 5909]),
+    (13, 625, [1, 3, 3, 13, 21, 45, 91, 183, 417, 501, 1913, 3773, 1287]),
+    (13, 644, [1, 1, 5, 3, 23, 1, 7, 245, 507, 367, 189, 1877, 5453]),
+    (13, 651, [1, 3, 7, 5, 17, 29, 51, 167, 365, 1023, 1989, 1735, 7653]),
+    (13, 654, [1, 1, 7, 9, 27, 45, 113, 183, 179, 633, 573, 677, 2217]),
+    (13, 656, [1, 1, 1, 5, 11, 43, 107, 135, 255, 639, 447, 611, 6539]),
+    (13, 662, [1, 1, 1, 3, 19, 51, 67, 75, 357, 825, 945, 2913, 7281]),
+    (13, 668, [1, 3, 7, 5, 7, 43, 63, 77, 443, 405, 941, 3019, 6219]),
+    (13, 681, [1, 1, 1, 15, 1, 27, 53, 153, 137, 853, 453, 1865, 2025]),
+    (13, 682, [1, 3, 5, 9, 31, 55, 33, 107, 131, 577, 1299, 1271, 3517]),
+    (13, 689, [1, 3, 1, 13, 27, 49, 111, 87, 217, 435, 1705, 1057, 4511]),
+    (13, 696, [1, 1, 5, 1, 9, 25, 61, 31, 499, 867, 1487, 3869, 1715]),
+    (13, 699, [1, 1, 3, 11, 19, 35, 45, 99, 311, 807, 509, 993, 5645]),
+    (13, 707, [1, 1, 1, 3, 27, 45, 17, 43, 221, 751, 809, 1435, 1557]),
+    (13, 709, [1, 3, 1, 13, 1, 3, 49, 67, 211, 563, 385, 207, 3875]),
+    (13, 714, [1, 1, 5, 11, 21, 51, 33, 25, 127, 253, 585, 2937, 2673]),
+    (13, 716, [1, 3, 1, 7, 13, 51, 25, 11, 217, 1015, 449, 935, 1471]),
+    (13, 719, [1, 1, 5, 9, 1, 37, 61, 253, 403, 449, 1065, 361, 6135]),
+    (13, 727, [1, 3, 3, 15, 29, 41, 107, 247, 49, 153, 1415, 3281, 3207]),
+    (13, 734, [1, 3, 7, 3, 11, 55, 27, 43, 355, 535, 929, 3239, 4335]),
+    (13, 738, [1, 3, 7, 1, 21, 5, 93, 213, 457, 315, 1607, 2379, 4971]),
+    (13, 743, [1, 1, 7, 7, 23, 51, 107, 89, 419, 577, 1129, 1151, 7327]),
+    (13, 747, [1, 1, 3, 15, 29, 3, 17, 13, 97, 115, 83, 2619, 1683]),
+    (13, 757, [1, 3, 7, 5, 27, 23, 3, 245, 31, 637, 1153, 379, 215]),
+    (13, 769, [1, 3, 5, 13, 19, 11, 11, 69, 299, 139, 2005, 331, 6033]),
+    (13, 770, [1, 3, 7, 9, 1, 55, 19, 165, 445, 261, 1155, 1187, 5597]),
+    (13, 776, [1, 1, 7, 9, 21, 1, 35, 77, 199, 199, 1955, 2569, 2611]),
+    (13, 790, [1, 3, 7, 1, 25, 63, 67, 123, 419, 739, 477, 1217, 257]),
+    (13, 799, [1, 1, 5, 13, 3, 21, 57, 133, 475, 845, 41, 2663, 5895]),
+    (13, 805, [1, 3, 1, 3, 25, 49, 31, 133, 483, 445, 595, 2419, 1621]),
+    (13, 809, [1, 3, 5, 15, 27, 47, 13, 187, 3, 935, 1099, 533, 3497]),
+    (13, 812, [1, 1, 3, 9, 27, 41, 59, 71, 471, 235, 991, 1133, 6393]),
+    (13, 820, [1, 1, 1, 1, 23, 43, 83, 151, 397, 585, 1505, 1967, 4455]),
+    (13, 827, [1, 3, 3, 11, 15, 31, 69, 227, 269, 465, 1753, 3425, 8057]),
+    (13, 829, [1, 3, 5, 13, 1, 7, 53, 157, 77, 759, 769, 413, 4677]),
+    (13, 835, [1, 3, 5, 13, 11, 55, 5, 85, 507, 601, 545, 1915, 6609]),
+    (13, 841, [1, 1, 7, 15, 31, 33, 31, 221, 45, 329, 1481, 1771, 6239]),
+    (13, 844, [1, 1, 7, 11, 7, 37, 63, 1, 207, 949, 1159, 1499, 2493]),
+    (13, 856, [1, 3, 1, 1, 1, 25, 33, 107, 233, 415, 1051, 1889, 6033]),
+    (13, 859, [1, 3, 7, 3, 11, 13, 85, 205, 403, 909, 125, 2985, 3671]),
+    (13, 862, [1, 3, 1, 3, 1, 23, 93, 255, 451, 75, 711, 2263, 6373]),
+    (13, 865, [1, 1, 1, 1, 27, 13, 23, 103, 117, 37, 925, 739, 169]),
+    (13, 885, [1, 3, 3, 11, 21, 3, 75, 217, 171, 469, 1407, 445, 7933]),
+    (13, 890, [1, 3, 5, 15, 9, 1, 117, 213, 17, 249, 685, 57, 4913]),
+    (13, 905, [1, 3, 1, 9, 29, 55, 63, 207, 59, 273, 1829, 935, 3071]),
+    (13, 916, [1, 1, 1, 15, 19, 57, 55, 227, 263, 133, 445, 3805, 2375]),
+    (13, 925, [1, 1, 7, 11, 23, 61, 73, 215, 39, 175, 387, 611, 5733]),
+    (13, 935, [1, 1, 7, 3, 19, 37, 101, 105, 227, 931, 1571, 2955, 4289]),
+    (13, 939, [1, 1, 3, 9, 11, 51, 55, 121, 249, 109, 1013, 291, 7593]),
+    (13, 942, [1, 1, 3, 13, 27, 13, 119, 243, 7, 411, 2003, 1865, 6291]),
+    (13, 949, [1, 3, 1, 1, 25, 37, 125, 217, 461, 351, 1055, 3857, 1989]),
+    (13, 953, [1, 1, 3, 7, 19, 35, 37, 193, 275, 233, 1467, 867, 6185]),
+    (13, 956, [1, 3, 5, 1, 25, 17, 71, 93, 487, 395, 699, 1217, 8101]),
+    (13, 961, [1, 3, 7, 13, 5, 51, 71, 211, 507, 713, 595, 2527, 6117]),
+    (13, 968, [1, 3, 7, 13, 1, 47, 21, 171, 31, 339, 407, 899, 7979]),
+    (13, 976, [1, 3, 3, 11, 7, 5, 59, 219, 495, 633, 839, 1681, 589]),
+    (13, 988, [1, 1, 1, 15, 19, 13, 35, 95, 427, 751, 1111, 2959, 6639]),
+    (13, 995, [1, 1, 5, 15, 25, 51, 115, 115, 109, 257, 1579, 605, 7659]),
+    (13, 997, [1, 3, 7, 1, 31, 45, 65, 139, 353, 81, 351, 1605, 5945]),
+    (13, 1007, [1, 3, 3, 15, 3, 35, 15, 93, 233, 249, 707, 3243, 7195]),
+    (13, 1015, [1, 1, 5, 9, 5, 11, 55, 105, 263, 471, 1083, 4041, 679]),
+    (13, 1016, [1, 1, 5, 15, 19, 31, 67, 43, 183, 13, 777, 173, 881]),
+    (13, 1027, [1, 3, 1, 15, 29, 27, 41, 151, 347, 701, 1933, 683, 5525]),
+    (13, 1036, [1, 3, 3, 1, 31, 13, 7, 221, 247, 971, 1741, 1663, 649]),
+    (13, 1039, [1, 1, 1, 3, 7, 15, 115, 105, 327, 649, 351, 413, 2201]),
+    (13, 1041, [1, 3, 1, 1, 9, 63, 109, 195, 349, 409, 1971, 3599, 421]),
+    (13, 1048, [1, 1, 3, 15, 21, 27, 31, 141, 207, 411, 895, 3727, 1097]),
+    (13, 1053, [1, 1, 5, 7, 13, 43, 101, 15, 155, 139, 1377, 1021, 2255]),
+    (13, 1054, [1, 1, 3, 9, 5, 21, 95, 47, 47, 463, 1725, 2561, 543]),
+    (13, 1058, [1, 1, 5, 1, 5, 21, 89, 137, 355, 283, 255, 2613, 7155]),
+    (13, 1075, [1, 3, 3, 7, 27, 39, 113, 63, 397, 625, 753, 1053, 3633]),
+    (13, 1082, [1, 1, 3, 15, 15, 59, 45, 99, 197, 249, 391, 2235, 733]),
+    (13, 1090, [1, 3, 1, 9, 29, 3, 35, 77, 505, 9, 1539, 2869, 7923]),
+    (13, 1109, [1, 1, 7, 5, 19, 43, 23, 161, 251, 1009, 1009, 767, 5213]),
+    (13, 1110, [1, 1, 5, 9, 19, 53, 99, 147, 511, 657, 1349, 115, 4811]),
+    (13, 1119, [1, 3, 5, 13, 11, 15, 35, 219, 73, 53, 699, 109, 1769]),
+    (13, 1126, [1, 1, 1, 3, 13, 27, 27, 169, 179, 287, 133, 45, 3999]),
+    (13, 1130, [1, 1, 5, 13, 11, 19, 35, 249, 59, 913, 1291, 77, 1063]),
+    (13, 1135, [1, 1, 3, 9, 7, 25, 25, 3, 405, 649, 1037, 1353, 5261]),
+    (13, 1137, [1, 3, 7, 11, 3, 33, 47, 185, 237, 301, 205, 1707, 5923]),
+    (13, 1140, [1, 1, 5, 13, 19, 21, 35, 95, 439, 947, 1175, 665, 3459]),
+    (13, 1149, [1, 3, 1, 1, 29, 1, 15, 49, 333, 865, 289, 1929, 5929]),
+    (13, 1156, [1, 3, 7, 3, 9, 35, 13, 65, 105, 853, 2029, 307, 3927]),
+    (13, 1159, [1, 1, 7, 3, 7, 9, 27, 187, 501, 1015, 439, 3885, 8137]),
+    (13, 1160, [1, 3, 7, 1, 13, 11, 95, 187, 405, 739, 987, 3489, 5663]),
+    (13, 1165, [1, 1, 1, 15, 17, 13, 105, 161, 49, 83, 411, 201, 4075]),
+    (13, 1173, [1, 1, 3, 5, 1, 47, 109, 47, 173, 75, 1695, 2085, 3795]),
+    (13, 1178, [1, 3, 3, 9, 1, 1, 99, 45, 9, 867, 797, 2323, 831]),
+    (13, 1183, [1, 3, 5, 5, 19, 13, 57, 115, 323, 283, 1191, 3439, 7473]),
+    (13, 1184, [1, 1, 1, 7, 23, 51, 79, 239, 191, 47, 1377, 3973, 7865]),
+    (13, 1189, [1, 1, 1, 13, 7, 57, 65, 155, 111, 655, 905, 3653, 3839]),
+    (13, 1194, [1, 3, 7, 13, 17, 59, 61, 219, 251, 469, 453, 2663, 3455]),
+    (13, 1211, [1, 1, 7, 1, 25, 25, 81, 103, 17, 473, 35, 2723, 6359]),
+    (13, 1214, [1, 1, 5, 9, 15, 39, 49, 227, 277, 341, 1493, 3573, 4611]),
+    (13, 1216, [1, 3, 7, 13, 27, 25, 79, 235, 79, 465, 839, 2417, 7489]),
+    (13, 1225, [1, 3, 7, 13, 29, 49, 29, 133, 205, 539, 551, 163, 4089]),
+    (13, 1231, [1, 1, 7, 3, 15, 57, 125, 19, 73, 227, 1693, 187, 3451]),
+    (13, 1239, [1, 3, 3, 11, 19, 29, 31, 243, 473, 801, 1867, 2015, 3669]),
+    (13, 1243, [1, 3, 7, 9, 11, 15, 23, 75, 437, 711, 33, 383, 3315]),
+    (13, 1246, [1, 3, 5, 1, 25, 51, 117, 177, 403, 987, 257, 147, 2045]),
+    (13, 1249, [1, 3, 5, 3, 19, 23, 91, 251, 49, 79, 109, 2799, 1863]),
+    (13, 1259, [1, 1, 1, 13, 13, 39, 17, 211, 13, 589, 2017, 1983, 4917]),
+    (13, 1273, [1, 3, 1, 3, 19, 49, 7, 227, 419, 49, 797, 1135, 7773]),
+    (13, 1274, [1, 1, 3, 7, 19, 61, 21, 219, 379, 731, 299, 3729, 5341]),
+    (13, 1281, [1, 1, 7, 9, 13, 7, 67, 165, 499, 949, 725, 813, 1413]),
+    (13, 1287, [1, 3, 5, 15, 15, 35, 59, 97, 15, 775, 547, 4093, 2895]),
+    (13, 1294, [1, 1, 7, 11, 5, 11, 31, 155, 395, 509, 443, 3935, 4669]),
+    (13, 1296, [1, 3, 5, 3, 31, 33, 65, 185, 261, 189, 1057, 2725, 3893]),
+    (13, 1305, [1, 3, 3, 7, 11, 25, 105, 71, 169, 647, 1815, 193, 727]),
+    (13, 1306, [1, 1, 5, 1, 29, 13, 49, 51, 5, 679, 493, 4053, 6461]),
+    (13, 1318, [1, 1, 5, 15, 27, 23, 79, 227, 173, 399, 521, 1617, 65]),
+    (13, 1332, [1, 3, 3, 9, 21, 29, 99, 79, 1, 771, 201, 1027, 5805]),
+    (13, 1335, [1, 3, 7, 9, 21, 47, 3, 107, 421, 649, 1283, 3907, 4043]),
+    (13, 1336, [1, 1, 1, 5, 3, 53, 67, 63, 145, 545, 1059, 2707, 3429]),
+    (13, 1341, [1, 3, 3, 13, 27, 51, 3, 127, 367, 525, 1649, 3643, 531]),
+    (13, 1342, [1, 1, 5, 5, 21, 39, 69, 27, 39, 727, 1039, 3887, 4129]),
+    (13, 1362, [1, 3, 5, 7, 3, 21, 21, 211, 91, 689, 1909, 2389, 3673]),
+    (13, 1364, [1, 3, 3, 13, 27, 55, 35, 215, 117, 37, 637, 2949, 7369]),
+    (13, 1368, [1, 1, 1, 15, 5, 47, 55, 153, 113, 375, 1275, 3631, 5977]),
+    (13, 1378, [1, 3, 1, 5, 19, 29, 67, 177, 247, 317, 43, 359, 2387]),
+    (13, 1387, [1, 3, 5, 1, 17, 15, 3, 189, 69, 827, 471, 1535, 6853]),
+    (13, 1389, [1, 3, 1, 7, 15, 7, 87, 89, 353, 511, 1135, 907, 5217]),
+    (13, 1397, [1, 1, 1, 7, 31, 43, 43, 151, 33, 295, 59, 1229, 2295]),
+    (13, 1401, [1, 3, 7, 9, 5, 21, 61, 193, 217, 135, 1107, 2377, 3887]),
+    (13, 1408, [1, 3, 7, 9, 5, 49, 93, 147, 315, 207, 1873, 71, 1609]),
+    (13, 1418, [1, 3, 5, 3, 5, 31, 95, 151, 439, 977, 1423, 1159, 1953]),
+    (13, 1425, [1, 3, 1, 1, 11, 37, 23, 85, 261, 281, 1169, 137, 4117]),
+    (13, 1426, [1, 3, 5, 1, 21, 35, 107, 131, 165, 301, 2003, 215, 4135]),
+    (13, 1431, [1, 1, 5, 11, 27, 5, 101, 199, 27, 941, 597, 1789, 3815]),
+    (13, 1435, [1, 3, 5, 5, 13, 59, 29, 227, 289, 815, 1153, 1317, 1063]),
+    (13, 1441, [1, 1, 1, 13, 31, 41, 109, 119, 213, 785, 1809, 2335, 2173]),
+    (13, 1444, [1, 3, 3, 13, 11, 21, 97, 171, 451, 623, 145, 359, 5573]),
+    (13, 1462, [1, 1, 1, 7, 5, 29, 65, 135, 207, 873, 733, 677, 5879]),
+    (13, 1471, [1, 1, 7, 9, 11, 61, 7, 197, 133, 957, 1101, 1653, 919]),
+    (13, 1474, [1, 1, 3, 5, 23, 19, 15, 73, 401, 191, 79, 3011, 1863]),
+    (13, 1483, [1, 1, 7, 1, 13, 41, 33, 215, 11, 753, 1673, 3745, 6453]),
+    (13, 1485, [1, 1, 5, 9, 31, 31, 69, 253, 483, 765, 1489, 3147, 6881]),
+    (13, 1494, [1, 3, 7, 7, 17, 11, 17, 37, 381, 323, 1233, 3529, 7173]),
+    (13, 1497, [1, 1, 3, 13, 11, 43, 5, 245, 67, 153, 919, 2975, 2737]),
+    (13, 1516, [1, 3, 1, 15, 27, 9, 69, 13, 341, 855, 877, 3527, 3227]),
+    (13, 1522, [1, 3, 7, 15, 29, 39, 79, 233, 267, 131, 1917, 1501, 747]),
+    (13, 1534, [1, 1, 1, 9, 11, 23, 81, 151, 297, 447, 497, 2491, 6103]),
+    (13, 1543, [1, 3, 3, 13, 3, 63, 19, 151, 409, 323, 819, 3059, 1589]),
+    (13, 1552, [1, 3, 1, 5, 21, 17, 25, 85, 199, 487, 395, 1535, 5539]),
+    (13, 1557, [1, 1, 5, 7, 1, 13, 81, 75, 225, 415, 425, 435, 3655]),
+    (13, 1558, [1, 3, 1, 7, 11, 15, 9, 103, 353, 9, 989, 521, 2403]),
+    (13, 1567, [1, 1, 3, 11, 11, 9, 109, 135, 169, 415, 793, 3877, 5827]),
+    (13, 1568, [1, 1, 7, 1, 25, 19, 31, 29, 363, 619, 37, 2663, 4671]),
+    (13, 1574, [1, 3, 1, 5, 17, 43, 39, 17, 329, 883, 1037, 1647, 2805]),
+    (13, 1592, [1, 1, 3, 3, 5, 15, 15, 81, 431, 401, 281, 939, 7511]),
+    (13, 1605, [1, 1, 1, 3, 27, 43, 115, 3, 125, 971, 1691, 145, 5349]),
+    (13, 1606, [1, 3, 7, 15, 3, 25, 35, 59, 427, 787, 1177, 1527, 3283]),
+    (13, 1610, [1, 1, 3, 7, 21, 3, 79, 123, 247, 475, 1157, 305, 439]),
+    (13, 1617, [1, 1, 5, 15, 27, 55, 41, 197, 45, 787, 205, 493, 2393]),
+    (13, 1623, [1, 3, 7, 1, 11, 51, 47, 53, 287, 957, 423, 817, 241]),
+    (13, 1630, [1, 1, 1, 13, 5, 3, 79, 107, 439, 207, 767, 99, 5753]),
+    (13, 1634, [1, 3, 5, 1, 17, 33, 67, 209, 505, 635, 1281, 1597, 5983]),
+    (13, 1640, [1, 3, 3, 9, 5, 31, 81, 253, 169, 1009, 1385, 3089, 3037]),
+    (13, 1643, [1, 3, 1, 13, 7, 27, 7, 45, 275, 399, 1159, 4009, 6851]),
+    (13, 1648, [1, 3, 5, 3, 19, 35, 67, 79, 309, 537, 1415, 1353, 7571]),
+    (13, 1651, [1, 1, 7, 9, 21, 35, 121, 131, 255, 831, 833, 2051, 3887]),
+    (13, 1653, [1, 1, 1, 9, 5, 21, 39, 17, 109, 129, 1937, 1901, 2443]),
+    (13, 1670, [1, 3, 5, 13, 3, 27, 111, 221, 227, 583, 817, 3033, 6581]),
+    (13, 1676, [1, 1, 1, 11, 19, 51, 37, 147, 507, 961, 1059, 2001, 5749]),
+    (13, 1684, [1, 1, 1, 11, 25, 5, 83, 225, 73, 9, 1849, 995, 1879]),
+    (13, 1687, [1, 3, 3, 5, 29, 57, 99, 159, 375, 545, 525, 535, 6141]),
+    (13, 1691, [1, 1, 7, 9, 19, 9, 113, 91, 301, 889, 455, 2763, 5925]),
+    (13, 1693, [1, 1, 5, 1, 3, 7, 97, 45, 111, 549, 749, 2831, 4423]),
+    (13, 1698, [1, 3, 1, 7, 19, 55, 1, 201, 31, 979, 1605, 3111, 5963]),
+    (13, 1709, [1, 3, 1, 15, 27, 3, 63, 215, 397, 555, 723, 529, 3253]),
+    (13, 1715, [1, 3, 1, 1, 27, 9, 125, 125, 287, 393, 845, 1445, 4989]),
+    (13, 1722, [1, 3, 1, 1, 11, 55, 29, 127, 471, 639, 947, 2453, 5991]),
+    (13, 1732, [1, 1, 1, 5, 21, 27, 63, 149, 5, 157, 163, 1565, 7291]),
+    (13, 1735, [1, 1, 3, 13, 3, 7, 21, 227, 427, 931, 925, 3053, 145]),
+    (13, 1747, [1, 3, 7, 5, 21, 19, 13, 41, 469, 599, 1351, 1687, 885]),
+    (13, 1749, [1, 3, 7, 3, 23, 55, 41, 203, 63, 247, 1847, 2999, 3621]),
+    (13, 1754, [1, 1, 5, 5, 25, 37, 35, 137, 279, 987, 1261, 3629, 3665]),
+    (13, 1777, [1, 3, 3, 7, 13, 35, 127, 185, 23, 819, 1171, 3463, 4049]),
+    (13, 1784, [1, 1, 3, 9, 3, 51, 121, 41, 495, 955, 853, 735, 2075]),
+    (13, 1790, [1, 1, 7, 15, 31, 15, 89, 157, 37, 217, 761, 1735, 1405]),
+    (13, 1795, [1, 3, 5, 13, 5, 59, 53, 211, 97, 183, 743, 395, 1659]),
+    (13, 1801, [1, 1, 5, 7, 9, 55, 3, 127, 307, 141, 1463, 2051, 1551]),
+    (13, 1802, [1, 3, 3, 11, 21, 41, 15, 199, 79, 495, 887, 553, 7783]),
+    (13, 1812, [1, 3, 3, 5, 1, 1, 63, 55, 49, 171, 629, 3751, 3349]),
+    (13, 1828, [1, 3, 5, 13, 17, 9, 95, 5, 33, 201, 935, 1475, 2207]),
+    (13, 1831, [1, 3, 3, 5, 23, 47, 69, 141, 453, 523, 813, 4033, 4383]),
+    (13, 1837, [1, 3, 7, 1, 29, 1, 51, 145, 487, 179, 1915, 37, 1711]),
+    (13, 1838, [1, 1, 7, 11, 31, 21, 53, 23, 455, 477, 1365, 3281, 6749]),
+    (13, 1840, [1, 3, 5, 15, 9, 21, 117, 35, 97, 719, 89, 3845, 5445]),
+    (13, 1845, [1, 1, 1, 15, 27, 45, 103, 91, 499, 579, 289, 787, 8107]),
+    (13, 1863, [1, 1, 3, 13, 5, 1, 97, 39, 119, 209, 1235, 2811, 1929]),
+    (13, 1864, [1, 1, 1, 5, 19, 45, 93, 253, 207, 59, 1955, 1711, 2687]),
+    (13, 1867, [1, 1, 5, 3, 7, 45, 35, 169, 455, 667, 1329, 93, 8141]),
+    (13, 1870, [1, 1, 1, 1, 3, 47, 71, 213, 203, 657, 849, 75, 7387]),
+    (13, 1877, [1, 1, 7, 5, 27, 9, 49, 21, 425, 827, 1345, 263, 2393]),
+    (13, 1881, [1, 1, 3, 3, 11, 9, 55, 223, 369, 35, 1611, 1949, 5481]),
+    (13, 1884, [1, 3, 7, 3, 3, 43, 35, 241, 433, 105, 831, 3661, 5945]),
+    (13, 1903, [1, 3, 3, 11, 13, 57, 103, 149, 297, 117, 1889, 1239, 3157]),
+    (13, 1917, [1, 1, 5, 7, 11, 55, 1, 119, 305, 437, 1363, 2237, 3129]),
+    (13, 1918, [1, 3, 3, 13, 25, 11, 79, 187, 507, 247, 1975, 603, 731]),
+    (13, 1922, [1, 3, 7, 1, 23, 57, 79, 247, 419, 365, 987, 1909, 1811]),
+    (13, 1924, [1, 1, 3, 15, 5, 53, 67, 153, 351, 705, 1011, 1987, 3471]),
+    (13, 1928, [1, 1, 5, 11, 11, 15, 33, 69, 263, 753, 201, 603, 3097]),
+    (13, 1931, [1, 3, 5, 5, 13, 15, 89, 219, 345, 305, 1305, 981, 723]),
+    (13, 1951, [1, 3, 1, 15, 13, 49, 123, 109, 37, 995, 1741, 2167, 7989]),
+    (13, 1952, [1, 3, 1, 1, 25, 5, 85, 123, 393, 245, 1257, 1929, 5141]),
+    (13, 1957, [1, 3, 5, 11, 25, 37, 13, 215, 295, 629, 1549, 351, 6317]),
+    (13, 1958, [1, 3, 5, 11, 13, 55, 127, 5, 67, 31, 1699, 3725, 197]),
+    (13, 1964, [1, 3, 7, 9, 15, 53, 27, 225, 297, 213, 921, 2883, 3991]),
+    (13, 1967, [1, 3, 1, 9, 1, 3, 3, 41, 297, 665, 477, 2339, 3043]),
+    (13, 1970, [1, 3, 1, 5, 13, 3, 103, 231, 67, 795, 465, 3063, 4515]),
+    (13, 1972, [1, 3, 3, 5, 3, 45, 23, 191, 423, 557, 1435, 4091, 1467]),
+    (13, 1994, [1, 1, 3, 3, 3, 25, 49, 225, 419, 187, 37, 1287, 3063]),
+    (13, 2002, [1, 1, 5, 7, 21, 5, 97, 215, 125, 941, 2047, 157, 4613]),
+    (13, 2007, [1, 3, 7, 1, 17, 35, 51, 93, 111, 613, 1355, 231, 3505]),
+    (13, 2008, [1, 3, 5, 11, 1, 9, 57, 179, 215, 155, 103, 27, 929]),
+    (13, 2023, [1, 3, 1, 1, 25, 23, 91, 173, 397, 251, 1665, 2341, 1781]),
+    (13, 2030, [1, 1, 1, 9, 21, 55, 109, 163, 461, 507, 1765, 473, 3421]),
+    (13, 2035, [1, 3, 1, 3, 17, 23, 21, 15, 447, 863, 35, 3153, 4303]),
+    (13, 2038, [1, 1, 7, 7, 27, 35, 115, 235, 335, 979, 465, 2233, 3413]),
+    (13, 2042, [1, 3, 5, 5, 9, 11, 13, 53, 487, 399, 1831, 3107, 7813]),
+    (13, 2047, [1, 3, 7, 1, 1, 27, 97, 141, 509, 595, 889, 425, 1853]),
+    (13, 2051, [1, 3, 3, 15, 25, 35, 115, 57, 131, 779, 1315, 2271, 7533]),
+    (13, 2058, [1, 3, 1, 15, 3, 31, 65, 61, 425, 667, 1625, 539, 805]),
+    (13, 2060, [1, 1, 7, 5, 25, 21, 59, 1, 477, 423, 363, 2573, 6313]),
+    (13, 2071, [1, 3, 7, 15, 5, 59, 37, 205, 435, 903, 733, 1255, 1231]),
+    (13, 2084, [1, 1, 7, 11, 17, 57, 81, 153, 137, 11, 369, 977, 829]),
+    (13, 2087, [1, 1, 1, 15, 27, 33, 1, 193, 213, 993, 353, 257, 1499]),
+    (13, 2099, [1, 1, 7, 13, 23, 39, 17, 31, 17, 105, 1775, 2697, 2615]),
+    (13, 2108, [1, 1, 5, 15, 13, 63, 113, 155, 385, 715, 465, 1643, 3743]),
+    (13, 2111, [1, 3, 5, 11, 31, 7, 41, 139, 355, 455, 821, 3775, 6839]),
+    (13, 2120, [1, 1, 3, 15, 17, 5, 37, 179, 503, 661, 729, 1603, 5005]),
+    (13, 2128, [1, 3, 3, 9, 5, 3, 25, 199, 437, 743, 1185, 2223, 1579]),
+    (13, 2138, [1, 3, 5, 11, 9, 9, 89, 53, 449, 957, 1495, 3755, 3431]),
+    (13, 2143, [1, 3, 3, 13, 31, 57, 67, 183, 427, 377, 913, 4089, 6683]),
+    (13, 2144, [1, 3, 1, 5, 31, 11, 117, 161, 497, 389, 1279, 207, 2849]),
+    (13, 2153, [1, 3, 1, 5, 13, 21, 41, 93, 463, 57, 1227, 919, 1437]),
+    (13, 2156, [1, 3, 3, 1, 31, 23, 117, 255, 405, 709, 1545, 581, 1631]),
+    (13, 2162, [1, 1, 3, 3, 15, 29, 75, 229, 467, 935, 1807, 1619, 515]),
+    (13, 2167, [1, 1, 3, 11, 15, 37, 61, 3, 247, 443, 1365, 1929, 7699]),
+    (13, 2178, [1, 3, 5, 5, 19, 63, 75, 171, 361, 187, 1383, 3931, 2173]),
+    (13, 2183, [1, 1, 5, 5, 11, 25, 7, 121, 343, 615, 1631, 2621, 2407]),
+    (13, 2202, [1, 1, 7, 5, 17, 5, 29, 135, 337, 271, 1285, 2929, 453]),
+    (13, 2211, [1, 1, 1, 13, 3, 53, 7, 51, 167, 763, 747, 2843, 2965]),
+    (13, 2214, [1, 1, 5, 15, 3, 27, 45, 167, 405, 141, 141, 2049, 3207]),
+    (13, 2223, [1, 3, 7, 3, 13, 5, 23, 195, 115, 895, 101, 3595, 5459]),
+    (13, 2225, [1, 3, 7, 13, 15, 33, 9, 249, 177, 153, 1571, 2227, 6545]),
+    (13, 2232, [1, 1, 7, 5, 13, 11, 33, 179, 119, 563, 563, 1651, 1619]),
+    (13, 2237, [1, 3, 7, 11, 11, 9, 67, 153, 81, 693, 981, 3327, 6219]),
+    (13, 2257, [1, 3, 3, 3, 17, 57, 95, 205, 181, 687, 311, 3951, 3005]),
+    (13, 2260, [1, 1, 5, 13, 29, 37, 39, 213, 451, 741, 699, 3357, 1249]),
+    (13, 2267, [1, 1, 3, 5, 25, 19, 107, 121, 337, 869, 1229, 1003, 6037]),
+    (13, 2274, [1, 3, 1, 5, 11, 13, 127, 101, 509, 963, 1609, 2931, 859]),
+    (13, 2276, [1, 1, 1, 1, 29, 39, 59, 225, 239, 671, 251, 1537, 4795]),
+    (13, 2285, [1, 3, 5, 3, 19, 29, 11, 113, 337, 83, 1855, 3895, 1075]),
+    (13, 2288, [1, 1, 3, 5, 13, 63, 69, 7, 477, 551, 867, 3995, 6321]),
+    (13, 2293, [1, 1, 7, 7, 3, 61, 53, 121, 461, 597, 1421, 2075, 2851]),
+    (13, 2294, [1, 3, 1, 5, 5, 61, 53, 141, 13, 187, 485, 1131, 4745]),
+    (13, 2297, [1, 3, 7, 1, 25, 55, 13, 199, 257, 637, 2033, 503, 5113]),
+    (13, 2303, [1, 3, 1, 15, 1, 35, 15, 25, 199, 1013, 1343, 1227, 7895]),
+    (13, 2308, [1, 3, 1, 1, 25, 7, 89, 193, 99, 323, 1331, 397, 1609]),
+    (13, 2311, [1, 1, 1, 11, 9, 9, 5, 187, 157, 653, 747, 2937, 4867]),
+    (13, 2318, [1, 1, 3, 9, 27, 63, 105, 197, 459, 939, 1759, 3241, 7219]),
+    (13, 2323, [1, 3, 3, 9, 31, 49, 79, 121, 55, 635, 1769, 3449, 2681]),
+    (13, 2332, [1, 3, 3, 13, 5, 19, 73, 47, 143, 973, 1405, 389, 79]),
+    (13, 2341, [1, 3, 3, 13, 31, 13, 35, 19, 257, 939, 351, 3515, 1453]),
+    (13, 2345, [1, 1, 7, 7, 17, 45, 19, 11, 247, 325, 931, 2149, 7035]),
+    (13, 2348, [1, 1, 7, 15, 3, 3, 117, 127, 3, 451, 737, 2663, 1521]),
+    (13, 2354, [1, 1, 7, 11, 23, 53, 71, 139, 201, 413, 1521, 3779, 7405]),
+    (13, 2368, [1, 1, 1, 11, 15, 19, 65, 113, 201, 581, 625, 3813, 7455]),
+    (13, 2377, [1, 3, 7, 9, 15, 23, 51, 11, 117, 557, 843, 3947, 2877]),
+    (13, 2380, [1, 3, 3, 7, 19, 29, 5, 211, 183, 277, 333, 31, 6173]),
+    (13, 2383, [1, 3, 7, 1, 13, 11, 107, 103, 59, 579, 1097, 3229, 767]),
+    (13, 2388, [1, 3, 7, 13, 1, 19, 91, 39, 319, 1017, 1789, 701, 5065]),
+    (13, 2395, [1, 1, 3, 11, 19, 61, 9, 99, 373, 967, 1951, 2871, 7313]),
+    (13, 2397, [1, 1, 5, 3, 7, 1, 35, 167, 81, 251, 637, 3691, 7283]),
+    (13, 2401, [1, 1, 7, 5, 27, 33, 105, 185, 443, 829, 1753, 561, 4327]),
+    (13, 2411, [1, 1, 3, 11, 9, 49, 75, 41, 27, 269, 495, 841, 1365]),
+    (13, 2413, [1, 3, 3, 1, 15, 15, 83, 197, 475, 61, 1775, 1393, 5207]),
+    (13, 2419, [1, 3, 1, 1, 31, 51, 89, 163, 79, 547, 1597, 3329, 4649]),
+    (13, 2435, [1, 3, 1, 15, 1, 41, 91, 235, 497, 451, 479, 2735, 8089]),
+    (13, 2442, [1, 3, 1, 7, 17, 27, 69, 53, 73, 13, 469, 2533, 7015]),
+    (13, 2455, [1, 1, 7, 13, 13, 9, 115, 213, 127, 969, 129, 3425, 5687]),
+    (13, 2472, [1, 3, 7, 13, 29, 33, 21, 109, 13, 753, 1631, 65, 3619]),
+    (13, 2478, [1, 3, 1, 9, 13, 5, 65, 41, 135, 379, 979, 3403, 4835]),
+    (13, 2490, [1, 3, 3, 15, 27, 19, 55, 49, 299, 743, 853, 1139, 1093]),
+    (13, 2507, [1, 3, 1, 13, 31, 61, 5, 203, 425, 971, 443, 3931, 1957]),
+    (13, 2509, [1, 3, 5, 5, 7, 37, 5, 147, 321, 453, 1399, 495, 2977]),
+    (13, 2517, [1, 1, 3, 11, 13, 23, 5, 103, 131, 831, 1545, 1649, 7165]),
+    (13, 2524, [1, 3, 3, 7, 1, 61, 97, 153, 71, 323, 735, 239, 6845]),
+    (13, 2528, [1, 1, 3, 5, 23, 15, 21, 37, 257, 343, 1825, 2667, 4971]),
+    (13, 2531, [1, 3, 3, 11, 7, 43, 17, 29, 297, 841, 703, 3775, 1875]),
+    (13, 2538, [1, 3, 7, 3, 21, 43, 33, 149, 121, 649, 1731, 3837, 3687]),
+    (13, 2545, [1, 3, 3, 5, 15, 47, 65, 51, 191, 339, 131, 359, 3959]),
+    (13, 2546, [1, 1, 1, 9, 21, 39, 65, 249, 139, 317, 1757, 797, 549]),
+    (13, 2555, [1, 3, 5, 7, 1, 9, 117, 193, 459, 655, 781, 1161, 4275]),
+    (13, 2557, [1, 1, 7, 7, 3, 55, 87, 177, 101, 649, 1615, 4015, 6349]),
+    (13, 2564, [1, 1, 3, 5, 31, 51, 15, 213, 283, 15, 277, 2999, 7143]),
+    (13, 2573, [1, 3, 5, 3, 17, 61, 91, 101, 365, 221, 61, 1603, 1205]),
+    (13, 2579, [1, 3, 3, 5, 31, 7, 51, 57, 75, 551, 727, 2551, 5467]),
+    (13, 2592, [1, 1, 5, 5, 25, 15, 25, 9, 79, 955, 471, 3573, 6725]),
+    (13, 2598, [1, 3, 5, 11, 1, 61, 109, 15, 463, 75, 1519, 597, 779]),
+    (13, 2607, [1, 1, 1, 9, 25, 11, 91, 41, 289, 43, 235, 793, 3889]),
+    (13, 2612, [1, 1, 3, 7, 7, 39, 77, 85, 41, 755, 501, 461, 8065]),
+    (13, 2619, [1, 3, 7, 15, 25, 23, 115, 133, 145, 831, 359, 483, 4685]),
+    (13, 2621, [1, 3, 1, 11, 21, 55, 11, 33, 139, 465, 1937, 3675, 1347]),
+    (13, 2627, [1, 1, 1, 9, 1, 11, 35, 33, 117, 731, 1223, 1367, 173]),
+    (13, 2633, [1, 1, 3, 3, 27, 53, 85, 195, 487, 917, 463, 1075, 131]),
+    (13, 2636, [1, 3, 5, 7, 7, 27, 33, 221, 65, 1001, 483, 2525, 6459]),
+    (13, 2642, [1, 3, 1, 1, 25, 39, 59, 175, 469, 81, 1283, 3367, 5907]),
+    (13, 2654, [1, 1, 3, 9, 17, 47, 65, 93, 225, 41, 63, 2405, 321]),
+    (13, 2660, [1, 1, 1, 13, 7, 45, 93, 183, 91, 403, 93, 3459, 7181]),
+    (13, 2669, [1, 3, 3, 11, 9, 55, 11, 29, 257, 105, 785, 1829, 4041]),
+    (13, 2675, [1, 1, 5, 15, 11, 7, 61, 255, 347, 937, 899, 2927, 1909]),
+    (13, 2684, [1, 1, 3, 3, 15, 9, 63, 81, 499, 275, 1309, 3467, 823]),
+    (13, 2694, [1, 1, 7, 3, 1, 61, 89, 109, 433, 935, 1187, 1679, 1943]),
+    (13, 2703, [1, 3, 3, 3, 17, 15, 109, 31, 153, 99, 1581, 579, 1281]),
+    (13, 2706, [1, 3, 3, 1, 29, 15, 103, 147, 445, 639, 1141, 1075, 1129]),
+    (13, 2712, [1, 3, 5, 13, 11, 25, 17, 77, 203, 345, 1243, 629, 7133]),
+    (13, 2715, [1, 1, 1, 5, 23, 29, 99, 61, 35, 737, 991, 1569, 2489]),
+    (13, 2722, [1, 3, 1, 13, 31, 15, 117, 109, 473, 735, 879, 867, 3465]),
+    (13, 2727, [1, 1, 3, 15, 1, 31, 45, 31, 121, 113, 51, 1283, 2577]),
+    (13, 2734, [1, 1, 5, 11, 7, 21, 85, 19, 485, 239, 1409, 2455, 3079]),
+    (13, 2742, [1, 3, 5, 13, 29, 13, 53, 137, 503, 7, 1947, 2675, 4203]),
+    (13, 2745, [1, 1, 5, 9, 23, 47, 1, 151, 509, 363, 1035, 3885, 6861]),
+    (13, 2751, [1, 1, 3, 13, 7, 27, 91, 95, 103, 249, 441, 3689, 1291]),
+    (13, 2766, [1, 1, 1, 11, 25, 27, 125, 87, 309, 27, 1175, 2937, 851]),
+    (13, 2768, [1, 1, 7, 3, 1, 59, 19, 55, 123, 331, 1933, 11, 2761]),
+    (13, 2780, [1, 3, 3, 1, 15, 59, 121, 131, 217, 933, 2015, 2281, 779]),
+    (13, 2790, [1, 1, 7, 13, 15, 33, 3, 191, 335, 1001, 1179, 3921, 6309]),
+    (13, 2794, [1, 1, 3, 7, 11, 61, 117, 53, 459, 171, 1909, 1851, 3731]),
+    (13, 2796, [1, 3, 1, 3, 13, 5, 51, 223, 29, 897, 1431, 3641, 295]),
+    (13, 2801, [1, 3, 5, 3, 9, 15, 31, 221, 435, 687, 317, 959, 8097]),
+    (13, 2804, [1, 1, 5, 11, 27, 25, 79, 213, 199, 759, 57, 2783, 4527]),
+    (13, 2807, [1, 1, 7, 3, 17, 5, 65, 13, 9, 527, 1913, 1961, 279]),
+    (13, 2816, [1, 1, 1, 9, 7, 59, 27, 219, 137, 323, 1137, 1995, 3899]),
+    (13, 2821, [1, 3, 7, 15, 1, 13, 123, 47, 93, 655, 167, 2487, 3015]),
+    (13, 2831, [1, 1, 7, 11, 21, 17, 99, 103, 89, 227, 471, 1405, 7903]),
+    (13, 2834, [1, 3, 3, 9, 17, 31, 61, 175, 327, 437, 833, 323, 3661]),
+    (13, 2839, [1, 3, 1, 15, 15, 39, 89, 123, 91, 343, 731, 1945, 3633]),
+    (13, 2845, [1, 1, 3, 5, 27, 21, 103, 43, 339, 285, 1369, 1537, 7559]),
+    (13, 2852, [1, 3, 3, 1, 25, 45, 127, 9, 451, 543, 949, 3295, 869]),
+    (13, 2856, [1, 1, 7, 1, 31, 35, 97, 5, 139, 367, 97, 1849, 7667]),
+    (13, 2861, [1, 1, 1, 9, 1, 23, 53, 199, 245, 75, 1863, 3519, 1275]),
+    (13, 2873, [1, 3, 1, 15, 7, 19, 15, 97, 427, 579, 479, 4017, 3101]),
+    (13, 2874, [1, 1, 5, 3, 7, 13, 125, 49, 359, 219, 1759, 617, 4033]),
+    (13, 2888, [1, 3, 7, 1, 27, 61, 119, 233, 429, 893, 213, 2543, 3063]),
+    (13, 2893, [1, 3, 7, 15, 3, 33, 115, 11, 183, 639, 737, 323, 2051]),
+    (13, 2894, [1, 1, 7, 1, 27, 33, 127, 225, 53, 961, 747, 1035, 3231]),
+    (13, 2902, [1, 1, 7, 1, 7, 3, 91, 211, 305, 697, 1087, 93, 3575]),
+    (13, 2917, [1, 1, 3, 11, 15, 57, 127, 165, 385, 533, 1217, 203, 7609]),
+    (13, 2921, [1, 3, 1, 5, 19, 43, 73, 143, 315, 785, 419, 877, 5727]),
+    (13, 2922, [1, 3, 1, 7, 29, 13, 53, 209, 349, 643, 1203, 97, 4905]),
+    (13, 2929, [1, 1, 5, 11, 27, 1, 55, 241, 439, 791, 1897, 3653, 4007]),
+    (13, 2935, [1, 3, 5, 11, 29, 23, 49, 85, 465, 795, 869, 1777, 4931]),
+    (13, 2946, [1, 3, 3, 3, 17, 59, 37, 29, 183, 283, 1321, 1313, 167]),
+    (13, 2951, [1, 3, 1, 11, 27, 25, 73, 195, 79, 95, 2005, 917, 719]),
+    (13, 2957, [1, 3, 5, 9, 27, 45, 43, 133, 199, 585, 539, 113, 3383]),
+    (13, 2960, [1, 1, 5, 7, 15, 55, 47, 133, 129, 907, 909, 3781, 107]),
+    (13, 2966, [1, 3, 7, 1, 31, 33, 97, 63, 431, 815, 1807, 4021, 147]),
+    (13, 2972, [1, 3, 5, 3, 5, 7, 3, 251, 505, 671, 449, 2533, 209]),
+    (13, 2976, [1, 3, 1, 9, 9, 7, 111, 229, 263, 71, 1717, 3313, 5763]),
+    (13, 2979, [1, 3, 5, 5, 5, 63, 67, 205, 321, 729, 399, 3649, 4045]),
+    (13, 2985, [1, 3, 7, 3, 27, 53, 5, 175, 219, 597, 1643, 3129, 2225]),
+    (13, 3000, [1, 3, 1, 9, 5, 59, 119, 157, 311, 3, 851, 3367, 5269]),
+    (13, 3003, [1, 3, 3, 15, 15, 19, 7, 117, 331, 853, 387, 1017, 4277]),
+    (13, 3013, [1, 3, 1, 15, 9, 33, 77, 9, 473, 647, 1783, 2911, 5153]),
+    (13, 3018, [1, 1, 3, 3, 7, 45, 99, 13, 175, 401, 1711, 81, 5449]),
+    (13, 3020, [1, 3, 1, 7, 9, 49, 87, 135, 21, 957, 773, 2119, 4583]),
+    (13, 3025, [1, 3, 5, 11, 27, 41, 25, 167, 131, 455, 87, 215, 1139]),
+    (13, 3042, [1, 1, 1, 15, 7, 49, 121, 157, 339, 125, 925, 55, 7269]),
+    (13, 3047, [1, 1, 7, 1, 21, 5, 119, 79, 101, 547, 403, 5, 7075]),
+    (13, 3048, [1, 1, 5, 11, 21, 63, 41, 117, 501, 619, 37, 3587, 6277]),
+    (13, 3051, [1, 1, 5, 11, 27, 31, 1, 5, 121, 319, 1857, 3319, 4657]),
+    (13, 3054, [1, 1, 3, 11, 31, 37, 75, 211, 69, 999, 929, 3551, 6219]),
+    (13, 3056, [1, 1, 5, 7, 11, 15, 105, 205, 399, 879, 1881, 727, 7997]),
+    (13, 3065, [1, 1, 1, 7, 31, 3, 1, 89, 91, 761, 1433, 681, 3807]),
+    (13, 3073, [1, 3, 7, 7, 3, 1, 79, 29, 431, 849, 593, 2825, 5589]),
+    (13, 3074, [1, 1, 1, 1, 19, 35, 117, 123, 15, 411, 1199, 2485, 2827]),
+    (13, 3083, [1, 3, 7, 7, 7, 17, 31, 73, 3, 473, 705, 2545, 6941]),
+    (13, 3086, [1, 1, 5, 9, 29, 21, 21, 183, 135, 423, 1905, 445, 1987]),
+    (13, 3091, [1, 1, 7, 3, 19, 57, 43, 27, 195, 197, 1573, 2409, 7189]),
+    (13, 3097, [1, 3, 5, 9, 3, 15, 55, 17, 483, 427, 1565, 3359, 1307]),
+    (13, 3109, [1, 3, 1, 15, 25, 21, 79, 127, 17, 871, 633, 393, 2567]),
+    (13, 3116, [1, 3, 3, 9, 7, 51, 81, 93, 237, 205, 1275, 685, 4041]),
+    (13, 3124, [1, 1, 3, 9, 31, 33, 19, 191, 249, 857, 981, 3493, 809]),
+    (13, 3128, [1, 1, 7, 5, 11, 43, 59, 165, 449, 457, 1705, 2703, 6639]),
+    (13, 3153, [1, 3, 7, 5, 11, 33, 107, 241, 53, 791, 445, 2959, 1613]),
+    (13, 3160, [1, 1, 1, 13, 17, 31, 111, 57, 261, 1005, 1589, 2221, 7175]),
+    (13, 3165, [1, 1, 3, 1, 9, 39, 67, 123, 227, 833, 1591, 2291, 2187]),
+    (13, 3172, [1, 1, 7, 7, 27, 19, 71, 207, 501, 189, 23, 141, 7589]),
+    (13, 3175, [1, 3, 5, 15, 25, 5, 81, 185, 189, 641, 829, 2917, 8007]),
+    (13, 3184, [1, 1, 7, 13, 3, 63, 47, 135, 295, 341, 1095, 2471, 1955]),
+    (13, 3193, [1, 3, 3, 3, 19, 21, 125, 111, 385, 795, 1601, 1479, 4001]),
+    (13, 3196, [1, 3, 3, 13, 23, 13, 31, 135, 67, 723, 875, 2291, 4737]),
+    (13, 3200, [1, 3, 1, 15, 1, 9, 1, 99, 333, 991, 1497, 1047, 5361]),
+    (13, 3203, [1, 3, 5, 5, 21, 29, 41, 87, 163, 157, 1153, 3385, 6945]),
+    (13, 3205, [1, 3, 1, 9, 1, 19, 51, 193, 85, 133, 1099, 37, 7177]),
+    (13, 3209, [1, 1, 3, 1, 5, 9, 53, 215, 161, 443, 1605, 2773, 4621]),
+    (13, 3224, [1, 1, 1, 3, 29, 3, 3, 249, 325, 737, 1381, 2879, 5697]),
+    (13, 3239, [1, 3, 5, 11, 23, 49, 29, 237, 185, 265, 341, 3869, 5539]),
+    (13, 3251, [1, 1, 5, 9, 29, 5, 41, 29, 15, 27, 665, 1195, 6391]),
+    (13, 3254, [1, 1, 3, 13, 13, 29, 113, 125, 89, 545, 63, 319, 5917]),
+    (13, 3265, [1, 3, 3, 5, 17, 59, 73, 223, 97, 333, 167, 3975, 6151]),
+    (13, 3266, [1, 3, 7, 7, 13, 43, 9, 29, 227, 567, 817, 4041, 5173]),
+    (13, 3275, [1, 3, 5, 5, 5, 47, 39, 7, 351, 731, 1091, 1059, 2563]),
+    (13, 3280, [1, 1, 7, 3, 13, 43, 9, 213, 349, 955, 1143, 2749, 2311]),
+    (13, 3283, [1, 1, 7, 1, 3, 33, 123, 125, 377, 419, 613, 1395, 6805]),
+    (13, 3286, [1, 1, 3, 3, 13, 33, 85, 77, 177, 989, 1381, 2049, 417]),
+    (13, 3301, [1, 3, 5, 9, 27, 35, 107, 89, 39, 711, 451, 113, 2959]),
+    (13, 3302, [1, 3, 7, 9, 9, 17, 1, 111, 159, 157, 1451, 2163, 4127]),
+    (13, 3305, [1, 3, 5, 9, 1, 13, 67, 245, 435, 159, 1833, 2847, 3385]),
+    (13, 3319, [1, 1, 7, 5, 29, 3, 35, 169, 143, 337, 853, 3017, 8133]),
+    (13, 3323, [1, 3, 3, 5, 25, 1, 11, 215, 245, 703, 913, 1457, 3853]),
+    (13, 3326, [1, 1, 3, 13, 11, 55, 51, 47, 505, 317, 1121, 439, 2713]),
+    (13, 3331, [1, 3, 3, 11, 19, 19, 41, 95, 211, 437, 571, 3171, 7273]),
+    (13, 3348, [1, 1, 1, 1, 5, 9, 65, 213, 95, 261, 1553, 2743, 7623]),
+    (13, 3351, [1, 3, 7, 7, 7, 23, 111, 115, 289, 521, 153, 2375, 2269]),
+    (13, 3358, [1, 3, 5, 1, 5, 15, 101, 3, 13, 307, 999, 37, 4691]),
+    (13, 3368, [1, 3, 7, 9, 3, 59, 31, 67, 203, 253, 1893, 889, 4721]),
+    (13, 3374, [1, 1, 1, 13, 19, 31, 69, 163, 161, 711, 1659, 1839, 8189]),
+    (13, 3376, [1, 1, 1, 7, 17, 25, 125, 155, 445, 283, 929, 3179, 7037]),
+    (13, 3379, [1, 1, 7, 7, 3, 21, 67, 135, 483, 853, 1057, 1569, 6351]),
+    (13, 3385, [1, 1, 5, 1, 31, 31, 79, 47, 181, 583, 101, 167, 6411]),
+    (13, 3386, [1, 1, 1, 15, 27, 43, 111, 41, 435, 443, 415, 1885, 4755]),
+    (13, 3396, [1, 3, 7, 7, 9, 43, 49, 175, 55, 767, 1133, 2031, 5263]),
+    (13, 3420, [1, 1, 1, 7, 25, 61, 127, 89, 121, 925, 1125, 1891, 4703]),
+    (13, 3423, [1, 1, 7, 7, 15, 51, 5, 159, 9, 7, 753, 2765, 6743]),
+    (13, 3430, [1, 3, 7, 13, 9, 3, 1, 37, 103, 221, 1577, 2727, 4319]),
+    (13, 3433, [1, 3, 1, 15, 19, 57, 95, 179, 345, 331, 989, 4027, 2261]),
+    (13, 3434, [1, 1, 5, 11, 29, 5, 97, 81, 355, 109, 1471, 3097, 8045]),
+    (13, 3439, [1, 1, 7, 3, 1, 57, 33, 87, 53, 3, 369, 3731, 6381]),
+    (13, 3442, [1, 3, 5, 9, 17, 21, 45, 19, 453, 341, 1343, 1453, 6721]),
+    (13, 3444, [1, 3, 1, 5, 17, 35, 87, 97, 19, 1005, 923, 2041, 3715]),
+    (13, 3453, [1, 1, 3, 7, 9, 27, 103, 9, 275, 317, 1245, 2381, 2977]),
+    (13, 3464, [1, 1, 3, 13, 11, 41, 119, 121, 143, 417, 1583, 3035, 1401]),
+    (13, 3477, [1, 3, 1, 13, 25, 19, 55, 207, 317, 651, 1993, 2895, 2691]),
+    (13, 3478, [1, 3, 1, 5, 17, 41, 21, 137, 455, 949, 747, 3659, 5597]),
+    (13, 3482, [1, 1, 7, 9, 19, 63, 85, 243, 477, 667, 1337, 2757, 2049]),
+    (13, 3487, [1, 3, 5, 9, 21, 17, 71, 99, 387, 831, 1567, 2993, 1847]),
+    (13, 3497, [1, 1, 5, 15, 21, 61, 41, 207, 133, 697, 767, 1685, 2835]),
+    (13, 3500, [1, 1, 1, 5, 1, 23, 1, 111, 215, 383, 385, 3011, 593]),
+    (13, 3505, [1, 3, 3, 3, 9, 35, 31, 115, 199, 869, 1841, 47, 7871]),
+    (13, 3506, [1, 3, 1, 1, 25, 9, 77, 167, 409, 193, 1131, 2393, 723]),
+    (13, 3511, [1, 1, 5, 13, 7, 49, 85, 205, 289, 307, 1807, 2273, 4691]),
+    (13, 3512, [1, 1, 3, 9, 1, 1, 103, 3, 29, 97, 173, 3235, 6933]),
+    (13, 3515, [1, 3, 1, 13, 9, 15, 7, 141, 403, 13, 1495, 2605, 7969]),
+    (13, 3525, [1, 3, 3, 13, 23, 39, 53, 15, 187, 273, 51, 1771, 1261]),
+    (13, 3532, [1, 1, 3, 11, 1, 11, 37, 115, 189, 767, 601, 1519, 1957]),
+    (13, 3538, [1, 3, 5, 1, 25, 1, 53, 243, 411, 793, 1651, 2391, 4929]),
+];
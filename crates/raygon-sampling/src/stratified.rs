@@ -0,0 +1,99 @@
+use crate::hash::{pixel_dimension_hash, sample_rng};
+use crate::rng::{permutation_element, Pcg32, ONE_MINUS_EPSILON};
+use crate::sampler::{Pixel, Sampler};
+
+/// Jittered samples, stratified over an `x_samples` by `y_samples` grid
+///
+/// Each dimension visits the strata of a pixel in a different random order, so dimensions are
+/// decorrelated while every sample of a pixel lands in a distinct stratum. 1D dimensions are
+/// stratified into `x_samples * y_samples` intervals.
+#[derive(Debug, Clone)]
+pub struct StratifiedSampler {
+    x_samples: u32,
+    y_samples: u32,
+    jitter: bool,
+    seed: u32,
+    rng: Pcg32,
+    pixel: Pixel,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl StratifiedSampler {
+    /// Creates a sampler with `x_samples * y_samples` samples per pixel.
+    ///
+    /// Without jitter, samples are placed at the center of each stratum.
+    pub fn new(x_samples: u32, y_samples: u32, jitter: bool, seed: u32) -> StratifiedSampler {
+        assert!(x_samples > 0 && y_samples > 0);
+
+        StratifiedSampler {
+            x_samples,
+            y_samples,
+            jitter,
+            seed,
+            rng: Pcg32::default(),
+            pixel: (0, 0),
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    #[inline]
+    fn stratum(&self) -> u32 {
+        let hash = pixel_dimension_hash(self.seed, self.pixel, self.dimension);
+
+        permutation_element(self.sample_index, self.samples_per_pixel(), hash)
+    }
+
+    #[inline]
+    fn jitter(&mut self) -> f32 {
+        if self.jitter {
+            self.rng.next_f32()
+        } else {
+            0.5
+        }
+    }
+}
+
+impl Sampler for StratifiedSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.x_samples * self.y_samples
+    }
+
+    fn start_pixel_sample_at(&mut self, pixel: Pixel, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+        self.rng = sample_rng(self.seed, pixel, sample_index, dimension);
+    }
+
+    fn dimension(&self) -> u32 {
+        self.dimension
+    }
+
+    fn next_1d(&mut self) -> f32 {
+        let stratum = self.stratum();
+        self.dimension += 1;
+
+        let delta = self.jitter();
+
+        ((stratum as f32 + delta) / self.samples_per_pixel() as f32).min(ONE_MINUS_EPSILON)
+    }
+
+    fn next_2d(&mut self) -> (f32, f32) {
+        let stratum = self.stratum();
+        self.dimension += 2;
+
+        let (x, y) = (stratum % self.x_samples, stratum / self.x_samples);
+        let (dx, dy) = (self.jitter(), self.jitter());
+
+        (
+            ((x as f32 + dx) / self.x_samples as f32).min(ONE_MINUS_EPSILON),
+            ((y as f32 + dy) / self.y_samples as f32).min(ONE_MINUS_EPSILON),
+        )
+    }
+
+    fn clone_for_thread(&self, _thread_index: u32) -> Box<dyn Sampler> {
+        Box::new(self.clone())
+    }
+}
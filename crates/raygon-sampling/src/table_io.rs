@@ -0,0 +1,41 @@
+//! Little-endian binary encoding shared by the precomputed sample tables
+
+use std::io::{self, Read, Write};
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_owned())
+}
+
+pub(crate) fn write_header<W: Write>(writer: &mut W, magic: &[u8; 4], fields: &[u32]) -> io::Result<()> {
+    writer.write_all(magic)?;
+    fields.iter().try_for_each(|&field| write_u32(writer, field))
+}
+
+/// Reads a header written by `write_header`, failing if the magic bytes do not match
+pub(crate) fn read_header<R: Read, const N: usize>(reader: &mut R, magic: &[u8; 4]) -> io::Result<[u32; N]> {
+    let mut actual = [0; 4];
+    reader.read_exact(&mut actual)?;
+
+    if &actual != magic {
+        return Err(invalid_data("unrecognized sample table format"));
+    }
+
+    let mut fields = [0; N];
+    for field in &mut fields {
+        *field = read_u32(reader)?;
+    }
+
+    Ok(fields)
+}
+
+#[inline]
+pub(crate) fn write_u32<W: Write>(writer: &mut W, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+#[inline]
+pub(crate) fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
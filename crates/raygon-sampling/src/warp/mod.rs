@@ -0,0 +1,7 @@
+//! Warping functions from the unit square to common sampling domains
+//!
+//! Every warp is paired with its density with respect to the measure of its domain,
+//! and with its inverse, which maps points of the domain back to the unit square.
+
+pub mod scalar;
+pub mod soa;
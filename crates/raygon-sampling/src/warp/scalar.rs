@@ -0,0 +1,331 @@
+use std::f32::consts::{FRAC_1_PI, FRAC_PI_2, FRAC_PI_4, PI};
+
+use crate::geometry::scalar::Vector3;
+use crate::math::scalar::*;
+
+const INV_2_PI: f32 = 0.5 * FRAC_1_PI;
+const INV_4_PI: f32 = 0.25 * FRAC_1_PI;
+
+#[inline(always)]
+fn safe_sqrt(x: f32) -> f32 {
+    x.max(0.0).sqrt()
+}
+
+#[inline(always)]
+fn safe_asin(x: f32) -> f32 {
+    x.clamp(-1.0, 1.0).asin()
+}
+
+/// Azimuthal angle of `(x, y)` in `[0, 2pi)`, mapped back to `[0, 1)`
+#[inline(always)]
+fn invert_phi(x: f32, y: f32) -> f32 {
+    let phi = y.atan2(x);
+    (if phi < 0.0 { phi + 2.0 * PI } else { phi }) * INV_2_PI
+}
+
+/// Angle between two unit vectors, accurate even when they are nearly parallel
+#[inline]
+fn angle_between(a: Vector3, b: Vector3) -> f32 {
+    if a.dot(&b) < 0.0 {
+        PI - 2.0 * safe_asin((a + b).norm() * 0.5)
+    } else {
+        2.0 * safe_asin((b - a).norm() * 0.5)
+    }
+}
+
+/// Component of `v` perpendicular to the unit vector `w`
+#[inline(always)]
+fn gram_schmidt(v: Vector3, w: Vector3) -> Vector3 {
+    v - w * Vector3::diag(v.dot(&w))
+}
+
+/// Maps the unit square to the unit disk with Shirley and Chiu's concentric mapping
+#[inline]
+pub fn sample_uniform_disk_concentric(u: (f32, f32)) -> (f32, f32) {
+    let (ox, oy) = (2.0 * u.0 - 1.0, 2.0 * u.1 - 1.0);
+
+    if ox == 0.0 && oy == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let (r, theta) = if ox.abs() > oy.abs() {
+        (ox, FRAC_PI_4 * (oy / ox))
+    } else {
+        (oy, FRAC_PI_2 - FRAC_PI_4 * (ox / oy))
+    };
+
+    let (sin, cos) = theta.sin_cos();
+    (r * cos, r * sin)
+}
+
+#[inline(always)]
+pub fn uniform_disk_pdf() -> f32 {
+    FRAC_1_PI
+}
+
+/// Inverse of `sample_uniform_disk_concentric`
+#[inline]
+pub fn invert_uniform_disk_concentric(p: (f32, f32)) -> (f32, f32) {
+    let (x, y) = p;
+    let theta = y.atan2(x);
+    let r = (x * x + y * y).sqrt();
+
+    let (ox, oy) = if theta.abs() < FRAC_PI_4 || theta.abs() > 3.0 * FRAC_PI_4 {
+        let r = r.copysign(x);
+
+        let theta = if x >= 0.0 {
+            theta
+        } else if y < 0.0 {
+            theta + PI
+        } else {
+            theta - PI
+        };
+
+        (r, theta * r / FRAC_PI_4)
+    } else {
+        let r = r.copysign(y);
+
+        let theta = if y < 0.0 { -FRAC_PI_2 - theta } else { FRAC_PI_2 - theta };
+
+        (theta * r / FRAC_PI_4, r)
+    };
+
+    ((ox + 1.0) * 0.5, (oy + 1.0) * 0.5)
+}
+
+/// Uniformly distributed direction on the hemisphere about `+z`
+#[inline]
+pub fn sample_uniform_hemisphere(u: (f32, f32)) -> Vector3 {
+    let z = u.0;
+    let r = safe_sqrt(1.0 - z * z);
+    let (sin, cos) = (2.0 * PI * u.1).sin_cos();
+
+    Vector3::new(r * cos, r * sin, z)
+}
+
+#[inline(always)]
+pub fn uniform_hemisphere_pdf() -> f32 {
+    INV_2_PI
+}
+
+/// Inverse of `sample_uniform_hemisphere`
+#[inline]
+pub fn invert_uniform_hemisphere(w: Vector3) -> (f32, f32) {
+    (w.z, invert_phi(w.x, w.y))
+}
+
+/// Uniformly distributed direction on the unit sphere
+#[inline]
+pub fn sample_uniform_sphere(u: (f32, f32)) -> Vector3 {
+    let z = 1.0 - 2.0 * u.0;
+    let r = safe_sqrt(1.0 - z * z);
+    let (sin, cos) = (2.0 * PI * u.1).sin_cos();
+
+    Vector3::new(r * cos, r * sin, z)
+}
+
+#[inline(always)]
+pub fn uniform_sphere_pdf() -> f32 {
+    INV_4_PI
+}
+
+/// Inverse of `sample_uniform_sphere`
+#[inline]
+pub fn invert_uniform_sphere(w: Vector3) -> (f32, f32) {
+    ((1.0 - w.z) * 0.5, invert_phi(w.x, w.y))
+}
+
+/// Cosine-weighted direction on the hemisphere about `+z`, by projecting a concentric disk sample up (Malley's method)
+#[inline]
+pub fn sample_cosine_hemisphere(u: (f32, f32)) -> Vector3 {
+    let (x, y) = sample_uniform_disk_concentric(u);
+    Vector3::new(x, y, safe_sqrt(1.0 - x * x - y * y))
+}
+
+#[inline(always)]
+pub fn cosine_hemisphere_pdf(cos_theta: f32) -> f32 {
+    cos_theta * FRAC_1_PI
+}
+
+/// Inverse of `sample_cosine_hemisphere`
+#[inline]
+pub fn invert_cosine_hemisphere(w: Vector3) -> (f32, f32) {
+    invert_uniform_disk_concentric((w.x, w.y))
+}
+
+/// Uniformly distributed direction within the cone of half-angle `acos(cos_theta_max)` about `+z`
+#[inline]
+pub fn sample_uniform_cone(u: (f32, f32), cos_theta_max: f32) -> Vector3 {
+    let cos_theta = (1.0 - u.0) + u.0 * cos_theta_max;
+    let sin_theta = safe_sqrt(1.0 - cos_theta * cos_theta);
+    let (sin, cos) = (2.0 * PI * u.1).sin_cos();
+
+    Vector3::new(sin_theta * cos, sin_theta * sin, cos_theta)
+}
+
+#[inline(always)]
+pub fn uniform_cone_pdf(cos_theta_max: f32) -> f32 {
+    INV_2_PI / (1.0 - cos_theta_max)
+}
+
+/// Inverse of `sample_uniform_cone`
+#[inline]
+pub fn invert_uniform_cone(w: Vector3, cos_theta_max: f32) -> (f32, f32) {
+    ((1.0 - w.z) / (1.0 - cos_theta_max), invert_phi(w.x, w.y))
+}
+
+/// Barycentric coordinates of a uniformly distributed point on a triangle
+///
+/// Uses Heitz's low-distortion mapping, which unlike the usual square root mapping keeps nearby samples nearby.
+#[inline]
+pub fn sample_uniform_triangle(u: (f32, f32)) -> [f32; 3] {
+    let (b0, b1) = if u.0 < u.1 {
+        let b0 = u.0 * 0.5;
+        (b0, u.1 - b0)
+    } else {
+        let b1 = u.1 * 0.5;
+        (u.0 - b1, b1)
+    };
+
+    [b0, b1, 1.0 - b0 - b1]
+}
+
+/// Density of `sample_uniform_triangle` with respect to area on a triangle of area `area`
+#[inline(always)]
+pub fn uniform_triangle_pdf(area: f32) -> f32 {
+    1.0 / area
+}
+
+/// Inverse of `sample_uniform_triangle`
+#[inline]
+pub fn invert_uniform_triangle(b: [f32; 3]) -> (f32, f32) {
+    if b[0] < b[1] {
+        (2.0 * b[0], b[0] + b[1])
+    } else {
+        (b[0] + b[1], 2.0 * b[1])
+    }
+}
+
+/// Normalized directions to the vertices, the great circle normals of the edges and the interior angles
+struct SphericalTriangle {
+    a: Vector3,
+    b: Vector3,
+    c: Vector3,
+    n_ab: Vector3,
+    alpha: f32,
+    area: f32,
+}
+
+impl SphericalTriangle {
+    fn new(v: [Vector3; 3]) -> Option<SphericalTriangle> {
+        let (a, b, c) = (v[0].normalize(), v[1].normalize(), v[2].normalize());
+
+        let (n_ab, n_bc, n_ca) = (a.cross(&b), b.cross(&c), c.cross(&a));
+
+        if n_ab.norm_squared() == 0.0 || n_bc.norm_squared() == 0.0 || n_ca.norm_squared() == 0.0 {
+            return None;
+        }
+
+        let (n_ab, n_bc, n_ca) = (n_ab.normalize(), n_bc.normalize(), n_ca.normalize());
+
+        let alpha = angle_between(n_ab, -n_ca);
+        let beta = angle_between(n_bc, -n_ab);
+        let gamma = angle_between(n_ca, -n_bc);
+
+        // Girard's theorem
+        let area = alpha + beta + gamma - PI;
+
+        if area.is_nan() || area <= 0.0 {
+            return None;
+        }
+
+        Some(SphericalTriangle {
+            a,
+            b,
+            c,
+            n_ab,
+            alpha,
+            area,
+        })
+    }
+}
+
+/// Solid angle subtended by the triangle with vertices `v`, relative to the origin
+#[inline]
+pub fn spherical_triangle_area(v: [Vector3; 3]) -> f32 {
+    SphericalTriangle::new(v).map_or(0.0, |t| t.area)
+}
+
+/// Uniformly distributed direction within the solid angle subtended by the triangle with vertices `v`,
+/// relative to the origin, and its density. Uses Arvo's method.
+///
+/// Returns `None` if the triangle is degenerate as seen from the origin.
+pub fn sample_spherical_triangle(v: [Vector3; 3], u: (f32, f32)) -> Option<(Vector3, f32)> {
+    let SphericalTriangle { a, b, c, alpha, area, .. } = SphericalTriangle::new(v)?;
+
+    // pick the sub-triangle with area u.0 * area, then the direction within it
+    let area_prime = u.0 * area + PI;
+
+    let (sin_alpha, cos_alpha) = alpha.sin_cos();
+    let (sin_ap, cos_ap) = area_prime.sin_cos();
+
+    let sin_phi = sin_ap * cos_alpha - cos_ap * sin_alpha;
+    let cos_phi = cos_ap * cos_alpha + sin_ap * sin_alpha;
+
+    let k1 = cos_phi + cos_alpha;
+    let k2 = sin_phi - sin_alpha * a.dot(&b);
+
+    let cos_bp =
+        (k2 + difference_of_products(k2, cos_phi, k1, sin_phi) * cos_alpha) / (sum_of_products(k2, sin_phi, k1, cos_phi) * sin_alpha);
+    let cos_bp = cos_bp.clamp(-1.0, 1.0);
+    let sin_bp = safe_sqrt(1.0 - cos_bp * cos_bp);
+
+    let cp = a * Vector3::diag(cos_bp) + gram_schmidt(c, a).normalize() * Vector3::diag(sin_bp);
+
+    let cos_theta = 1.0 - u.1 * (1.0 - cp.dot(&b));
+    let sin_theta = safe_sqrt(1.0 - cos_theta * cos_theta);
+
+    let w = b * Vector3::diag(cos_theta) + gram_schmidt(cp, b).normalize() * Vector3::diag(sin_theta);
+
+    Some((w, 1.0 / area))
+}
+
+/// Inverse of `sample_spherical_triangle`
+pub fn invert_spherical_triangle(v: [Vector3; 3], w: Vector3) -> (f32, f32) {
+    let SphericalTriangle {
+        a,
+        b,
+        c,
+        n_ab,
+        alpha,
+        area,
+    } = match SphericalTriangle::new(v) {
+        Some(t) => t,
+        None => return (0.5, 0.5),
+    };
+
+    // where the arc from b through w meets the edge ca
+    let mut cp = b.cross(&w).cross(&c.cross(&a)).normalize();
+    if cp.dot(&(a + c)) < 0.0 {
+        cp = -cp;
+    }
+
+    let u0 = if a.dot(&cp) > 0.999_998_5 {
+        0.0
+    } else {
+        let (n_cpb, n_acp) = (cp.cross(&b), a.cross(&cp));
+
+        if n_cpb.norm_squared() == 0.0 || n_acp.norm_squared() == 0.0 {
+            return (0.5, 0.5);
+        }
+
+        let (n_cpb, n_acp) = (n_cpb.normalize(), n_acp.normalize());
+        let area_prime = alpha + angle_between(n_ab, n_cpb) + angle_between(n_acp, -n_cpb) - PI;
+
+        area_prime / area
+    };
+
+    let u1 = (1.0 - w.dot(&b)) / (1.0 - cp.dot(&b));
+
+    (u0.clamp(0.0, 1.0), u1.clamp(0.0, 1.0))
+}
@@ -0,0 +1,499 @@
+use std::f32::consts::{FRAC_1_PI, FRAC_PI_2, FRAC_PI_4, PI};
+
+use thermite::*;
+
+use crate::geometry::soa::{tangent::TangentFrame, Vector3};
+use crate::math::soa::*;
+
+pub use warps::*;
+
+#[dispatch]
+mod warps {
+    use super::*;
+
+    const INV_2_PI: f32 = 0.5 * FRAC_1_PI;
+    const INV_4_PI: f32 = 0.25 * FRAC_1_PI;
+
+    #[inline(always)]
+    fn safe_sqrt<S: Simd>(x: Vf32<S>) -> Vf32<S> {
+        x.max(Vf32::<S>::zero()).sqrt()
+    }
+
+    #[inline(always)]
+    fn safe_asin<S: Simd>(x: Vf32<S>) -> Vf32<S> {
+        x.max(Vf32::<S>::neg_one()).min(Vf32::<S>::one()).asin()
+    }
+
+    #[inline(always)]
+    fn invert_phi<S: Simd>(x: Vf32<S>, y: Vf32<S>) -> Vf32<S> {
+        let phi = y.atan2(x);
+        let phi = phi.lt(Vf32::<S>::zero()).select(phi + Vf32::<S>::splat(2.0 * PI), phi);
+
+        phi * Vf32::<S>::splat(INV_2_PI)
+    }
+
+    #[inline(always)]
+    fn angle_between<S: Simd>(a: Vector3<S>, b: Vector3<S>) -> Vf32<S> {
+        let half = Vf32::<S>::splat(0.5);
+        let two = Vf32::<S>::splat(2.0);
+
+        let obtuse = Vf32::<S>::splat(PI) - two * safe_asin::<S>((a + b).norm() * half);
+        let acute = two * safe_asin::<S>((b - a).norm() * half);
+
+        a.dot(&b).lt(Vf32::<S>::zero()).select(obtuse, acute)
+    }
+
+    #[inline(always)]
+    fn gram_schmidt<S: Simd>(v: Vector3<S>, w: Vector3<S>) -> Vector3<S> {
+        v - w * Vector3::diag(v.dot(&w))
+    }
+
+    /// Lane-wise `scalar::sample_uniform_disk_concentric`
+    #[inline]
+    pub fn sample_uniform_disk_concentric<S: Simd>(u: (Vf32<S>, Vf32<S>)) -> (Vf32<S>, Vf32<S>) {
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+        let two = Vf32::<S>::splat(2.0);
+        let frac_pi_4 = Vf32::<S>::splat(FRAC_PI_4);
+
+        let (ox, oy) = (u.0.mul_sub(two, one), u.1.mul_sub(two, one));
+
+        let x_major = ox.abs().gt(oy.abs());
+
+        let r = x_major.select(ox, oy);
+        let theta = x_major.select(frac_pi_4 * (oy / ox), frac_pi_4.nmul_add(ox / oy, Vf32::<S>::splat(FRAC_PI_2)));
+
+        let (sin, cos) = theta.sin_cos();
+
+        // the center maps to itself, avoiding 0/0
+        let center = ox.eq(zero) & oy.eq(zero);
+
+        (center.select(zero, r * cos), center.select(zero, r * sin))
+    }
+
+    #[inline(always)]
+    pub fn uniform_disk_pdf<S: Simd>() -> Vf32<S> {
+        Vf32::<S>::splat(FRAC_1_PI)
+    }
+
+    /// Lane-wise `scalar::invert_uniform_disk_concentric`
+    #[inline]
+    pub fn invert_uniform_disk_concentric<S: Simd>(p: (Vf32<S>, Vf32<S>)) -> (Vf32<S>, Vf32<S>) {
+        let (x, y) = p;
+        let half = Vf32::<S>::splat(0.5);
+        let pi = Vf32::<S>::splat(PI);
+        let frac_pi_2 = Vf32::<S>::splat(FRAC_PI_2);
+        let inv_frac_pi_4 = Vf32::<S>::splat(1.0 / FRAC_PI_4);
+
+        let theta = y.atan2(x);
+        let r = x.mul_add(x, y * y).sqrt();
+
+        let abs_theta = theta.abs();
+        let x_major = abs_theta.lt(Vf32::<S>::splat(FRAC_PI_4)) | abs_theta.gt(Vf32::<S>::splat(3.0 * FRAC_PI_4));
+
+        // |x| > |y|, so x holds the radius
+        let rx = r.copysign(x);
+        let theta_x = x
+            .lt(Vf32::<S>::zero())
+            .select(y.lt(Vf32::<S>::zero()).select(theta + pi, theta - pi), theta);
+
+        // otherwise y holds the radius
+        let ry = r.copysign(y);
+        let theta_y = y.lt(Vf32::<S>::zero()).select(-frac_pi_2 - theta, frac_pi_2 - theta);
+
+        let ox = x_major.select(rx, theta_y * ry * inv_frac_pi_4);
+        let oy = x_major.select(theta_x * rx * inv_frac_pi_4, ry);
+
+        ((ox + Vf32::<S>::one()) * half, (oy + Vf32::<S>::one()) * half)
+    }
+
+    /// Lane-wise `scalar::sample_uniform_hemisphere`
+    #[inline]
+    pub fn sample_uniform_hemisphere<S: Simd>(u: (Vf32<S>, Vf32<S>)) -> Vector3<S> {
+        let z = u.0;
+        let r = safe_sqrt::<S>(z.nmul_add(z, Vf32::<S>::one()));
+        let (sin, cos) = (u.1 * Vf32::<S>::splat(2.0 * PI)).sin_cos();
+
+        Vector3 { x: r * cos, y: r * sin, z }
+    }
+
+    #[inline(always)]
+    pub fn uniform_hemisphere_pdf<S: Simd>() -> Vf32<S> {
+        Vf32::<S>::splat(INV_2_PI)
+    }
+
+    /// Lane-wise `scalar::invert_uniform_hemisphere`
+    #[inline]
+    pub fn invert_uniform_hemisphere<S: Simd>(w: Vector3<S>) -> (Vf32<S>, Vf32<S>) {
+        (w.z, invert_phi::<S>(w.x, w.y))
+    }
+
+    /// Lane-wise `scalar::sample_uniform_sphere`
+    #[inline]
+    pub fn sample_uniform_sphere<S: Simd>(u: (Vf32<S>, Vf32<S>)) -> Vector3<S> {
+        let one = Vf32::<S>::one();
+
+        let z = u.0.nmul_add(Vf32::<S>::splat(2.0), one);
+        let r = safe_sqrt::<S>(z.nmul_add(z, one));
+        let (sin, cos) = (u.1 * Vf32::<S>::splat(2.0 * PI)).sin_cos();
+
+        Vector3 { x: r * cos, y: r * sin, z }
+    }
+
+    #[inline(always)]
+    pub fn uniform_sphere_pdf<S: Simd>() -> Vf32<S> {
+        Vf32::<S>::splat(INV_4_PI)
+    }
+
+    /// Lane-wise `scalar::invert_uniform_sphere`
+    #[inline]
+    pub fn invert_uniform_sphere<S: Simd>(w: Vector3<S>) -> (Vf32<S>, Vf32<S>) {
+        ((Vf32::<S>::one() - w.z) * Vf32::<S>::splat(0.5), invert_phi::<S>(w.x, w.y))
+    }
+
+    /// Lane-wise `scalar::sample_cosine_hemisphere`
+    #[inline]
+    pub fn sample_cosine_hemisphere<S: Simd>(u: (Vf32<S>, Vf32<S>)) -> Vector3<S> {
+        let (x, y) = sample_uniform_disk_concentric::<S>(u);
+        let z = safe_sqrt::<S>(x.nmul_add(x, y.nmul_add(y, Vf32::<S>::one())));
+
+        Vector3 { x, y, z }
+    }
+
+    #[inline(always)]
+    pub fn cosine_hemisphere_pdf<S: Simd>(cos_theta: Vf32<S>) -> Vf32<S> {
+        cos_theta * Vf32::<S>::splat(FRAC_1_PI)
+    }
+
+    /// Lane-wise `scalar::invert_cosine_hemisphere`
+    #[inline]
+    pub fn invert_cosine_hemisphere<S: Simd>(w: Vector3<S>) -> (Vf32<S>, Vf32<S>) {
+        invert_uniform_disk_concentric::<S>((w.x, w.y))
+    }
+
+    /// Lane-wise `scalar::sample_uniform_cone`
+    #[inline]
+    pub fn sample_uniform_cone<S: Simd>(u: (Vf32<S>, Vf32<S>), cos_theta_max: Vf32<S>) -> Vector3<S> {
+        let one = Vf32::<S>::one();
+
+        let cos_theta = u.0.mul_add(cos_theta_max - one, one);
+        let sin_theta = safe_sqrt::<S>(cos_theta.nmul_add(cos_theta, one));
+        let (sin, cos) = (u.1 * Vf32::<S>::splat(2.0 * PI)).sin_cos();
+
+        Vector3 {
+            x: sin_theta * cos,
+            y: sin_theta * sin,
+            z: cos_theta,
+        }
+    }
+
+    #[inline(always)]
+    pub fn uniform_cone_pdf<S: Simd>(cos_theta_max: Vf32<S>) -> Vf32<S> {
+        Vf32::<S>::splat(INV_2_PI) / (Vf32::<S>::one() - cos_theta_max)
+    }
+
+    /// Lane-wise `scalar::invert_uniform_cone`
+    #[inline]
+    pub fn invert_uniform_cone<S: Simd>(w: Vector3<S>, cos_theta_max: Vf32<S>) -> (Vf32<S>, Vf32<S>) {
+        let one = Vf32::<S>::one();
+        ((one - w.z) / (one - cos_theta_max), invert_phi::<S>(w.x, w.y))
+    }
+
+    /// Uniformly distributed direction on the hemisphere about the frame's normal
+    #[inline]
+    pub fn sample_uniform_hemisphere_around<S: Simd>(frame: &TangentFrame<S>, u: (Vf32<S>, Vf32<S>)) -> Vector3<S> {
+        frame.to_world(sample_uniform_hemisphere::<S>(u))
+    }
+
+    /// Cosine-weighted direction on the hemisphere about the frame's normal
+    #[inline]
+    pub fn sample_cosine_hemisphere_around<S: Simd>(frame: &TangentFrame<S>, u: (Vf32<S>, Vf32<S>)) -> Vector3<S> {
+        frame.to_world(sample_cosine_hemisphere::<S>(u))
+    }
+
+    /// Uniformly distributed direction within the cone about the frame's normal
+    #[inline]
+    pub fn sample_uniform_cone_around<S: Simd>(frame: &TangentFrame<S>, u: (Vf32<S>, Vf32<S>), cos_theta_max: Vf32<S>) -> Vector3<S> {
+        frame.to_world(sample_uniform_cone::<S>(u, cos_theta_max))
+    }
+
+    /// Lane-wise `scalar::sample_uniform_triangle`
+    #[inline]
+    pub fn sample_uniform_triangle<S: Simd>(u: (Vf32<S>, Vf32<S>)) -> [Vf32<S>; 3] {
+        let half = Vf32::<S>::splat(0.5);
+        let first = u.0.lt(u.1);
+
+        let b0 = first.select(u.0 * half, u.1.nmul_add(half, u.0));
+        let b1 = first.select(u.0.nmul_add(half, u.1), u.1 * half);
+
+        [b0, b1, Vf32::<S>::one() - b0 - b1]
+    }
+
+    #[inline(always)]
+    pub fn uniform_triangle_pdf<S: Simd>(area: Vf32<S>) -> Vf32<S> {
+        Vf32::<S>::one() / area
+    }
+
+    /// Lane-wise `scalar::invert_uniform_triangle`
+    #[inline]
+    pub fn invert_uniform_triangle<S: Simd>(b: [Vf32<S>; 3]) -> (Vf32<S>, Vf32<S>) {
+        let first = b[0].lt(b[1]);
+        let sum = b[0] + b[1];
+
+        (first.select(b[0] + b[0], sum), first.select(sum, b[1] + b[1]))
+    }
+
+    /// Normalized vertex directions, the normal of the edge `ab`, the interior angle at `a`,
+    /// the area, and a mask of degenerate lanes
+    #[inline(always)]
+    fn spherical_triangle<S: Simd>(v: &[Vector3<S>; 3]) -> ([Vector3<S>; 3], Vector3<S>, Vf32<S>, Vf32<S>, Mask<S, Vf32<S>>) {
+        let (a, b, c) = (v[0].normalize(), v[1].normalize(), v[2].normalize());
+
+        let (n_ab, _, zero_ab) = a.cross(&b).normalize_len_mask();
+        let (n_bc, _, zero_bc) = b.cross(&c).normalize_len_mask();
+        let (n_ca, _, zero_ca) = c.cross(&a).normalize_len_mask();
+
+        let alpha = angle_between::<S>(n_ab, -n_ca);
+        let beta = angle_between::<S>(n_bc, -n_ab);
+        let gamma = angle_between::<S>(n_ca, -n_bc);
+
+        // Girard's theorem
+        let area = alpha + beta + gamma - Vf32::<S>::splat(PI);
+
+        // the comparison fails for NaN areas too
+        let degenerate = zero_ab | zero_bc | zero_ca | !area.gt(Vf32::<S>::zero());
+
+        ([a, b, c], n_ab, alpha, area, degenerate)
+    }
+
+    /// Lane-wise `scalar::spherical_triangle_area`
+    #[inline]
+    pub fn spherical_triangle_area<S: Simd>(v: &[Vector3<S>; 3]) -> Vf32<S> {
+        let (_, _, _, area, degenerate) = spherical_triangle::<S>(v);
+        degenerate.select(Vf32::<S>::zero(), area)
+    }
+
+    /// Lane-wise `scalar::sample_spherical_triangle`, returning the direction, its density,
+    /// and a mask of lanes where the triangle is degenerate and the sample is invalid
+    #[inline]
+    pub fn sample_spherical_triangle<S: Simd>(v: &[Vector3<S>; 3], u: (Vf32<S>, Vf32<S>)) -> (Vector3<S>, Vf32<S>, Mask<S, Vf32<S>>) {
+        let one = Vf32::<S>::one();
+
+        let ([a, b, c], _, alpha, area, degenerate) = spherical_triangle::<S>(v);
+
+        let area_prime = u.0.mul_add(area, Vf32::<S>::splat(PI));
+
+        let (sin_alpha, cos_alpha) = alpha.sin_cos();
+        let (sin_ap, cos_ap) = area_prime.sin_cos();
+
+        let sin_phi = difference_of_products::<S>(sin_ap, cos_alpha, cos_ap, sin_alpha);
+        let cos_phi = sum_of_products::<S>(cos_ap, cos_alpha, sin_ap, sin_alpha);
+
+        let k1 = cos_phi + cos_alpha;
+        let k2 = sin_alpha.nmul_add(a.dot(&b), sin_phi);
+
+        let cos_bp = difference_of_products::<S>(k2, cos_phi, k1, sin_phi).mul_add(cos_alpha, k2)
+            / (sum_of_products::<S>(k2, sin_phi, k1, cos_phi) * sin_alpha);
+        let cos_bp = cos_bp.max(Vf32::<S>::neg_one()).min(one);
+        let sin_bp = safe_sqrt::<S>(cos_bp.nmul_add(cos_bp, one));
+
+        let cp = a * Vector3::diag(cos_bp) + gram_schmidt::<S>(c, a).normalize() * Vector3::diag(sin_bp);
+
+        let cos_theta = u.1.nmul_add(one - cp.dot(&b), one);
+        let sin_theta = safe_sqrt::<S>(cos_theta.nmul_add(cos_theta, one));
+
+        let w = b * Vector3::diag(cos_theta) + gram_schmidt::<S>(cp, b).normalize() * Vector3::diag(sin_theta);
+
+        (w, one / area, degenerate)
+    }
+
+    /// Lane-wise `scalar::invert_spherical_triangle`
+    #[inline]
+    pub fn invert_spherical_triangle<S: Simd>(v: &[Vector3<S>; 3], w: Vector3<S>) -> (Vf32<S>, Vf32<S>) {
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+        let half = Vf32::<S>::splat(0.5);
+
+        let ([a, b, c], n_ab, alpha, area, degenerate) = spherical_triangle::<S>(v);
+
+        // where the arc from b through w meets the edge ca
+        let cp = b.cross(&w).cross(&c.cross(&a)).normalize();
+        let cp = cp * Vector3::diag(cp.dot(&(a + c)).lt(zero).select(Vf32::<S>::neg_one(), one));
+
+        let (n_cpb, _, zero_cpb) = cp.cross(&b).normalize_len_mask();
+        let (n_acp, _, zero_acp) = a.cross(&cp).normalize_len_mask();
+
+        let area_prime = alpha + angle_between::<S>(n_ab, n_cpb) + angle_between::<S>(n_acp, -n_cpb) - Vf32::<S>::splat(PI);
+        let u0 = a.dot(&cp).gt(Vf32::<S>::splat(0.999_998_5)).select(zero, area_prime / area);
+
+        let u1 = (one - w.dot(&b)) / (one - cp.dot(&b));
+
+        let invalid = degenerate | zero_cpb | zero_acp;
+
+        (
+            invalid.select(half, u0.max(zero).min(one)),
+            invalid.select(half, u1.max(zero).min(one)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thermite::backends::avx2::AVX2;
+
+    use crate::geometry::scalar;
+    use crate::rng::Pcg32;
+    use crate::warp::scalar as warp;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const LANES: usize = <AVX2 as Simd>::Vf32::NUM_ELEMENTS;
+
+    fn uniform(rng: &mut Pcg32) -> ([f32; LANES], Vf32) {
+        let mut u = [0.0; LANES];
+        u.iter_mut().for_each(|u| *u = rng.next_f32());
+        (u, Vf32::load_unaligned(&u))
+    }
+
+    fn lane(v: &Vector3<AVX2>, lane: usize) -> scalar::Vector3 {
+        scalar::Vector3::new(v.x.extract(lane), v.y.extract(lane), v.z.extract(lane))
+    }
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    fn assert_close3(a: scalar::Vector3, b: scalar::Vector3) {
+        assert_close(a.x, b.x);
+        assert_close(a.y, b.y);
+        assert_close(a.z, b.z);
+    }
+
+    #[test]
+    fn test_disk_matches_scalar() {
+        let mut rng = Pcg32::default();
+
+        for _ in 0..1000 {
+            let ((u0, v0), (u1, v1)) = (uniform(&mut rng), uniform(&mut rng));
+
+            let (x, y) = sample_uniform_disk_concentric::<AVX2>((v0, v1));
+            let (i0, i1) = invert_uniform_disk_concentric::<AVX2>((x, y));
+
+            for l in 0..LANES {
+                let p = warp::sample_uniform_disk_concentric((u0[l], u1[l]));
+                assert_close(x.extract(l), p.0);
+                assert_close(y.extract(l), p.1);
+
+                let i = warp::invert_uniform_disk_concentric(p);
+                assert_close(i0.extract(l), i.0);
+                assert_close(i1.extract(l), i.1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_directions_match_scalar() {
+        let mut rng = Pcg32::default();
+        let cos_theta_max = Vf32::splat(0.7);
+
+        for _ in 0..1000 {
+            let ((u0, v0), (u1, v1)) = (uniform(&mut rng), uniform(&mut rng));
+            let u = (v0, v1);
+
+            let hemisphere = sample_uniform_hemisphere::<AVX2>(u);
+            let sphere = sample_uniform_sphere::<AVX2>(u);
+            let cosine = sample_cosine_hemisphere::<AVX2>(u);
+            let cone = sample_uniform_cone::<AVX2>(u, cos_theta_max);
+
+            let inverses = [
+                invert_uniform_hemisphere::<AVX2>(hemisphere),
+                invert_uniform_sphere::<AVX2>(sphere),
+                invert_cosine_hemisphere::<AVX2>(cosine),
+                invert_uniform_cone::<AVX2>(cone, cos_theta_max),
+            ];
+
+            for l in 0..LANES {
+                let u = (u0[l], u1[l]);
+
+                assert_close3(lane(&hemisphere, l), warp::sample_uniform_hemisphere(u));
+                assert_close3(lane(&sphere, l), warp::sample_uniform_sphere(u));
+                assert_close3(lane(&cosine, l), warp::sample_cosine_hemisphere(u));
+                assert_close3(lane(&cone, l), warp::sample_uniform_cone(u, 0.7));
+
+                // phi wraps around at u.1 = 0
+                for &(i0, i1) in &inverses {
+                    assert_close(i0.extract(l), u.0);
+                    let d = (i1.extract(l) - u.1).abs();
+                    assert!(d < 1e-4 || d > 1.0 - 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangle_matches_scalar() {
+        let mut rng = Pcg32::default();
+
+        for _ in 0..1000 {
+            let ((u0, v0), (u1, v1)) = (uniform(&mut rng), uniform(&mut rng));
+
+            let b = sample_uniform_triangle::<AVX2>((v0, v1));
+            let (i0, i1) = invert_uniform_triangle::<AVX2>(b);
+
+            for l in 0..LANES {
+                let s = warp::sample_uniform_triangle((u0[l], u1[l]));
+
+                for k in 0..3 {
+                    assert_close(b[k].extract(l), s[k]);
+                }
+
+                assert_close(i0.extract(l), u0[l]);
+                assert_close(i1.extract(l), u1[l]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spherical_triangle_matches_scalar() {
+        let mut rng = Pcg32::default();
+
+        let vertex = |x: f32, y: f32, z: f32| Vector3::<AVX2> {
+            x: Vf32::splat(x),
+            y: Vf32::splat(y),
+            z: Vf32::splat(z),
+        };
+
+        let v = [vertex(1.0, 0.2, 0.1), vertex(0.1, 1.0, 0.3), vertex(0.2, -0.1, 1.0)];
+        let s = [
+            scalar::Vector3::new(1.0, 0.2, 0.1),
+            scalar::Vector3::new(0.1, 1.0, 0.3),
+            scalar::Vector3::new(0.2, -0.1, 1.0),
+        ];
+
+        assert_close(spherical_triangle_area::<AVX2>(&v).extract(0), warp::spherical_triangle_area(s));
+
+        for _ in 0..1000 {
+            let ((u0, v0), (u1, v1)) = (uniform(&mut rng), uniform(&mut rng));
+
+            let (w, pdf, degenerate) = sample_spherical_triangle::<AVX2>(&v, (v0, v1));
+            assert!(!degenerate.any());
+
+            let (i0, i1) = invert_spherical_triangle::<AVX2>(&v, w);
+
+            for l in 0..LANES {
+                let (sw, spdf) = warp::sample_spherical_triangle(s, (u0[l], u1[l])).unwrap();
+
+                assert_close3(lane(&w, l), sw);
+                assert_close(pdf.extract(l), spdf);
+
+                assert!((i0.extract(l) - u0[l]).abs() < 1e-3);
+                assert!((i1.extract(l) - u1[l]).abs() < 1e-3);
+            }
+        }
+
+        let flat = [vertex(1.0, 0.0, 0.0), vertex(2.0, 0.0, 0.0), vertex(0.0, 0.0, 1.0)];
+        let (_, _, degenerate) = sample_spherical_triangle::<AVX2>(&flat, (Vf32::splat(0.5), Vf32::splat(0.5)));
+        assert!(degenerate.all());
+    }
+}
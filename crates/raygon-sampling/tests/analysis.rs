@@ -0,0 +1,226 @@
+use std::f32::consts::PI;
+
+use raygon_geometry::scalar::Vector3;
+use raygon_sampling::analysis::*;
+use raygon_sampling::rng::Pcg32;
+use raygon_sampling::warp::scalar::*;
+use raygon_sampling::*;
+
+/// Maps a direction to `(cos theta, phi / 2pi)`, where the solid angle measure is `2pi` times the area
+fn direction_to_square(w: Vector3) -> (f32, f32) {
+    let phi = w.y.atan2(w.x);
+    let phi = if phi < 0.0 { phi + 2.0 * PI } else { phi };
+
+    (w.z, phi / (2.0 * PI))
+}
+
+fn assert_passes(name: &str, result: ChiSquareResult) {
+    assert!(result.p_value > 0.001, "{}: {:?}", name, result);
+}
+
+#[test]
+fn test_warps_chi_square() {
+    let test = ChiSquareTest::default();
+    let cos_theta_max = 0.6;
+
+    assert_passes(
+        "disk",
+        test.run(
+            |u| {
+                let (x, y) = sample_uniform_disk_concentric(u);
+                let phi = y.atan2(x);
+                Some((x * x + y * y, if phi < 0.0 { phi + 2.0 * PI } else { phi } / (2.0 * PI)))
+            },
+            |_| 1.0,
+        ),
+    );
+
+    assert_passes(
+        "hemisphere",
+        test.run(
+            |u| Some(direction_to_square(sample_uniform_hemisphere(u))),
+            |_| uniform_hemisphere_pdf() * 2.0 * PI,
+        ),
+    );
+
+    assert_passes(
+        "sphere",
+        test.run(
+            |u| {
+                let (z, t) = direction_to_square(sample_uniform_sphere(u));
+                Some(((z + 1.0) * 0.5, t))
+            },
+            |_| uniform_sphere_pdf() * 4.0 * PI,
+        ),
+    );
+
+    assert_passes(
+        "cosine hemisphere",
+        test.run(
+            |u| Some(direction_to_square(sample_cosine_hemisphere(u))),
+            |(z, _)| cosine_hemisphere_pdf(z) * 2.0 * PI,
+        ),
+    );
+
+    assert_passes(
+        "cone",
+        test.run(
+            |u| {
+                let (z, t) = direction_to_square(sample_uniform_cone(u, cos_theta_max));
+                Some(((z - cos_theta_max) / (1.0 - cos_theta_max), t))
+            },
+            |_| uniform_cone_pdf(cos_theta_max) * (1.0 - cos_theta_max) * 2.0 * PI,
+        ),
+    );
+
+    // uniform triangles have uniform 1 - (1 - b0)^2 and b1 / (1 - b0)
+    assert_passes(
+        "triangle",
+        test.run(
+            |u| {
+                let b = sample_uniform_triangle(u);
+                Some((1.0 - (1.0 - b[0]) * (1.0 - b[0]), b[1] / (1.0 - b[0])))
+            },
+            |_| 1.0,
+        ),
+    );
+
+    let octant = [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+
+    assert_passes(
+        "spherical triangle",
+        test.run(
+            |u| {
+                // keep rounding on the edges from wrapping around
+                sample_spherical_triangle(octant, u).map(|(w, _)| {
+                    let (z, t) = direction_to_square(Vector3::new(w.x.max(0.0), w.y.max(0.0), w.z));
+                    (z, t.min(0.249_999))
+                })
+            },
+            |(_, t)| {
+                if t < 0.25 {
+                    2.0 * PI / spherical_triangle_area(octant)
+                } else {
+                    0.0
+                }
+            },
+        ),
+    );
+}
+
+#[test]
+fn test_chi_square_rejects_wrong_density() {
+    let test = ChiSquareTest {
+        samples: 100_000,
+        ..ChiSquareTest::default()
+    };
+
+    let result = test.run(|u| Some(direction_to_square(sample_cosine_hemisphere(u))), |_| 1.0);
+    assert!(result.p_value < 1e-6, "{:?}", result);
+
+    // samples outside the support can't be explained at all
+    let result = test.run(|(x, y)| Some((x, y)), |(x, _)| if x < 0.5 { 2.0 } else { 0.0 });
+    assert_eq!(result.p_value, 0.0);
+}
+
+/// Star discrepancy straight from the definition, checking every corner
+fn brute_force_discrepancy(points: &[(f32, f32)]) -> f64 {
+    let n = points.len() as f64;
+    let xs: Vec<f32> = points.iter().map(|p| p.0).chain(Some(1.0)).collect();
+    let ys: Vec<f32> = points.iter().map(|p| p.1).chain(Some(1.0)).collect();
+
+    let mut discrepancy = 0.0f64;
+
+    for &cx in &xs {
+        for &cy in &ys {
+            let area = cx as f64 * cy as f64;
+            let open = points.iter().filter(|p| p.0 < cx && p.1 < cy).count() as f64;
+            let closed = points.iter().filter(|p| p.0 <= cx && p.1 <= cy).count() as f64;
+
+            discrepancy = discrepancy.max(area - open / n).max(closed / n - area);
+        }
+    }
+
+    discrepancy
+}
+
+#[test]
+fn test_star_discrepancy() {
+    assert!((star_discrepancy(&[(0.5, 0.5)]) - 0.75).abs() < 1e-12);
+
+    let mut rng = Pcg32::default();
+
+    for n in [1, 2, 7, 50] {
+        let points: Vec<_> = (0..n).map(|_| (rng.next_f32(), rng.next_f32())).collect();
+        assert!((star_discrepancy(&points) - brute_force_discrepancy(&points)).abs() < 1e-9);
+    }
+
+    // ties in both coordinates
+    let grid: Vec<_> = (0..16).map(|i| ((i % 4) as f32 / 4.0, (i / 4) as f32 / 4.0)).collect();
+    assert!((star_discrepancy(&grid) - brute_force_discrepancy(&grid)).abs() < 1e-9);
+
+    let n = 256;
+    let mut independent = IndependentSampler::new(n, 3);
+    let mut sobol = SobolSampler::new(n, Randomization::Owen, 3);
+
+    let random = star_discrepancy(&project(&mut independent, (0, 0), n, (0, 1)));
+    let low_discrepancy = star_discrepancy(&project(&mut sobol, (0, 0), n, (0, 1)));
+
+    assert!(low_discrepancy * 3.0 < random, "{} vs {}", low_discrepancy, random);
+}
+
+#[test]
+fn test_power_spectrum() {
+    let (n, size) = (1024, 32);
+    let mut independent = IndependentSampler::new(n, 5);
+    let mut sobol = SobolSampler::new(n, Randomization::Owen, 5);
+
+    let random = power_spectrum(&project(&mut independent, (2, 1), n, (2, 3)), size);
+    let low_discrepancy = power_spectrum(&project(&mut sobol, (2, 1), n, (2, 3)), size);
+
+    // the zero frequency holds all of the energy
+    let center = size / 2 * size + size / 2;
+    assert!((random[center] - n as f32).abs() < 1e-2 * n as f32);
+
+    // white noise is flat, while low-discrepancy points have little energy at low frequencies
+    let random = radial_average(&random, size);
+    let low_discrepancy = radial_average(&low_discrepancy, size);
+
+    let mean = random[1..].iter().sum::<f32>() / (random.len() - 1) as f32;
+    assert!((mean - 1.0).abs() < 0.1, "{}", mean);
+    assert!(low_discrepancy[1..4].iter().all(|&p| p < 0.5), "{:?}", low_discrepancy);
+}
+
+#[test]
+fn test_image_output() {
+    let mut ppm = Vec::new();
+    write_points_ppm(&mut ppm, &[(0.0, 0.0), (0.99, 0.99)], 4).unwrap();
+
+    let header = b"P6\n4 4\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+
+    let pixels = &ppm[header.len()..];
+    assert_eq!(pixels.len(), 4 * 4 * 3);
+
+    // y points up, so the origin is at the start of the last row
+    assert_eq!(&pixels[12 * 3..12 * 3 + 3], &[0, 0, 0]);
+    assert_eq!(&pixels[3 * 3..3 * 3 + 3], &[0, 0, 0]);
+    assert_eq!(pixels.iter().filter(|&&p| p == 0).count(), 6);
+
+    let mut pfm = Vec::new();
+    write_pfm(&mut pfm, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2).unwrap();
+
+    let header = b"Pf\n3 2\n-1.0\n";
+    assert_eq!(&pfm[..header.len()], header);
+
+    // rows are stored from the bottom
+    let values: Vec<f32> = pfm[header.len()..]
+        .chunks(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+    assert_eq!(values, [4.0, 5.0, 6.0, 1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+use std::thread;
+
+use raygon_sampling::*;
+
+const WIDTH: u32 = 24;
+const HEIGHT: u32 = 16;
+const TILE: u32 = 8;
+const SAMPLES: u32 = 8;
+const VALUES: usize = 6;
+
+/// Sample values of the whole image, in bits so comparisons are exact
+type Image = Vec<u32>;
+
+fn new_image() -> Image {
+    vec![0; (WIDTH * HEIGHT * SAMPLES) as usize * VALUES]
+}
+
+/// Draws the values of one sample the way an integrator would, mixing 1D and 2D dimensions
+fn render_sample(sampler: &mut dyn Sampler, image: &mut Image, pixel: Pixel, sample_index: u32) {
+    sampler.start_pixel_sample(pixel, sample_index);
+
+    let (a, b) = sampler.next_2d();
+    let c = sampler.next_1d();
+    let (d, e) = sampler.next_2d();
+    let f = sampler.next_1d();
+
+    let offset = (((pixel.1 * WIDTH + pixel.0) * SAMPLES + sample_index) as usize) * VALUES;
+    for (value, u) in image[offset..offset + VALUES].iter_mut().zip(&[a, b, c, d, e, f]) {
+        *value = u.to_bits();
+    }
+}
+
+fn tiles() -> Vec<(u32, u32)> {
+    (0..HEIGHT / TILE)
+        .flat_map(|ty| (0..WIDTH / TILE).map(move |tx| (tx, ty)))
+        .collect()
+}
+
+fn render_tile(sampler: &mut dyn Sampler, image: &mut Image, (tx, ty): (u32, u32)) {
+    for y in ty * TILE..(ty + 1) * TILE {
+        for x in tx * TILE..(tx + 1) * TILE {
+            for index in 0..SAMPLES {
+                render_sample(sampler, image, (x, y), index);
+            }
+        }
+    }
+}
+
+fn scanline(sampler: &dyn Sampler) -> Image {
+    let mut sampler = sampler.clone_for_thread(0);
+    let mut image = new_image();
+
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            for index in 0..SAMPLES {
+                render_sample(sampler.as_mut(), &mut image, (x, y), index);
+            }
+        }
+    }
+
+    image
+}
+
+/// Tiles in reverse order, each on its own sampler
+fn reversed_tiles(sampler: &dyn Sampler) -> Image {
+    let mut image = new_image();
+
+    for (i, tile) in tiles().into_iter().enumerate().rev() {
+        render_tile(sampler.clone_for_thread(i as u32).as_mut(), &mut image, tile);
+    }
+
+    image
+}
+
+/// Progressive rendering, taking one sample of every pixel at a time, from the last pixel to the first
+fn progressive(sampler: &dyn Sampler) -> Image {
+    let mut sampler = sampler.clone_for_thread(0);
+    let mut image = new_image();
+
+    for index in 0..SAMPLES {
+        for y in (0..HEIGHT).rev() {
+            for x in (0..WIDTH).rev() {
+                render_sample(sampler.as_mut(), &mut image, (x, y), index);
+            }
+        }
+    }
+
+    image
+}
+
+/// Tiles dealt out to threads, as rendering across threads or machines would
+fn threaded(sampler: &dyn Sampler, threads: u32) -> Image {
+    let handles: Vec<_> = (0..threads)
+        .map(|thread_index| {
+            let mut sampler = sampler.clone_for_thread(thread_index);
+
+            thread::spawn(move || {
+                let mut image = new_image();
+
+                for tile in tiles().into_iter().skip(thread_index as usize).step_by(threads as usize) {
+                    render_tile(sampler.as_mut(), &mut image, tile);
+                }
+
+                image
+            })
+        })
+        .collect();
+
+    // every value is written by exactly one thread, and is zero in the images of the others
+    let mut image = new_image();
+    for handle in handles {
+        for (value, part) in image.iter_mut().zip(handle.join().unwrap()) {
+            *value |= part;
+        }
+    }
+
+    image
+}
+
+fn reproducible_samplers() -> Vec<(&'static str, Box<dyn Sampler>)> {
+    let pmj02 = Arc::new(Pmj02Table::generate(2, 64, 1));
+    let blue_noise = Arc::new(BlueNoiseTexture::generate(16, 1));
+
+    vec![
+        ("independent", Box::new(IndependentSampler::new(SAMPLES, 1))),
+        ("stratified", Box::new(StratifiedSampler::new(4, 2, true, 1))),
+        ("halton", Box::new(HaltonSampler::new(SAMPLES, Randomization::Owen, 1))),
+        ("sobol", Box::new(SobolSampler::new(SAMPLES, Randomization::Owen, 1))),
+        ("pmj02", Box::new(Pmj02Sampler::new(pmj02, SAMPLES, Randomization::Xor, 1))),
+        (
+            "blue noise",
+            Box::new(BlueNoiseSampler::new(
+                Box::new(SobolSampler::new(SAMPLES, Randomization::None, 1)),
+                blue_noise,
+                1,
+            )),
+        ),
+    ]
+}
+
+#[test]
+fn test_tiling_orders_identical() {
+    for (name, sampler) in reproducible_samplers() {
+        assert!(sampler.is_reproducible(), "{}", name);
+
+        let reference = scanline(sampler.as_ref());
+
+        assert!(
+            reference == reversed_tiles(sampler.as_ref()),
+            "{} differs with reversed tiles",
+            name
+        );
+        assert!(reference == progressive(sampler.as_ref()), "{} differs when progressive", name);
+        assert!(reference == threaded(sampler.as_ref(), 4), "{} differs across threads", name);
+    }
+}
+
+#[test]
+fn test_random_not_reproducible() {
+    let sampler = RandomSampler::new(SAMPLES, 1);
+
+    assert!(!sampler.is_reproducible());
+    assert!(scanline(&sampler) != reversed_tiles(&sampler));
+}
@@ -0,0 +1,114 @@
+use raygon_sampling::rng::Pcg32;
+use raygon_sampling::{AliasTable, Distribution1D, Distribution2D};
+
+fn func(n: usize) -> Vec<f32> {
+    (0..n)
+        .map(|i| if i % 5 == 2 { 0.0 } else { (i as f32 * 0.61).cos() * 2.0 })
+        .collect()
+}
+
+#[test]
+fn test_distribution1d() {
+    let f = func(23);
+    let dist = Distribution1D::new(&f, 2.0, 6.0);
+
+    let integral: f32 = f.iter().map(|f| f.abs() * 4.0 / 23.0).sum();
+    assert!((dist.integral() - integral).abs() < 1e-4);
+
+    let mut rng = Pcg32::default();
+    let mut counts = vec![0u32; f.len()];
+    let n = 200000;
+
+    for _ in 0..n {
+        let u = rng.next_f32();
+        let (x, pdf, offset) = dist.sample(u);
+
+        assert!((2.0..=6.0).contains(&x));
+        assert!(pdf > 0.0, "sampled a segment with zero density");
+        assert_eq!(pdf, dist.pdf(x));
+        assert!((dist.invert(x).unwrap() - u).abs() < 1e-5);
+
+        counts[offset] += 1;
+    }
+
+    // each segment is sampled proportionally to its share of the integral
+    for (i, &count) in counts.iter().enumerate() {
+        let expected = f[i].abs() * 4.0 / 23.0 / integral;
+        assert!((count as f32 / n as f32 - expected).abs() < 0.005, "segment {}", i);
+    }
+
+    assert_eq!(dist.invert(1.0), None);
+    assert_eq!(dist.pdf(7.0), 0.0);
+}
+
+#[test]
+fn test_distribution1d_zero() {
+    let dist = Distribution1D::new(&[0.0; 4], 0.0, 1.0);
+
+    let (x, pdf, offset) = dist.sample(0.6);
+    assert!((x - 0.6).abs() < 1e-6);
+    assert_eq!((pdf, offset), (0.0, 2));
+}
+
+#[test]
+fn test_distribution2d() {
+    let (nu, nv) = (16, 11);
+    let f = func(nu * nv);
+    let dist = Distribution2D::new(&f, nu, nv);
+
+    let mut rng = Pcg32::default();
+
+    for _ in 0..100000 {
+        let u = (rng.next_f32(), rng.next_f32());
+        let ((x, y), pdf) = dist.sample(u);
+
+        let value = f[(y * nv as f32) as usize * nu + (x * nu as f32) as usize].abs();
+
+        assert!(pdf > 0.0);
+        assert!((pdf - value / dist.integral()).abs() < 1e-4 * pdf);
+        assert!((pdf - dist.pdf((x, y))).abs() < 1e-4 * pdf);
+
+        let inverse = dist.invert((x, y)).unwrap();
+        assert!(
+            (inverse.0 - u.0).abs() < 1e-4 && (inverse.1 - u.1).abs() < 1e-4,
+            "{:?} != {:?}",
+            inverse,
+            u
+        );
+    }
+}
+
+#[test]
+fn test_alias_table() {
+    let weights = func(37);
+    let table = AliasTable::new(&weights);
+
+    let sum: f32 = weights.iter().map(|w| w.abs()).sum();
+    let mut rng = Pcg32::default();
+    let mut counts = vec![0u32; weights.len()];
+    let n = 200000;
+
+    for _ in 0..n {
+        let u = rng.next_f32();
+        let (index, pmf, remapped) = table.sample(u);
+
+        assert!(pmf > 0.0);
+        assert!((0.0..1.0).contains(&remapped));
+
+        let inverse = table.invert(index, remapped).unwrap();
+        assert_eq!(table.sample(inverse).0, index);
+
+        counts[index] += 1;
+    }
+
+    for (i, &count) in counts.iter().enumerate() {
+        let expected = weights[i].abs() / sum;
+
+        assert!((table.pmf(i) - expected).abs() < 1e-6);
+        assert!((count as f32 / n as f32 - expected).abs() < 0.005, "index {}", i);
+
+        if expected == 0.0 {
+            assert_eq!(table.invert(i, 0.5), None);
+        }
+    }
+}
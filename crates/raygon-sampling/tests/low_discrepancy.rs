@@ -0,0 +1,214 @@
+use std::f64::consts::PI;
+
+use raygon_sampling::halton::{radical_inverse, scrambled_radical_inverse, PRIMES};
+use raygon_sampling::sobol::{sobol_sample, NUM_SOBOL_DIMENSIONS};
+use raygon_sampling::*;
+
+const RANDOMIZATIONS: [Randomization; 3] = [Randomization::None, Randomization::Xor, Randomization::Owen];
+
+#[test]
+fn test_primes() {
+    assert_eq!(&PRIMES[..10], &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert_eq!(PRIMES[1023], 8161);
+}
+
+#[test]
+fn test_radical_inverse() {
+    assert_eq!(radical_inverse(0, 0), 0.0);
+    assert_eq!(radical_inverse(0, 1), 0.5);
+    assert_eq!(radical_inverse(0, 2), 0.25);
+    assert_eq!(radical_inverse(0, 3), 0.75);
+    assert_eq!(radical_inverse(0, 6), 0.375);
+    assert_eq!(radical_inverse(1, 1), 1.0 / 3.0);
+    assert_eq!(radical_inverse(1, 5), 7.0 / 9.0);
+}
+
+/// Every dimension of the first `n` points must land in distinct intervals of width `1/n`
+fn assert_stratified_1d<F: Fn(u32) -> f32>(n: u32, sample: F) {
+    let mut seen = vec![false; n as usize];
+
+    for i in 0..n {
+        let u = sample(i);
+        assert!((0.0..1.0).contains(&u));
+
+        let j = (u * n as f32) as usize;
+        assert!(!seen[j], "sample {} shares an interval with a previous sample", i);
+        seen[j] = true;
+    }
+}
+
+#[test]
+fn test_sobol_stratified_1d() {
+    for &randomization in &RANDOMIZATIONS {
+        for dim in (0..NUM_SOBOL_DIMENSIONS as u32).step_by(37) {
+            assert_stratified_1d(256, |i| sobol_sample(i, dim, randomization, 0x1234567 + dim));
+        }
+    }
+}
+
+#[test]
+fn test_halton_stratified_1d() {
+    for &(base_index, n) in &[(0, 64), (1, 81), (2, 125), (10, 31 * 31)] {
+        // unscrambled points sit exactly on interval boundaries, which may round down in base 3 and above
+        assert_stratified_1d(n, |i| radical_inverse(base_index, i) + 0.1 / n as f32);
+
+        for &randomization in &RANDOMIZATIONS[1..] {
+            assert_stratified_1d(n, |i| scrambled_radical_inverse(base_index, i, randomization, 0xabcdef));
+        }
+    }
+}
+
+#[test]
+fn test_sobol_elementary_intervals() {
+    // the first two dimensions form a (0, m, 2)-net, so every 2^-a by 2^-(m-a) box holds exactly one point
+    let m = 8;
+    let n = 1u32 << m;
+
+    for &randomization in &RANDOMIZATIONS {
+        for a in 0..=m {
+            let mut seen = vec![false; n as usize];
+
+            for i in 0..n {
+                let x = (sobol_sample(i, 0, randomization, 17) * (1 << a) as f32) as usize;
+                let y = (sobol_sample(i, 1, randomization, 91) * (1 << (m - a)) as f32) as usize;
+
+                let cell = (y << a) | x;
+                assert!(!seen[cell], "elementary interval {}x{} holds two points", a, m - a);
+                seen[cell] = true;
+            }
+        }
+    }
+}
+
+/// Smallest `t` for which the first `2^m` points of dimensions `a` and `b` form a `(t, m, 2)`-net,
+/// so every `2^-i` by `2^-(m-t-i)` elementary interval holds exactly `2^t` points
+fn sobol_t_value(a: u32, b: u32, m: u32, randomization: Randomization) -> u32 {
+    let points: Vec<(f32, f32)> = (0..1u32 << m)
+        .map(|i| (sobol_sample(i, a, randomization, 17 + a), sobol_sample(i, b, randomization, 91 + b)))
+        .collect();
+
+    (0..=m)
+        .find(|&t| {
+            (0..=m - t).all(|i| {
+                let j = m - t - i;
+                let mut counts = vec![0u32; 1 << (m - t)];
+
+                for &(x, y) in &points {
+                    let cx = (x * (1 << i) as f32) as usize;
+                    let cy = (y * (1 << j) as f32) as usize;
+                    counts[(cy << i) | cx] += 1;
+                }
+
+                counts.iter().all(|&c| c == 1 << t)
+            })
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_sobol_pairwise_elementary_intervals() {
+    // every 2D projection of the leading dimensions is a good net, not just the first two
+    for &randomization in &RANDOMIZATIONS {
+        for b in 1..16 {
+            for a in 0..b {
+                let t = sobol_t_value(a, b, 10, randomization);
+                assert!(t <= 4, "dimensions {} and {} form a (t, 10, 2)-net with t = {}", a, b, t);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sobol_neighbor_projections() {
+    // the initial direction numbers of each dimension are chosen against the 16 dimensions before it
+    let mut t_sum = 0;
+    let mut pairs = 0;
+
+    for b in (1..NUM_SOBOL_DIMENSIONS as u32).step_by(7) {
+        for a in b.saturating_sub(16)..b {
+            let t = sobol_t_value(a, b, 8, Randomization::None);
+            assert!(
+                t < 8,
+                "the first 256 points of dimensions {} and {} fall in a single interval",
+                a,
+                b
+            );
+
+            t_sum += t;
+            pairs += 1;
+        }
+    }
+
+    let mean = t_sum as f32 / pairs as f32;
+    assert!(mean < 2.5, "mean t-value of neighboring projections is {}", mean);
+}
+
+/// Root-mean-square error of estimating `integral` over many pixels with `samples_per_pixel` samples each
+fn rmse<F>(sampler: &mut dyn Sampler, dimensions: usize, integral: f64, f: F) -> f64
+where
+    F: Fn(&[f64]) -> f64,
+{
+    const PIXELS: u32 = 64;
+
+    let n = sampler.samples_per_pixel();
+    let mut x = vec![0.0; dimensions];
+    let mut sum_squared_error = 0.0;
+
+    for p in 0..PIXELS {
+        let mut estimate = 0.0;
+
+        for index in 0..n {
+            sampler.start_pixel_sample((p, 0), index);
+
+            for x in &mut x {
+                *x = sampler.next_1d() as f64;
+            }
+
+            estimate += f(&x);
+        }
+
+        let error = estimate / n as f64 - integral;
+        sum_squared_error += error * error;
+    }
+
+    (sum_squared_error / PIXELS as f64).sqrt()
+}
+
+fn assert_beats_independent<F>(dimensions: usize, integral: f64, f: F)
+where
+    F: Fn(&[f64]) -> f64 + Copy,
+{
+    let n = 256;
+    let baseline = rmse(&mut IndependentSampler::new(n, 0), dimensions, integral, f);
+
+    let mut samplers: Vec<(&str, Box<dyn Sampler>)> = vec![
+        ("sobol xor", Box::new(SobolSampler::new(n, Randomization::Xor, 0))),
+        ("sobol owen", Box::new(SobolSampler::new(n, Randomization::Owen, 0))),
+        ("halton xor", Box::new(HaltonSampler::new(n, Randomization::Xor, 0))),
+        ("halton owen", Box::new(HaltonSampler::new(n, Randomization::Owen, 0))),
+    ];
+
+    for (name, sampler) in &mut samplers {
+        let error = rmse(sampler.as_mut(), dimensions, integral, f);
+
+        assert!(
+            error < baseline * 0.5,
+            "{} error {} is not below independent error {}",
+            name,
+            error,
+            baseline
+        );
+    }
+}
+
+#[test]
+fn test_smooth_integral() {
+    // each factor integrates to one over the unit interval
+    assert_beats_independent(4, 1.0, |x| x.iter().map(|&x| PI * 0.5 * (PI * x).sin()).product());
+}
+
+#[test]
+fn test_discontinuous_integral() {
+    // area of a quarter disk
+    assert_beats_independent(2, PI * 0.25, |x| if x[0] * x[0] + x[1] * x[1] < 1.0 { 1.0 } else { 0.0 });
+}
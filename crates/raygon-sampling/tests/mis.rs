@@ -0,0 +1,100 @@
+use raygon_sampling::mis::*;
+use raygon_sampling::rng::Pcg32;
+
+// f(x) = x^2 + 0.1 on [0, 1], integrating to 13/30
+fn f(x: f32) -> f32 {
+    x * x + 0.1
+}
+
+const INTEGRAL: f32 = 13.0 / 30.0;
+
+// technique 0 samples uniformly, technique 1 with density 2x
+fn sample(technique: usize, u: f32) -> f32 {
+    if technique == 0 {
+        u
+    } else {
+        u.sqrt()
+    }
+}
+
+fn pdfs(x: f32) -> [f32; 2] {
+    [1.0, 2.0 * x]
+}
+
+#[test]
+fn test_two_technique_weights() {
+    for &(f_pdf, g_pdf) in &[(1.0, 1.0), (0.5, 2.0), (3.0, 0.0), (0.0, 4.0)] {
+        let b = balance_heuristic(2, f_pdf, 1, g_pdf) + balance_heuristic(1, g_pdf, 2, f_pdf);
+        let p = power_heuristic(2, f_pdf, 1, g_pdf) + power_heuristic(1, g_pdf, 2, f_pdf);
+
+        assert!((b - 1.0).abs() < 1e-6);
+        assert!((p - 1.0).abs() < 1e-6);
+
+        assert_eq!(
+            balance_heuristic(2, f_pdf, 1, g_pdf),
+            Heuristic::Balance.weight(0, &[2, 1], &[f_pdf, g_pdf])
+        );
+        assert_eq!(
+            power_heuristic(2, f_pdf, 1, g_pdf),
+            Heuristic::Power.weight(0, &[2, 1], &[f_pdf, g_pdf])
+        );
+    }
+
+    // delta distributions take all of the weight
+    assert_eq!(power_heuristic(1, f32::INFINITY, 1, 5.0), 1.0);
+    assert_eq!(balance_heuristic(1, 5.0, 1, f32::INFINITY), 0.0);
+    assert_eq!(balance_heuristic(1, 0.0, 1, 0.0), 0.0);
+}
+
+#[test]
+fn test_multi_sample_estimator() {
+    let mut rng = Pcg32::default();
+
+    for &heuristic in &[Heuristic::Balance, Heuristic::Power] {
+        let counts = [3, 5];
+        let mut total = 0.0;
+        let runs = 20000;
+
+        for _ in 0..runs {
+            let mut estimator = MisEstimator::new(heuristic, &counts);
+
+            for (technique, &n) in counts.iter().enumerate() {
+                for _ in 0..n {
+                    let x = sample(technique, rng.next_f32());
+                    estimator.add(technique, f(x), &pdfs(x));
+                }
+            }
+
+            total += estimator.estimate();
+        }
+
+        let estimate = total / runs as f32;
+        assert!((estimate - INTEGRAL).abs() < 0.002, "{:?}: {}", heuristic, estimate);
+    }
+}
+
+#[test]
+fn test_one_sample_estimator() {
+    let mut rng = Pcg32::default();
+    let selection = [0.3, 0.7];
+
+    for &heuristic in &[Heuristic::Balance, Heuristic::Power] {
+        let n = 200000;
+        let mut total = 0.0f64;
+
+        for _ in 0..n {
+            let technique = if rng.next_f32() < selection[0] { 0 } else { 1 };
+
+            let x = sample(technique, rng.next_f32());
+            let pdfs = pdfs(x);
+
+            if pdfs[technique] > 0.0 {
+                let weight = one_sample_weight(heuristic, technique, &selection, &pdfs);
+                total += (weight * f(x) / pdfs[technique]) as f64;
+            }
+        }
+
+        let estimate = (total / n as f64) as f32;
+        assert!((estimate - INTEGRAL).abs() < 0.003, "{:?}: {}", heuristic, estimate);
+    }
+}
@@ -0,0 +1,95 @@
+use raygon_sampling::*;
+
+fn samples(sampler: &mut dyn Sampler, pixel: Pixel, dimensions: u32) -> Vec<f32> {
+    let mut values = Vec::new();
+
+    for index in 0..sampler.samples_per_pixel() {
+        sampler.start_pixel_sample(pixel, index);
+
+        for _ in 0..dimensions {
+            values.push(sampler.next_1d());
+        }
+    }
+
+    values
+}
+
+#[test]
+fn test_samples_in_range() {
+    let mut samplers: Vec<Box<dyn Sampler>> = vec![
+        Box::new(IndependentSampler::new(16, 1)),
+        Box::new(StratifiedSampler::new(4, 4, true, 1)),
+        Box::new(RandomSampler::new(16, 1)),
+    ];
+
+    for sampler in &mut samplers {
+        for &u in &samples(sampler.as_mut(), (3, 5), 8) {
+            assert!((0.0..1.0).contains(&u), "{} out of range", u);
+        }
+    }
+}
+
+#[test]
+fn test_deterministic_seeding() {
+    let mut a = IndependentSampler::new(8, 7);
+    let mut b = IndependentSampler::new(8, 7);
+    let mut c = IndependentSampler::new(8, 8);
+
+    assert_eq!(samples(&mut a, (1, 2), 4), samples(&mut b, (1, 2), 4));
+    assert_ne!(samples(&mut a, (1, 2), 4), samples(&mut c, (1, 2), 4));
+
+    let mut a = StratifiedSampler::new(2, 4, true, 7);
+    let mut b = a.clone_for_thread(3);
+
+    assert_eq!(samples(&mut a, (9, 9), 4), samples(b.as_mut(), (9, 9), 4));
+}
+
+#[test]
+fn test_start_at_dimension() {
+    let mut samplers: Vec<Box<dyn Sampler>> = vec![
+        Box::new(IndependentSampler::new(4, 0)),
+        Box::new(StratifiedSampler::new(2, 2, true, 0)),
+    ];
+
+    for sampler in &mut samplers {
+        sampler.start_pixel_sample((4, 2), 3);
+        let (_, _, c) = (sampler.next_1d(), sampler.next_1d(), sampler.next_1d());
+        assert_eq!(sampler.dimension(), 3);
+
+        sampler.start_pixel_sample_at((4, 2), 3, 2);
+        assert_eq!(sampler.next_1d(), c);
+    }
+}
+
+#[test]
+fn test_stratified_strata() {
+    let mut sampler = StratifiedSampler::new(4, 2, true, 0);
+    let n = sampler.samples_per_pixel();
+
+    let mut seen_1d = vec![false; n as usize];
+    let mut seen_2d = vec![false; n as usize];
+
+    for index in 0..n {
+        sampler.start_pixel_sample((0, 0), index);
+
+        let u = sampler.next_1d();
+        let (x, y) = sampler.next_2d();
+
+        let s1 = (u * n as f32) as usize;
+        let s2 = (y * 2.0) as usize * 4 + (x * 4.0) as usize;
+
+        assert!(!seen_1d[s1] && !seen_2d[s2]);
+        seen_1d[s1] = true;
+        seen_2d[s2] = true;
+    }
+}
+
+#[test]
+fn test_random_threads_differ() {
+    let sampler = RandomSampler::new(4, 0);
+
+    let mut a = sampler.clone_for_thread(0);
+    let mut b = sampler.clone_for_thread(1);
+
+    assert_ne!(samples(a.as_mut(), (0, 0), 4), samples(b.as_mut(), (0, 0), 4));
+}
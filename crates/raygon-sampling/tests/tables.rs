@@ -0,0 +1,136 @@
+use std::io::{Cursor, ErrorKind};
+use std::sync::Arc;
+
+use raygon_sampling::*;
+
+#[test]
+fn test_pmj02_elementary_intervals() {
+    let table = Pmj02Table::generate(2, 1024, 3);
+
+    for set in 0..table.sets() {
+        // every power-of-two prefix must be a (0, m, 2)-net
+        for m in 0..=10 {
+            let n = 1usize << m;
+
+            for a in 0..=m {
+                let mut seen = vec![false; n];
+
+                for i in 0..n {
+                    let [x, y] = table.point_bits(set, i);
+                    let (x, y) = ((x as u64 >> (32 - a)) as usize, (y as u64 >> (32 - (m - a))) as usize);
+
+                    let cell = (x << (m - a)) | y;
+                    assert!(
+                        !seen[cell],
+                        "set {}, {} points: elementary interval {}x{} holds two points",
+                        set,
+                        n,
+                        a,
+                        m - a
+                    );
+                    seen[cell] = true;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pmj02_roundtrip() {
+    let table = Pmj02Table::generate(3, 64, 1);
+
+    let mut bytes = Vec::new();
+    table.write(&mut bytes).unwrap();
+
+    assert_eq!(Pmj02Table::read(&mut Cursor::new(&bytes)).unwrap(), table);
+
+    // truncated data
+    let err = Pmj02Table::read(&mut Cursor::new(&bytes[..bytes.len() - 1])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    // wrong table type
+    let err = BlueNoiseTexture::read(&mut Cursor::new(&bytes)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_pmj02_sampler() {
+    let table = Arc::new(Pmj02Table::generate(4, 256, 0));
+
+    for &randomization in &[Randomization::None, Randomization::Xor, Randomization::Owen] {
+        let mut sampler = Pmj02Sampler::new(table.clone(), 64, randomization, 9);
+        let mut seen = [false; 64];
+
+        // randomization preserves the 8x8 stratification of the first 64 points
+        for index in 0..64 {
+            sampler.start_pixel_sample((2, 7), index);
+            let (x, y) = sampler.next_2d();
+
+            let cell = (y * 8.0) as usize * 8 + (x * 8.0) as usize;
+            assert!(!seen[cell]);
+            seen[cell] = true;
+        }
+    }
+}
+
+#[test]
+fn test_blue_noise_texture() {
+    let size = 32;
+    let texture = BlueNoiseTexture::generate(size, 5);
+
+    let mut seen = vec![false; (size * size) as usize];
+    for y in 0..size {
+        for x in 0..size {
+            let rank = texture.rank(x, y) as usize;
+            assert!(!seen[rank]);
+            seen[rank] = true;
+
+            assert_eq!(texture.rank(x + size, y + 3 * size), rank as u32);
+        }
+    }
+
+    // the lowest ranked pixels should be evenly spread out, unlike white noise
+    let points: Vec<(i32, i32)> = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .filter(|&(x, y)| texture.rank(x, y) < 64)
+        .map(|(x, y)| (x as i32, y as i32))
+        .collect();
+
+    for (i, a) in points.iter().enumerate() {
+        for b in &points[i + 1..] {
+            let dx = (a.0 - b.0).abs().min(size as i32 - (a.0 - b.0).abs());
+            let dy = (a.1 - b.1).abs().min(size as i32 - (a.1 - b.1).abs());
+
+            assert!(dx * dx + dy * dy >= 4, "{:?} and {:?} are too close", a, b);
+        }
+    }
+
+    let mut bytes = Vec::new();
+    texture.write(&mut bytes).unwrap();
+    assert_eq!(BlueNoiseTexture::read(&mut Cursor::new(&bytes)).unwrap(), texture);
+}
+
+#[test]
+fn test_blue_noise_sampler() {
+    let texture = Arc::new(BlueNoiseTexture::generate(16, 0));
+    let inner = SobolSampler::new(16, Randomization::None, 0);
+
+    let mut sampler = BlueNoiseSampler::new(Box::new(inner.clone()), texture, 0);
+    let mut inner: Box<dyn Sampler> = Box::new(inner);
+
+    let mut shift = None;
+
+    for index in 0..16 {
+        sampler.start_pixel_sample((5, 1), index);
+        inner.start_pixel_sample((5, 1), index);
+
+        let (u, v) = (sampler.next_1d(), inner.next_1d());
+        assert!((0.0..1.0).contains(&u));
+        assert_eq!(sampler.dimension(), inner.dimension());
+
+        // every sample of a pixel is shifted by the same amount, which keeps the inner stratification
+        let s = (u - v).rem_euclid(1.0);
+        let shift = *shift.get_or_insert(s);
+        assert!((s - shift).abs() < 1e-6 || (s - shift).abs() > 1.0 - 1e-6);
+    }
+}
@@ -0,0 +1,126 @@
+use std::f32::consts::PI;
+
+use raygon_geometry::scalar::Vector3;
+use raygon_sampling::rng::Pcg32;
+use raygon_sampling::warp::scalar::*;
+
+fn uniform(rng: &mut Pcg32) -> (f32, f32) {
+    (rng.next_f32(), rng.next_f32())
+}
+
+fn assert_close(a: (f32, f32), b: (f32, f32), tolerance: f32) {
+    assert!((a.0 - b.0).abs() < tolerance && (a.1 - b.1).abs() < tolerance, "{:?} != {:?}", a, b);
+}
+
+fn assert_unit(w: Vector3) {
+    assert!((w.norm() - 1.0).abs() < 1e-5, "{:?} is not normalized", w);
+}
+
+#[test]
+fn test_disk_roundtrip() {
+    let mut rng = Pcg32::default();
+
+    for _ in 0..10000 {
+        let u = uniform(&mut rng);
+        let (x, y) = sample_uniform_disk_concentric(u);
+
+        assert!(x * x + y * y <= 1.0 + 1e-6);
+        assert_close(invert_uniform_disk_concentric((x, y)), u, 1e-4);
+    }
+}
+
+#[test]
+fn test_direction_roundtrips() {
+    let mut rng = Pcg32::default();
+    let cos_theta_max = 0.8;
+
+    for _ in 0..10000 {
+        let u = uniform(&mut rng);
+
+        let w = sample_uniform_hemisphere(u);
+        assert_unit(w);
+        assert!(w.z >= 0.0);
+        assert_close(invert_uniform_hemisphere(w), u, 1e-4);
+
+        let w = sample_uniform_sphere(u);
+        assert_unit(w);
+        assert_close(invert_uniform_sphere(w), u, 1e-4);
+
+        let w = sample_cosine_hemisphere(u);
+        assert_unit(w);
+        assert!(w.z >= 0.0);
+        assert_close(invert_cosine_hemisphere(w), u, 1e-3);
+
+        let w = sample_uniform_cone(u, cos_theta_max);
+        assert_unit(w);
+        assert!(w.z >= cos_theta_max - 1e-6);
+        assert_close(invert_uniform_cone(w, cos_theta_max), u, 1e-3);
+    }
+}
+
+#[test]
+fn test_triangle_roundtrip() {
+    let mut rng = Pcg32::default();
+
+    for _ in 0..10000 {
+        let u = uniform(&mut rng);
+        let b = sample_uniform_triangle(u);
+
+        assert!(b.iter().all(|b| (0.0..=1.0).contains(b)));
+        assert_close(invert_uniform_triangle(b), u, 1e-5);
+    }
+}
+
+#[test]
+fn test_cosine_pdf_normalized() {
+    let mut rng = Pcg32::default();
+    let n = 100000;
+
+    let sum: f64 = (0..n)
+        .map(|_| {
+            let w = sample_cosine_hemisphere(uniform(&mut rng));
+            (uniform_hemisphere_pdf() / cosine_hemisphere_pdf(w.z)) as f64
+        })
+        .filter(|x| x.is_finite())
+        .sum();
+
+    // integral of the uniform density, importance sampled by the cosine density
+    assert!((sum / n as f64 - 1.0).abs() < 0.05, "{}", sum / n as f64);
+}
+
+#[test]
+fn test_spherical_triangle() {
+    let v = [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+
+    // one octant of the sphere
+    let area = spherical_triangle_area(v);
+    assert!((area - PI / 2.0).abs() < 1e-5, "{}", area);
+
+    let mut rng = Pcg32::default();
+
+    for _ in 0..10000 {
+        let u = uniform(&mut rng);
+        let (w, pdf) = sample_spherical_triangle(v, u).unwrap();
+
+        assert_unit(w);
+        assert!(w.x >= -1e-5 && w.y >= -1e-5 && w.z >= -1e-5, "{:?} is outside the octant", w);
+        assert!((pdf - 1.0 / area).abs() < 1e-5);
+
+        assert_close(invert_spherical_triangle(v, w), u, 2e-3);
+    }
+
+    // moving the triangle away from the origin shrinks it
+    let far = [v[0] * Vector3::diag(4.0), v[1] * Vector3::diag(4.0), v[2] * Vector3::diag(4.0)];
+    assert!((spherical_triangle_area(far) - area).abs() < 1e-5);
+
+    let offset = Vector3::new(0.0, 0.0, 10.0);
+    let small = [v[0] + offset, v[1] + offset, v[2] + offset];
+    assert!(spherical_triangle_area(small) < 0.01);
+
+    // degenerate as seen from the origin
+    assert!(sample_spherical_triangle([v[0], v[0], v[1]], (0.5, 0.5)).is_none());
+}
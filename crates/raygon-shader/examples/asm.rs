@@ -1,16 +1,18 @@
-use thermite::backends::avx2::AVX2;
 use thermite::*;
 
 use raygon_shader::vm;
-use vm::{instr::Instruction, rom::ROM, stack::Stack};
+use vm::{exec::Executor, instr::Instruction, random::RandomBatch, rom::ROM, stack::Stack};
 
-#[no_mangle]
-#[target_feature(enable = "avx2,fma")]
+#[dispatch]
 #[inline(never)]
-pub unsafe fn eval_instruction(isntr: Instruction, stack: &mut Stack<AVX2>, rom: &ROM) {
-    isntr.eval(stack, rom);
+pub fn eval_instruction<S: Simd>(isntr: Instruction, stack: &mut Stack<S>, rom: &ROM, random: &mut RandomBatch) {
+    isntr.eval(stack, rom, random);
 }
 
-fn main() {}
+fn main() {
+    let executor = Executor::new();
 
-// cargo rustc --example asm --release -- -C target-cpu=native -C opt-level=3 --emit asm
+    println!("Shaders will run on {:?} with {} lanes", executor.instrset(), executor.lanes());
+}
+
+// cargo rustc --example asm --release -- -C opt-level=3 --emit asm
@@ -0,0 +1,83 @@
+use std::time::Instant;
+
+use raygon_shader::vm;
+use vm::{
+    exec::Executor,
+    instr::{binary::BinaryOp, Instruction},
+    program::Program,
+    register::RegisterProgram,
+    rom::ROM,
+};
+
+const POINTS: usize = 1 << 16;
+const ITERATIONS: usize = 64;
+
+fn bench<F>(name: &str, executor: &Executor, instructions: usize, mut run: F)
+where
+    F: FnMut(),
+{
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        run();
+    }
+    let elapsed = start.elapsed();
+
+    let batches = (POINTS + executor.lanes() - 1) / executor.lanes();
+    let dispatches = instructions * batches * ITERATIONS;
+    let points_per_second = (POINTS * ITERATIONS) as f64 / elapsed.as_secs_f64();
+
+    println!(
+        "{:>16}: {:>3} instructions, {:>10} dispatches, {:>8.2} Mpoints/s",
+        name,
+        instructions,
+        dispatches,
+        points_per_second / 1e6
+    );
+}
+
+fn main() {
+    // Horner-style polynomial in the inputs, squaring between steps
+    let steps = 16;
+
+    let mut code = Vec::new();
+    for _ in 0..steps {
+        code.extend_from_slice(&[
+            Instruction::ScalarBinary(BinaryOp::Mul),
+            Instruction::ScalarBinary(BinaryOp::Add),
+            Instruction::CopyScalar(1),
+            Instruction::ScalarBinary(BinaryOp::Mul),
+        ]);
+    }
+
+    let num_inputs = steps * 2 + 1;
+    let program = Program::new(code, num_inputs, 1).unwrap();
+    let fused = program.fused();
+
+    let rom = ROM {
+        scalar: Vec::new(),
+        curves: Vec::new(),
+    };
+
+    let inputs: Vec<f32> = (0..num_inputs * POINTS).map(|i| (i % 13) as f32 / 13.0).collect();
+
+    let executor = Executor::new();
+    println!("Running on {:?} with {} lanes", executor.instrset(), executor.lanes());
+
+    let mut outputs = vec![0.0; POINTS];
+
+    for (name, program) in &[("stack", &program), ("stack (fused)", &fused)] {
+        bench(name, &executor, program.code().len(), || {
+            executor.run(program, &rom, &inputs, &mut outputs, POINTS)
+        });
+    }
+
+    for (name, program) in &[("register", &program), ("register (fused)", &fused)] {
+        let registers = RegisterProgram::translate(program);
+
+        bench(name, &executor, registers.code().len(), || {
+            executor.run_registers(&registers, &rom, &inputs, &mut outputs, POINTS)
+        });
+    }
+}
+
+// cargo run --example vm_bench --release
@@ -1,7 +1,7 @@
 #[macro_use]
 extern crate deepsize;
 
-//pub mod vm;
+pub mod vm;
 //pub mod jit;
 
 pub mod engine;
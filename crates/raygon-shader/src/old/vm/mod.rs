@@ -1,3 +0,0 @@
-pub mod instr;
-pub mod rom;
-pub mod stack;
@@ -0,0 +1,390 @@
+//! Forward-mode automatic differentiation of VM programs
+//!
+//! Each value on the stack carries its partial derivatives with respect to `N` chosen
+//! inputs, such as the UV coordinates, so one evaluation gives both the shader output
+//! and its gradient. Piecewise-constant operations have a zero derivative everywhere.
+
+use thermite::*;
+
+use super::instr::Instruction;
+use super::{program::Program, random::RandomBatch, rom::ROM};
+
+/// A value and its partial derivatives with respect to `N` inputs
+#[derive(Debug, Clone, Copy)]
+pub struct Dual<S: Simd, const N: usize> {
+    pub v: Vf32<S>,
+    pub d: [Vf32<S>; N],
+}
+
+impl<S: Simd, const N: usize> Dual<S, N> {
+    /// A value which does not depend on any input
+    #[inline(always)]
+    pub fn constant(v: Vf32<S>) -> Self {
+        Dual {
+            v,
+            d: [Vf32::<S>::zero(); N],
+        }
+    }
+
+    /// The input that partial derivative `i` is taken with respect to
+    #[inline(always)]
+    pub fn variable(v: Vf32<S>, i: usize) -> Self {
+        let mut x = Self::constant(v);
+        x.d[i] = Vf32::<S>::one();
+        x
+    }
+
+    /// Chain rule for `f(self)`, given `f(self.v)` and `f'(self.v)`
+    #[inline(always)]
+    pub fn chain(self, fx: Vf32<S>, dfdx: Vf32<S>) -> Self {
+        let mut res = self;
+        res.v = fx;
+        for d in &mut res.d {
+            *d *= dfdx;
+        }
+        res
+    }
+
+    /// Chain rule for `f(a, b)`, given `f(a.v, b.v)` and both partial derivatives
+    #[inline(always)]
+    pub fn chain2(a: Self, b: Self, fab: Vf32<S>, dfda: Vf32<S>, dfdb: Vf32<S>) -> Self {
+        let mut res = a;
+        res.v = fab;
+        for (d, db) in res.d.iter_mut().zip(&b.d) {
+            *d = d.mul_add(dfda, *db * dfdb);
+        }
+        res
+    }
+
+    /// Flushes non-finite derivatives to zero
+    #[inline(always)]
+    pub fn flush_derivatives(mut self) -> Self {
+        let zero = Vf32::<S>::zero();
+        for d in &mut self.d {
+            *d = d.is_finite().select(*d, zero);
+        }
+        self
+    }
+
+    /// Flushes the value to zero where it is not normal, the same as the VM does,
+    /// along with the derivatives of any non-finite values.
+    #[inline(always)]
+    pub fn flush(mut self) -> Self {
+        let zero = Vf32::<S>::zero();
+        let finite = self.v.is_finite();
+
+        self.v = self.v.is_normal().select(self.v, zero);
+        for d in &mut self.d {
+            *d = finite.select(*d, zero);
+        }
+
+        self.flush_derivatives()
+    }
+}
+
+/// Pops `M` values, returned bottom-most first
+#[inline(always)]
+fn pop<S: Simd, const N: usize, const M: usize>(stack: &mut Vec<Dual<S, N>>) -> [Dual<S, N>; M] {
+    let start = stack.len() - M;
+    let mut values = [Dual::constant(Vf32::<S>::zero()); M];
+    values.copy_from_slice(&stack[start..]);
+    stack.truncate(start);
+    values
+}
+
+impl Instruction {
+    /// Evaluates the instruction on a stack of dual numbers. Values match `Instruction::eval`.
+    pub fn eval_dual<S: Simd, const N: usize>(self, stack: &mut Vec<Dual<S, N>>, rom: &ROM, random: &mut RandomBatch) {
+        match self {
+            Instruction::NoOp => {}
+
+            Instruction::ScalarUnary(op) => {
+                let [x] = pop(stack);
+                stack.push(op.eval_dual(x));
+            }
+            Instruction::ScalarBinary(op) => {
+                let [a, b] = pop(stack);
+                stack.push(op.eval_dual(a, b));
+            }
+            Instruction::ScalarCompare(mode) => {
+                let [a, b] = pop(stack);
+                stack.push(mode.compare_dual(a, b));
+            }
+
+            Instruction::VectorUnary(op) => {
+                let [x, y, z] = pop(stack);
+                stack.extend_from_slice(&[op.eval_dual(x), op.eval_dual(y), op.eval_dual(z)]);
+            }
+            // A is the top-most vector
+            Instruction::VectorBinary(op) => {
+                let [xb, yb, zb, xa, ya, za] = pop(stack);
+                stack.extend_from_slice(&[op.eval_dual(xa, xb), op.eval_dual(ya, yb), op.eval_dual(za, zb)]);
+            }
+            Instruction::VectorCompare(mode) => {
+                let [xb, yb, zb, xa, ya, za] = pop(stack);
+                stack.extend_from_slice(&[mode.compare_dual(xa, xb), mode.compare_dual(ya, yb), mode.compare_dual(za, zb)]);
+            }
+
+            Instruction::VectorSum => {
+                let [x, y, z] = pop(stack);
+                let one = Vf32::<S>::one();
+                let xy = Dual::chain2(x, y, x.v + y.v, one, one);
+                stack.push(Dual::chain2(xy, z, xy.v + z.v, one, one));
+            }
+            Instruction::VectorProduct => {
+                let [x, y, z] = pop(stack);
+                let xy = Dual::chain2(x, y, x.v * y.v, y.v, x.v);
+                stack.push(Dual::chain2(xy, z, xy.v * z.v, z.v, xy.v));
+            }
+            Instruction::VectorMin => {
+                let [x, y, z] = pop(stack);
+                let min = |a: Dual<S, N>, b: Dual<S, N>| if_then_else(a.v.le(b.v), a, b);
+                stack.push(min(min(x, y), z));
+            }
+            Instruction::VectorMax => {
+                let [x, y, z] = pop(stack);
+                let max = |a: Dual<S, N>, b: Dual<S, N>| if_then_else(a.v.ge(b.v), a, b);
+                stack.push(max(max(x, y), z));
+            }
+            Instruction::VectorSplat => {
+                let [x] = pop(stack);
+                stack.extend_from_slice(&[x, x, x]);
+            }
+
+            Instruction::CopyScalar(count) => {
+                let x = stack[stack.len() - 1];
+                for _ in 0..count {
+                    stack.push(x);
+                }
+            }
+            Instruction::CopyVector(count) => {
+                let start = stack.len() - 3;
+                for _ in 0..count {
+                    stack.extend_from_within(start..start + 3);
+                }
+            }
+
+            Instruction::Curve(idx) => {
+                let [x] = pop(stack);
+                stack.push(rom.get_curve(idx).eval_dual(x));
+            }
+
+            Instruction::Random(offset) => stack.push(Dual::constant(random.lanes::<S>(offset))),
+
+            Instruction::ScalarBinaryDup(op) => {
+                let [x] = pop(stack);
+                stack.push(op.eval_dual(x, x));
+            }
+            Instruction::ScalarMulAdd => {
+                let [a, b, c] = pop(stack);
+                let one = Vf32::<S>::one();
+                stack.push(Dual::chain2(
+                    a,
+                    Dual::chain2(b, c, b.v * c.v, c.v, b.v),
+                    b.v.mul_add(c.v, a.v),
+                    one,
+                    one,
+                ));
+            }
+            Instruction::ScalarMulSub => {
+                let [a, b, c] = pop(stack);
+                let one = Vf32::<S>::one();
+                stack.push(Dual::chain2(
+                    a,
+                    Dual::chain2(b, c, b.v * c.v, c.v, b.v),
+                    b.v.nmul_add(c.v, a.v),
+                    one,
+                    -one,
+                ));
+            }
+            Instruction::VectorMulAdd => {
+                let [xc, yc, zc, xb, yb, zb, xa, ya, za] = pop(stack);
+                let one = Vf32::<S>::one();
+                let mul_add = |a: Dual<S, N>, b: Dual<S, N>, c: Dual<S, N>| {
+                    Dual::chain2(Dual::chain2(a, b, a.v * b.v, b.v, a.v), c, a.v.mul_add(b.v, c.v), one, one)
+                };
+                stack.extend_from_slice(&[mul_add(xa, xb, xc), mul_add(ya, yb, yc), mul_add(za, zb, zc)]);
+            }
+        }
+    }
+}
+
+/// Per-lane select of whole dual numbers
+#[inline(always)]
+fn if_then_else<S: Simd, const N: usize>(mask: Mask<S, Vf32<S>>, a: Dual<S, N>, b: Dual<S, N>) -> Dual<S, N> {
+    let mut res = b;
+    res.v = mask.select(a.v, b.v);
+    for (d, da) in res.d.iter_mut().zip(&a.d) {
+        *d = mask.select(*da, *d);
+    }
+    res
+}
+
+/// Evaluates `program` with forward-mode automatic differentiation, returning its outputs
+///
+/// Inputs should be seeded with `Dual::variable` for the inputs to differentiate with respect to,
+/// and `Dual::constant` for the rest. The program must not draw random numbers.
+pub fn eval_dual<S: Simd, const N: usize>(program: &Program, rom: &ROM, inputs: &[Dual<S, N>]) -> Vec<Dual<S, N>> {
+    assert_eq!(
+        program.random_dimensions(),
+        0,
+        "Shader program draws random numbers, but was not given a ShaderRandom"
+    );
+
+    eval_dual_with_random(program, rom, inputs, &mut RandomBatch::none())
+}
+
+/// Same as `eval_dual`, drawing the values of any `Instruction::Random` from `random`.
+/// Random values do not depend on any input.
+pub fn eval_dual_with_random<S: Simd, const N: usize>(
+    program: &Program,
+    rom: &ROM,
+    inputs: &[Dual<S, N>],
+    random: &mut RandomBatch,
+) -> Vec<Dual<S, N>> {
+    assert_eq!(inputs.len(), program.inputs(), "Shader inputs do not match program");
+    assert!(
+        program.random_dimensions() == 0 || !random.is_none(),
+        "Shader program draws random numbers, but was not given a ShaderRandom"
+    );
+    assert!(
+        program.curves() <= rom.curves.len(),
+        "Shader program reads curves missing from the ROM"
+    );
+
+    let mut stack = Vec::with_capacity(program.max_depth());
+    stack.extend_from_slice(inputs);
+
+    for &instr in program.code() {
+        instr.eval_dual::<S, N>(&mut stack, rom, random);
+    }
+
+    stack
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::instr::{binary::BinaryOp, unary::UnaryOp};
+    use crate::vm::rom::curve::{Curve, InterpolationMode};
+    use thermite::backends::avx2::AVX2;
+
+    type Vf32 = <AVX2 as Simd>::Vf32;
+
+    const H: f32 = 1e-3;
+
+    fn assert_close(derivative: f32, expected: f32, what: &str) {
+        assert!(
+            (derivative - expected).abs() <= 1e-2 * (1.0 + expected.abs()),
+            "{}: {} == {}",
+            what,
+            derivative,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_unary_derivatives() {
+        use UnaryOp::*;
+
+        let ops = [
+            Saturate, Neg, Abs, Sqrt, Square, Sign, Ln, Sin, Cos, Tan, ArcSin, ArcCos, ArcTan, Trunc, Fract, Round, Floor, Ceil, ToDegrees,
+            ToRadians, Invert, Heavyside,
+        ];
+
+        for &op in &ops {
+            for &x in &[0.3f32, 0.7] {
+                let f = |x: f32| op.eval::<AVX2>(Vf32::splat(x)).extract(0);
+                let expected = (f(x + H) - f(x - H)) / (2.0 * H);
+
+                let dual = op.eval_dual::<AVX2, 1>(Dual::variable(Vf32::splat(x), 0));
+
+                assert_eq!(dual.v.extract(0), f(x));
+                assert_close(dual.d[0].extract(0), expected, &format!("{:?}({})", op, x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_derivatives() {
+        use BinaryOp::*;
+
+        let ops = [Add, Sub, Mul, Div, Rem, Powf, Min, Max, ArcTan2, Hypot];
+
+        for &op in &ops {
+            for &(a, b) in &[(0.7f32, 0.4f32), (1.3, 1.6)] {
+                let f = |a: f32, b: f32| op.eval::<AVX2>(Vf32::splat(a), Vf32::splat(b)).extract(0);
+                let dfda = (f(a + H, b) - f(a - H, b)) / (2.0 * H);
+                let dfdb = (f(a, b + H) - f(a, b - H)) / (2.0 * H);
+
+                let dual = op.eval_dual::<AVX2, 2>(Dual::variable(Vf32::splat(a), 0), Dual::variable(Vf32::splat(b), 1));
+
+                assert_close(dual.d[0].extract(0), dfda, &format!("d{:?}({}, {})/da", op, a, b));
+                assert_close(dual.d[1].extract(0), dfdb, &format!("d{:?}({}, {})/db", op, a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_curve_derivatives() {
+        let curves = [
+            Curve::Poly(vec![0.5, -1.0, 2.0, 0.25]),
+            Curve::LookupTable {
+                values: vec![(0.0, 0.1, -0.1), (0.3, 0.3, 0.7), (0.5, 0.6, 0.0), (1.0, 0.2, -0.3)],
+                interpolation: InterpolationMode::CubicHermite,
+            },
+            Curve::LookupTable {
+                values: vec![(0.0, 0.1, 0.0), (0.3, 0.3, 0.0), (0.5, 0.6, 0.0), (1.0, 0.2, 0.0)],
+                interpolation: InterpolationMode::Linear,
+            },
+        ];
+
+        for curve in &curves {
+            for &x in &[0.2f32, 0.42, 0.65] {
+                let f = |x: f32| curve.eval::<AVX2>(Vf32::splat(x)).extract(0);
+                let expected = (f(x + H) - f(x - H)) / (2.0 * H);
+
+                let dual = curve.eval_dual::<AVX2, 1>(Dual::variable(Vf32::splat(x), 0));
+
+                assert_close(dual.d[0].extract(0), expected, &format!("{:?} at {}", curve, x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_program_gradient() {
+        // u + v * sin(u * u)
+        let code = vec![
+            Instruction::CopyScalar(1),
+            Instruction::ScalarBinary(BinaryOp::Mul),
+            Instruction::ScalarUnary(UnaryOp::Sin),
+            Instruction::ScalarMulAdd,
+        ];
+
+        let program = Program::new(code, 3, 1).unwrap();
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let (u, v) = (0.4f32, 1.7f32);
+
+        let inputs = [
+            Dual::<AVX2, 2>::variable(Vf32::splat(u), 0),
+            Dual::variable(Vf32::splat(v), 1),
+            Dual::variable(Vf32::splat(u), 0),
+        ];
+
+        let out = eval_dual(&program, &rom, &inputs);
+
+        let expected = u + v * (u * u).sin();
+        let dfdu = 1.0 + v * (u * u).cos() * 2.0 * u;
+        let dfdv = (u * u).sin();
+
+        assert_eq!(out.len(), 1);
+        assert_close(out[0].v.extract(0), expected, "value");
+        assert_close(out[0].d[0].extract(0), dfdu, "df/du");
+        assert_close(out[0].d[1].extract(0), dfdv, "df/dv");
+    }
+}
@@ -0,0 +1,584 @@
+use thermite::backends::{avx2::AVX2, scalar::Scalar, sse42::SSE42};
+use thermite::*;
+
+use super::random::{RandomBatch, ShaderRandom};
+use super::{profile::ShaderProfiler, program::Program, register::RegisterProgram, rom::ROM, stack::Stack};
+use raygon_profiler::Profiler;
+
+/// Calls a generic `#[dispatch]` function with the backend matching an already-validated instruction set
+macro_rules! dispatch_instrset {
+    ($instrset:expr, $f:ident::<S>($($arg:expr),*)) => {
+        match $instrset {
+            SimdInstructionSet::AVX2 => $f::<AVX2>($($arg),*),
+            SimdInstructionSet::SSE42 => $f::<SSE42>($($arg),*),
+            _ => $f::<Scalar>($($arg),*),
+        }
+    };
+}
+
+/// Runs shader programs over batches of shading points, using the widest instruction set the CPU supports
+///
+/// Inputs and outputs are given in SoA layout, where value `i` of shading point `j` is at index `i * count + j`.
+#[derive(Debug, Clone, Copy)]
+pub struct Executor {
+    instrset: SimdInstructionSet,
+}
+
+impl Default for Executor {
+    fn default() -> Executor {
+        Executor::new()
+    }
+}
+
+#[inline]
+fn rank(instrset: SimdInstructionSet) -> u8 {
+    match instrset {
+        SimdInstructionSet::AVX2 => 2,
+        SimdInstructionSet::SSE42 | SimdInstructionSet::AVX => 1,
+        _ => 0,
+    }
+}
+
+impl Executor {
+    /// Creates an executor for the best instruction set detected at runtime
+    pub fn new() -> Executor {
+        Executor::with_instrset(SimdInstructionSet::runtime_detect())
+    }
+
+    /// Creates an executor for the given instruction set, falling back to a narrower one
+    /// if the running CPU does not support it.
+    pub fn with_instrset(instrset: SimdInstructionSet) -> Executor {
+        let instrset = match rank(instrset).min(rank(SimdInstructionSet::runtime_detect())) {
+            2 => SimdInstructionSet::AVX2,
+            1 => SimdInstructionSet::SSE42,
+            _ => SimdInstructionSet::Scalar,
+        };
+
+        Executor { instrset }
+    }
+
+    /// The instruction set programs will actually be run with
+    #[inline(always)]
+    pub fn instrset(&self) -> SimdInstructionSet {
+        self.instrset
+    }
+
+    /// Number of shading points evaluated per instruction dispatch
+    pub fn lanes(&self) -> usize {
+        match self.instrset {
+            SimdInstructionSet::AVX2 => Vf32::<AVX2>::NUM_ELEMENTS,
+            SimdInstructionSet::SSE42 => Vf32::<SSE42>::NUM_ELEMENTS,
+            _ => Vf32::<Scalar>::NUM_ELEMENTS,
+        }
+    }
+
+    /// Runs `program` for `count` shading points, which must not draw random numbers
+    pub fn run(&self, program: &Program, rom: &ROM, inputs: &[f32], outputs: &mut [f32], count: usize) {
+        assert_eq!(inputs.len(), program.inputs() * count, "Shader inputs do not match program");
+        assert_eq!(outputs.len(), program.outputs() * count, "Shader outputs do not match program");
+        assert!(
+            program.curves() <= rom.curves.len(),
+            "Shader program reads curves missing from the ROM"
+        );
+        assert_eq!(
+            program.random_dimensions(),
+            0,
+            "Shader program draws random numbers, but was not given a ShaderRandom"
+        );
+
+        dispatch_instrset!(self.instrset, run_program::<S>(program, rom, inputs, outputs, count, None))
+    }
+
+    /// Runs `program` for `count` shading points, drawing the values of any `Instruction::Random` from `random`
+    pub fn run_with_random(
+        &self,
+        program: &Program,
+        rom: &ROM,
+        inputs: &[f32],
+        outputs: &mut [f32],
+        count: usize,
+        random: &mut ShaderRandom,
+    ) {
+        assert_eq!(inputs.len(), program.inputs() * count, "Shader inputs do not match program");
+        assert_eq!(outputs.len(), program.outputs() * count, "Shader outputs do not match program");
+        assert!(
+            program.curves() <= rom.curves.len(),
+            "Shader program reads curves missing from the ROM"
+        );
+        assert_eq!(random.len(), count, "Shader sample points do not match count");
+
+        dispatch_instrset!(self.instrset, run_program::<S>(program, rom, inputs, outputs, count, Some(random)))
+    }
+
+    /// Runs `program` for `count` shading points, recording statistics under the program's name.
+    /// The program must not draw random numbers.
+    pub fn run_profiled(
+        &self,
+        program: &Program,
+        rom: &ROM,
+        inputs: &[f32],
+        outputs: &mut [f32],
+        count: usize,
+        profiler: &mut ShaderProfiler,
+    ) {
+        assert_eq!(
+            program.random_dimensions(),
+            0,
+            "Shader program draws random numbers, but was not given a ShaderRandom"
+        );
+
+        self.run_profiled_inner(program, rom, inputs, outputs, count, None, profiler)
+    }
+
+    /// Same as `run_profiled`, but draws the values of any `Instruction::Random` from `random`
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_profiled_with_random(
+        &self,
+        program: &Program,
+        rom: &ROM,
+        inputs: &[f32],
+        outputs: &mut [f32],
+        count: usize,
+        random: &mut ShaderRandom,
+        profiler: &mut ShaderProfiler,
+    ) {
+        assert_eq!(random.len(), count, "Shader sample points do not match count");
+
+        self.run_profiled_inner(program, rom, inputs, outputs, count, Some(random), profiler)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn run_profiled_inner(
+        &self,
+        program: &Program,
+        rom: &ROM,
+        inputs: &[f32],
+        outputs: &mut [f32],
+        count: usize,
+        random: Option<&mut ShaderRandom>,
+        profiler: &mut ShaderProfiler,
+    ) {
+        assert_eq!(inputs.len(), program.inputs() * count, "Shader inputs do not match program");
+        assert_eq!(outputs.len(), program.outputs() * count, "Shader outputs do not match program");
+        assert!(
+            program.curves() <= rom.curves.len(),
+            "Shader program reads curves missing from the ROM"
+        );
+
+        let profile = profiler.program(program.name());
+
+        profile.points.add(count as u64);
+
+        let _guard = profile.runs.profile();
+
+        match profile.opcodes {
+            Some(ref mut opcodes) => dispatch_instrset!(
+                self.instrset,
+                run_program_per_opcode::<S>(program, rom, inputs, outputs, count, random, opcodes)
+            ),
+            None => dispatch_instrset!(self.instrset, run_program::<S>(program, rom, inputs, outputs, count, random)),
+        }
+    }
+
+    /// Runs a register `program` for `count` shading points, which must not draw random numbers
+    pub fn run_registers(&self, program: &RegisterProgram, rom: &ROM, inputs: &[f32], outputs: &mut [f32], count: usize) {
+        assert_eq!(inputs.len(), program.inputs() * count, "Shader inputs do not match program");
+        assert_eq!(
+            outputs.len(),
+            program.outputs().len() * count,
+            "Shader outputs do not match program"
+        );
+        assert!(
+            program.curves() <= rom.curves.len(),
+            "Shader program reads curves missing from the ROM"
+        );
+        assert_eq!(
+            program.random_dimensions(),
+            0,
+            "Shader program draws random numbers, but was not given a ShaderRandom"
+        );
+
+        dispatch_instrset!(self.instrset, run_register_program::<S>(program, rom, inputs, outputs, count, None))
+    }
+
+    /// Runs a register `program` for `count` shading points, drawing the values of any `Instruction::Random` from `random`
+    pub fn run_registers_with_random(
+        &self,
+        program: &RegisterProgram,
+        rom: &ROM,
+        inputs: &[f32],
+        outputs: &mut [f32],
+        count: usize,
+        random: &mut ShaderRandom,
+    ) {
+        assert_eq!(inputs.len(), program.inputs() * count, "Shader inputs do not match program");
+        assert_eq!(
+            outputs.len(),
+            program.outputs().len() * count,
+            "Shader outputs do not match program"
+        );
+        assert!(
+            program.curves() <= rom.curves.len(),
+            "Shader program reads curves missing from the ROM"
+        );
+        assert_eq!(random.len(), count, "Shader sample points do not match count");
+
+        dispatch_instrset!(
+            self.instrset,
+            run_register_program::<S>(program, rom, inputs, outputs, count, Some(random))
+        )
+    }
+}
+
+#[dispatch]
+fn run_program<S: Simd>(
+    program: &Program,
+    rom: &ROM,
+    inputs: &[f32],
+    outputs: &mut [f32],
+    count: usize,
+    random: Option<&mut ShaderRandom>,
+) {
+    let mut random = random;
+
+    // the stack asserts there is always one free slot above the top
+    let mut buffer = vec![Vf32::<S>::zero(); program.max_depth() + 1];
+
+    for_each_batch::<S, _>(inputs, program.inputs(), outputs, program.outputs(), count, |io, first| {
+        let mut stack = Stack::new(&mut buffer);
+        let mut random = RandomBatch::new(random.as_deref_mut(), first);
+
+        stack.push_from(&io[..program.inputs()]);
+
+        for instr in program.code() {
+            instr.eval::<S>(&mut stack, rom, &mut random);
+        }
+
+        stack.pop_to(&mut io[..program.outputs()]);
+    });
+}
+
+/// Same as `run_program`, but profiles every instruction dispatch
+#[dispatch]
+fn run_program_per_opcode<S: Simd>(
+    program: &Program,
+    rom: &ROM,
+    inputs: &[f32],
+    outputs: &mut [f32],
+    count: usize,
+    random: Option<&mut ShaderRandom>,
+    opcodes: &mut [Profiler],
+) {
+    let mut random = random;
+
+    let mut buffer = vec![Vf32::<S>::zero(); program.max_depth() + 1];
+
+    for_each_batch::<S, _>(inputs, program.inputs(), outputs, program.outputs(), count, |io, first| {
+        let mut stack = Stack::new(&mut buffer);
+        let mut random = RandomBatch::new(random.as_deref_mut(), first);
+
+        stack.push_from(&io[..program.inputs()]);
+
+        for instr in program.code() {
+            let _guard = opcodes[instr.opcode()].profile();
+
+            instr.eval::<S>(&mut stack, rom, &mut random);
+        }
+
+        stack.pop_to(&mut io[..program.outputs()]);
+    });
+}
+
+#[dispatch]
+fn run_register_program<S: Simd>(
+    program: &RegisterProgram,
+    rom: &ROM,
+    inputs: &[f32],
+    outputs: &mut [f32],
+    count: usize,
+    random: Option<&mut ShaderRandom>,
+) {
+    let mut random = random;
+
+    let mut regs = vec![Vf32::<S>::zero(); program.registers()];
+
+    for_each_batch::<S, _>(inputs, program.inputs(), outputs, program.outputs().len(), count, |io, first| {
+        let mut random = RandomBatch::new(random.as_deref_mut(), first);
+
+        regs[..program.inputs()].copy_from_slice(&io[..program.inputs()]);
+
+        for instr in program.code() {
+            instr.eval::<S>(&mut regs, rom, &mut random);
+        }
+
+        for (value, &r) in io.iter_mut().zip(program.outputs()) {
+            *value = regs[usize::from(r)];
+        }
+    });
+}
+
+/// Loads each batch of SoA inputs into the front of a buffer, calls `f` on it along with
+/// the index of the batch's first shading point, then stores the front of the buffer back out as outputs.
+///
+/// The last batch is padded with zeroes if `count` is not a multiple of the lane count.
+#[inline(always)]
+fn for_each_batch<S: Simd, F>(inputs: &[f32], num_inputs: usize, outputs: &mut [f32], num_outputs: usize, count: usize, mut f: F)
+where
+    F: FnMut(&mut [Vf32<S>], usize),
+{
+    let lanes = Vf32::<S>::NUM_ELEMENTS;
+
+    let mut io = vec![Vf32::<S>::zero(); num_inputs.max(num_outputs)];
+    let mut tail = vec![0.0f32; lanes];
+
+    let mut start = 0;
+    while start < count {
+        let n = lanes.min(count - start);
+
+        for (i, value) in io[..num_inputs].iter_mut().enumerate() {
+            let src = &inputs[i * count + start..][..n];
+
+            *value = if n == lanes {
+                Vf32::<S>::load_unaligned(src)
+            } else {
+                tail[..n].copy_from_slice(src);
+                tail[n..].fill(0.0);
+                Vf32::<S>::load_unaligned(&tail)
+            };
+        }
+
+        f(&mut io, start);
+
+        for (i, value) in io[..num_outputs].iter().enumerate() {
+            let dst = &mut outputs[i * count + start..][..n];
+
+            if n == lanes {
+                value.store_unaligned(dst);
+            } else {
+                value.store_unaligned(&mut tail);
+                dst.copy_from_slice(&tail[..n]);
+            }
+        }
+
+        start += n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::instr::{binary::BinaryOp, CurveIndex, Instruction};
+    use crate::vm::random::SamplePoint;
+
+    use raygon_sampling::{IndependentSampler, Sampler};
+
+    #[test]
+    fn test_instrsets_agree() {
+        // a + b * c
+        let program = Program::new(
+            vec![Instruction::ScalarBinary(BinaryOp::Mul), Instruction::ScalarBinary(BinaryOp::Add)],
+            3,
+            1,
+        )
+        .unwrap();
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        // not a multiple of any lane count, to test the padded tail
+        let count = 37;
+
+        let inputs: Vec<f32> = (0..3 * count).map(|i| i as f32 * 0.25).collect();
+        let expected: Vec<f32> = (0..count).map(|j| inputs[j] + inputs[count + j] * inputs[2 * count + j]).collect();
+
+        for &instrset in &[SimdInstructionSet::Scalar, SimdInstructionSet::SSE42, SimdInstructionSet::AVX2] {
+            let executor = Executor::with_instrset(instrset);
+
+            let mut outputs = vec![0.0; count];
+            executor.run(&program, &rom, &inputs, &mut outputs, count);
+
+            for (y, e) in outputs.iter().zip(&expected) {
+                assert!((y - e).abs() < 1e-3, "{:?}: {} == {}", executor.instrset(), y, e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_profiled_counts() {
+        let code = vec![Instruction::ScalarBinary(BinaryOp::Mul), Instruction::ScalarBinary(BinaryOp::Add)];
+        let program = Program::new(code, 3, 1).unwrap().with_name("mul_add");
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let count = 37;
+        let inputs = vec![0.5; 3 * count];
+        let mut outputs = vec![0.0; count];
+
+        let executor = Executor::new();
+        let batches = (count + executor.lanes() - 1) / executor.lanes();
+
+        let mut profiler = ShaderProfiler::new(false);
+        profiler.set_per_opcode(true);
+
+        for _ in 0..3 {
+            executor.run_profiled(&program, &rom, &inputs, &mut outputs, count, &mut profiler);
+        }
+
+        let profile = profiler.get("mul_add").unwrap();
+        let opcodes = profile.opcodes.as_ref().unwrap();
+        let binary = Instruction::ScalarBinary(BinaryOp::Add).opcode();
+
+        assert_eq!(profile.runs.samples(), 3);
+        assert_eq!(profile.runs.total_time(), 0);
+        assert_eq!(profile.points.get(), 3 * count as u64);
+        // the multiply and the add are both ScalarBinary
+        assert_eq!(opcodes[binary].samples(), 2 * 3 * batches as u64);
+        assert!(outputs.iter().all(|&y| y == 0.75));
+    }
+
+    #[test]
+    fn test_random_matches_sampler() {
+        // x * Random(0) + Random(2)
+        let code = vec![
+            Instruction::Random(0),
+            Instruction::ScalarBinary(BinaryOp::Mul),
+            Instruction::Random(2),
+            Instruction::ScalarBinary(BinaryOp::Add),
+        ];
+
+        let program = Program::new(code, 1, 1).unwrap();
+        let registers = RegisterProgram::translate(&program);
+
+        assert_eq!(program.random_dimensions(), 3);
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let count = 37;
+        let dimension = 10;
+
+        let points: Vec<SamplePoint> = (0..count as u32).map(|i| ((i % 5, i / 5), i * 3)).collect();
+        let inputs: Vec<f32> = (0..count).map(|i| i as f32 * 0.5).collect();
+
+        let mut sampler = IndependentSampler::new(128, 7);
+
+        let mut draw = |(pixel, index): SamplePoint, offset: u32| {
+            sampler.start_pixel_sample_at(pixel, index, dimension + offset);
+            sampler.next_1d()
+        };
+
+        let expected: Vec<f32> = points
+            .iter()
+            .zip(&inputs)
+            .map(|(&point, &x)| x * draw(point, 0) + draw(point, 2))
+            .collect();
+
+        for &instrset in &[SimdInstructionSet::Scalar, SimdInstructionSet::SSE42, SimdInstructionSet::AVX2] {
+            let executor = Executor::with_instrset(instrset);
+
+            let mut outputs = vec![0.0; count];
+            let mut random = ShaderRandom::new(&mut sampler, &points, dimension);
+            executor.run_with_random(&program, &rom, &inputs, &mut outputs, count, &mut random);
+
+            let mut register_outputs = vec![0.0; count];
+            executor.run_registers_with_random(&registers, &rom, &inputs, &mut register_outputs, count, &mut random);
+
+            for ((y, r), e) in outputs.iter().zip(&register_outputs).zip(&expected) {
+                assert!((y - e).abs() < 1e-4, "{:?}: {} == {}", executor.instrset(), y, e);
+                assert_eq!(y, r);
+            }
+        }
+    }
+
+    #[test]
+    fn test_profiled_random() {
+        // x * Random(1)
+        let code = vec![Instruction::Random(1), Instruction::ScalarBinary(BinaryOp::Mul)];
+        let program = Program::new(code, 1, 1).unwrap().with_name("random");
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let count = 37;
+        let dimension = 4;
+
+        let points: Vec<SamplePoint> = (0..count as u32).map(|i| ((i % 3, i / 3), i)).collect();
+        let inputs: Vec<f32> = (0..count).map(|i| i as f32 + 1.0).collect();
+
+        let mut sampler = IndependentSampler::new(64, 11);
+
+        let executor = Executor::new();
+        let batches = (count + executor.lanes() - 1) / executor.lanes();
+
+        let mut expected = vec![0.0; count];
+        let mut random = ShaderRandom::new(&mut sampler, &points, dimension);
+        executor.run_with_random(&program, &rom, &inputs, &mut expected, count, &mut random);
+
+        for &per_opcode in &[false, true] {
+            let mut profiler = ShaderProfiler::new(false);
+            profiler.set_per_opcode(per_opcode);
+
+            let mut outputs = vec![0.0; count];
+            executor.run_profiled_with_random(&program, &rom, &inputs, &mut outputs, count, &mut random, &mut profiler);
+
+            assert_eq!(outputs, expected);
+
+            let profile = profiler.get("random").unwrap();
+            assert_eq!(profile.points.get(), count as u64);
+
+            if per_opcode {
+                let opcodes = profile.opcodes.as_ref().unwrap();
+                assert_eq!(opcodes[Instruction::Random(1).opcode()].samples(), batches as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_program_validation() {
+        let add = Instruction::ScalarBinary(BinaryOp::Add);
+
+        assert!(Program::new(vec![add], 1, 1).is_none());
+        assert!(Program::new(vec![add], 2, 2).is_none());
+        assert_eq!(Program::new(vec![Instruction::CopyVector(2), add], 3, 8).unwrap().max_depth(), 9);
+
+        let curves = vec![Instruction::Curve(CurveIndex(3)), Instruction::Curve(CurveIndex(1))];
+        assert_eq!(Program::new(curves, 1, 1).unwrap().curves(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Shader program draws random numbers, but was not given a ShaderRandom")]
+    fn test_missing_random() {
+        let program = Program::new(vec![Instruction::Random(0)], 0, 1).unwrap();
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let mut outputs = vec![0.0; 4];
+        Executor::new().run(&program, &rom, &[], &mut outputs, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Shader program reads curves missing from the ROM")]
+    fn test_missing_curve() {
+        let program = Program::new(vec![Instruction::Curve(CurveIndex(0))], 1, 1).unwrap();
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let mut outputs = vec![0.0; 4];
+        Executor::new().run(&program, &rom, &[0.5; 4], &mut outputs, 4);
+    }
+}
@@ -0,0 +1,91 @@
+//! Peephole pass for fusing common instruction sequences into superinstructions
+//!
+//! Each superinstruction replaces two dispatches with one, and avoids the intermediate
+//! `pop_n`/`push_n` round-trip through the stack. Multiply-add sequences are fused into
+//! a single `mul_add`, so results may differ from the unfused program by one rounding.
+
+use super::instr::{binary::BinaryOp, Instruction};
+
+/// Returns the superinstruction equivalent to running `a` then `b`, if there is one
+#[inline]
+pub fn fuse_pair(a: Instruction, b: Instruction) -> Option<Instruction> {
+    Some(match (a, b) {
+        (Instruction::CopyScalar(1), Instruction::ScalarBinary(op)) => Instruction::ScalarBinaryDup(op),
+        (Instruction::ScalarBinary(BinaryOp::Mul), Instruction::ScalarBinary(BinaryOp::Add)) => Instruction::ScalarMulAdd,
+        (Instruction::ScalarBinary(BinaryOp::Mul), Instruction::ScalarBinary(BinaryOp::Sub)) => Instruction::ScalarMulSub,
+        (Instruction::VectorBinary(BinaryOp::Mul), Instruction::VectorBinary(BinaryOp::Add)) => Instruction::VectorMulAdd,
+        _ => return None,
+    })
+}
+
+/// Greedily fuses instruction pairs from left to right, and strips out any `NoOp`s
+pub fn fuse(code: &[Instruction]) -> Vec<Instruction> {
+    let mut fused = Vec::with_capacity(code.len());
+
+    let mut rest = code;
+    while let Some((&a, tail)) = rest.split_first() {
+        rest = tail;
+
+        if a == Instruction::NoOp {
+            continue;
+        }
+
+        if let Some(&b) = rest.first() {
+            if let Some(f) = fuse_pair(a, b) {
+                fused.push(f);
+                rest = &rest[1..];
+                continue;
+            }
+        }
+
+        fused.push(a);
+    }
+
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::{exec::Executor, program::Program, rom::ROM};
+
+    #[test]
+    fn test_fused_program_matches() {
+        // x0 + x1 * (x2 + x3 * (...)), squaring after each step
+        let mut code = Vec::new();
+        for _ in 0..4 {
+            code.extend_from_slice(&[
+                Instruction::ScalarBinary(BinaryOp::Mul),
+                Instruction::ScalarBinary(BinaryOp::Add),
+                Instruction::CopyScalar(1),
+                Instruction::ScalarBinary(BinaryOp::Mul),
+                Instruction::NoOp,
+            ]);
+        }
+
+        let program = Program::new(code, 9, 1).unwrap();
+        let fused = program.fused();
+
+        assert_eq!(fused.code().len(), 8);
+        assert_eq!(fused.max_depth(), program.max_depth());
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let count = 19;
+        let inputs: Vec<f32> = (0..9 * count).map(|i| (i % 7) as f32 / 7.0).collect();
+
+        let mut expected = vec![0.0; count];
+        let mut outputs = vec![0.0; count];
+
+        let executor = Executor::new();
+        executor.run(&program, &rom, &inputs, &mut expected, count);
+        executor.run(&fused, &rom, &inputs, &mut outputs, count);
+
+        for (y, e) in outputs.iter().zip(&expected) {
+            assert!((y - e).abs() < 1e-4, "{} == {}", y, e);
+        }
+    }
+}
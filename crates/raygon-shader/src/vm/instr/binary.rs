@@ -0,0 +1,86 @@
+use thermite::*;
+
+use crate::vm::dual::Dual;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+#[repr(u8)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Powf,
+    Min,
+    Max,
+    ArcTan2,
+    Hypot,
+}
+
+impl BinaryOp {
+    #[inline(always)]
+    pub fn eval<S: Simd>(self, a: Vf32<S>, b: Vf32<S>) -> Vf32<S> {
+        let zero = Vf32::<S>::zero();
+
+        let res = match self {
+            BinaryOp::Add => return a + b,
+            BinaryOp::Sub => return a - b,
+            BinaryOp::Mul => return a * b,
+            BinaryOp::Min => return a.min(b),
+            BinaryOp::Max => return a.max(b),
+            // these require checking for not-normal values
+            BinaryOp::Div => a / b,
+            BinaryOp::Rem => a % b,
+            BinaryOp::Powf => a.powf(b),
+            BinaryOp::ArcTan2 => a.atan2(b),
+            BinaryOp::Hypot => a.hypot(b),
+        };
+
+        res.is_normal().select(res, zero)
+    }
+
+    /// Evaluates the operation along with its derivatives. Values match `BinaryOp::eval`.
+    #[inline(always)]
+    pub fn eval_dual<S: Simd, const N: usize>(self, a: Dual<S, N>, b: Dual<S, N>) -> Dual<S, N> {
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+
+        let (x, y) = (a.v, b.v);
+
+        let res = match self {
+            BinaryOp::Add => return Dual::chain2(a, b, x + y, one, one),
+            BinaryOp::Sub => return Dual::chain2(a, b, x - y, one, -one),
+            BinaryOp::Mul => return Dual::chain2(a, b, x * y, y, x),
+            BinaryOp::Min => {
+                let lt = x.lt(y);
+                return Dual::chain2(a, b, x.min(y), lt.select(one, zero), lt.select(zero, one));
+            }
+            BinaryOp::Max => {
+                let gt = x.gt(y);
+                return Dual::chain2(a, b, x.max(y), gt.select(one, zero), gt.select(zero, one));
+            }
+            BinaryOp::Div => {
+                let q = x / y;
+                let inv_y = one / y;
+                Dual::chain2(a, b, q, inv_y, -q * inv_y)
+            }
+            // x % y = x - y * trunc(x / y)
+            BinaryOp::Rem => Dual::chain2(a, b, x % y, one, -(x / y).trunc()),
+            BinaryOp::Powf => {
+                let p = x.powf(y);
+                Dual::chain2(a, b, p, y * x.powf(y - one), p * x.ln())
+            }
+            BinaryOp::ArcTan2 => {
+                let inv_r2 = one / x.mul_add(x, y * y);
+                Dual::chain2(a, b, x.atan2(y), y * inv_r2, -x * inv_r2)
+            }
+            BinaryOp::Hypot => {
+                let h = x.hypot(y);
+                let inv_h = one / h;
+                Dual::chain2(a, b, h, x * inv_h, y * inv_h)
+            }
+        };
+
+        res.flush()
+    }
+}
@@ -1,5 +1,7 @@
 use thermite::*;
 
+use crate::vm::dual::Dual;
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
 #[repr(u8)]
 pub enum CompareMode {
@@ -29,4 +31,10 @@ impl CompareMode {
 
         res.select(Vf32::<S>::one(), Vf32::<S>::zero())
     }
+
+    /// Comparisons are piecewise-constant, so their derivatives are always zero
+    #[inline(always)]
+    pub fn compare_dual<S: Simd, const N: usize>(self, a: Dual<S, N>, b: Dual<S, N>) -> Dual<S, N> {
+        Dual::constant(self.compare::<S>(a.v, b.v))
+    }
 }
@@ -0,0 +1,232 @@
+use thermite::*;
+
+use crate::vm;
+
+use vm::{random::RandomBatch, rom::ROM, stack::Stack};
+
+pub mod binary;
+pub mod compare;
+pub mod unary;
+
+macro_rules! decl_wrappers {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[repr(transparent)]
+            pub struct $name(pub $t);
+
+            impl $name {
+                #[inline]
+                pub fn new(index: usize) -> $name {
+                    debug_assert!(index < (<$t>::MAX as usize));
+
+                    $name(index as $t)
+                }
+            }
+
+            impl From<$name> for usize {
+                #[inline(always)]
+                fn from(index: $name) -> usize {
+                    index.0 as usize
+                }
+            }
+
+            impl From<usize> for $name {
+                #[inline]
+                fn from(index: usize) -> $name {
+                    $name::new(index)
+                }
+            }
+        )*
+    }
+}
+
+decl_wrappers! {
+    ScalarIndex: u16,
+    TextureIndex: u16,
+    CurveIndex: u8,
+    ColorModelIndex: u8,
+    RegisterIndex: u16
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    NoOp,
+    ScalarBinary(binary::BinaryOp),
+    ScalarUnary(unary::UnaryOp),
+    ScalarCompare(compare::CompareMode),
+    VectorBinary(binary::BinaryOp),
+    VectorUnary(unary::UnaryOp),
+    VectorCompare(compare::CompareMode),
+    VectorSum,
+    VectorProduct,
+    VectorMin,
+    VectorMax,
+    VectorSplat,
+    CopyScalar(u8),
+    CopyVector(u8),
+    Curve(CurveIndex),
+    /// Pushes a uniform random value, from the shader's sampler dimension plus the operand. See `vm::random`
+    Random(u8),
+
+    // Superinstructions, see `vm::fuse`
+    /// `CopyScalar(1)` followed by `ScalarBinary(op)`, computing `x op x`
+    ScalarBinaryDup(binary::BinaryOp),
+    /// `ScalarBinary(Mul)` followed by `ScalarBinary(Add)`, computing `a + b * c`
+    ScalarMulAdd,
+    /// `ScalarBinary(Mul)` followed by `ScalarBinary(Sub)`, computing `a - b * c`
+    ScalarMulSub,
+    /// `VectorBinary(Mul)` followed by `VectorBinary(Add)`, computing `A * B + C`
+    VectorMulAdd,
+}
+
+impl Instruction {
+    /// Number of distinct opcodes, ignoring operands
+    pub const NUM_OPCODES: usize = 20;
+
+    /// Names of each opcode, indexed by `Instruction::opcode`
+    pub const OPCODE_NAMES: [&'static str; Instruction::NUM_OPCODES] = [
+        "NoOp",
+        "ScalarBinary",
+        "ScalarUnary",
+        "ScalarCompare",
+        "VectorBinary",
+        "VectorUnary",
+        "VectorCompare",
+        "VectorSum",
+        "VectorProduct",
+        "VectorMin",
+        "VectorMax",
+        "VectorSplat",
+        "CopyScalar",
+        "CopyVector",
+        "Curve",
+        "ScalarBinaryDup",
+        "ScalarMulAdd",
+        "ScalarMulSub",
+        "VectorMulAdd",
+        "Random",
+    ];
+
+    /// Index of the instruction kind, ignoring operands
+    #[rustfmt::skip]
+    pub fn opcode(self) -> usize {
+        match self {
+            Instruction::NoOp               => 0,
+            Instruction::ScalarBinary(_)    => 1,
+            Instruction::ScalarUnary(_)     => 2,
+            Instruction::ScalarCompare(_)   => 3,
+            Instruction::VectorBinary(_)    => 4,
+            Instruction::VectorUnary(_)     => 5,
+            Instruction::VectorCompare(_)   => 6,
+            Instruction::VectorSum          => 7,
+            Instruction::VectorProduct      => 8,
+            Instruction::VectorMin          => 9,
+            Instruction::VectorMax          => 10,
+            Instruction::VectorSplat        => 11,
+            Instruction::CopyScalar(_)      => 12,
+            Instruction::CopyVector(_)      => 13,
+            Instruction::Curve(_)           => 14,
+            Instruction::ScalarBinaryDup(_) => 15,
+            Instruction::ScalarMulAdd       => 16,
+            Instruction::ScalarMulSub       => 17,
+            Instruction::VectorMulAdd       => 18,
+            Instruction::Random(_)          => 19,
+        }
+    }
+
+    /// Returns how many values the instruction consumes from the top of the stack, and how many it pushes back.
+    ///
+    /// Instructions which only peek at the stack, like `CopyScalar`, are treated as consuming
+    /// those values and pushing them back unchanged.
+    #[rustfmt::skip]
+    pub fn stack_effect(self) -> (usize, usize) {
+        match self {
+            Instruction::NoOp               => (0, 0),
+            Instruction::ScalarBinary(_)    => (2, 1),
+            Instruction::ScalarUnary(_)     => (1, 1),
+            Instruction::ScalarCompare(_)   => (2, 1),
+            Instruction::VectorBinary(_)    => (6, 3),
+            Instruction::VectorUnary(_)     => (3, 3),
+            Instruction::VectorCompare(_)   => (6, 3),
+            Instruction::VectorSum          => (3, 1),
+            Instruction::VectorProduct      => (3, 1),
+            Instruction::VectorMin          => (3, 1),
+            Instruction::VectorMax          => (3, 1),
+            Instruction::VectorSplat        => (1, 3),
+            Instruction::CopyScalar(count)  => (1, 1 + count as usize),
+            Instruction::CopyVector(count)  => (3, 3 + count as usize * 3),
+            Instruction::Curve(_)           => (1, 1),
+            Instruction::ScalarBinaryDup(_) => (1, 1),
+            Instruction::ScalarMulAdd       => (3, 1),
+            Instruction::ScalarMulSub       => (3, 1),
+            Instruction::VectorMulAdd       => (9, 3),
+            Instruction::Random(_)          => (0, 1),
+        }
+    }
+
+    pub fn eval<S: Simd>(self, stack: &mut Stack<S>, rom: &ROM, random: &mut RandomBatch) {
+        match self {
+            Instruction::NoOp => {}
+
+            Instruction::ScalarUnary(op) => stack.peek_one_mut(|x| *x = op.eval::<S>(*x)),
+            Instruction::ScalarBinary(op) => stack.reduce(|[a, b]| op.eval::<S>(a, b)),
+
+            Instruction::VectorUnary(op) => stack.map(|[x, y, z]| [op.eval::<S>(x), op.eval::<S>(y), op.eval::<S>(z)]),
+            Instruction::VectorBinary(op) => {
+                // map two 3-vectors into one 3-vector, where A is the top-most vector
+                stack.map(|[xb, yb, zb, xa, ya, za]: [Vf32<S>; 6]| [op.eval::<S>(xa, xb), op.eval::<S>(ya, yb), op.eval::<S>(za, zb)]);
+            }
+
+            Instruction::ScalarCompare(mode) => stack.reduce(|[a, b]| mode.compare::<S>(a, b)),
+            Instruction::VectorCompare(mode) => stack.map(|[xb, yb, zb, xa, ya, za]: [Vf32<S>; 6]| {
+                [mode.compare::<S>(xa, xb), mode.compare::<S>(ya, yb), mode.compare::<S>(za, zb)]
+            }),
+
+            Instruction::VectorSum => stack.reduce(|[x, y, z]| x + y + z),
+            Instruction::VectorProduct => stack.reduce(|[x, y, z]| x * y * z),
+            Instruction::VectorMin => stack.reduce(|[x, y, z]| x.min(y).min(z)),
+            Instruction::VectorMax => stack.reduce(|[x, y, z]| x.max(y).max(z)),
+            Instruction::VectorSplat => stack.map(|[x]| [x, x, x]),
+
+            Instruction::CopyScalar(count) => {
+                let value = stack.peek_one(|x| *x);
+                stack.push(count as usize, |head| head.fill(value));
+            }
+            Instruction::CopyVector(count) => {
+                let xyz = stack.peek_n::<3>();
+
+                stack.push(count as usize * 3, |head| {
+                    let mut i = 0;
+                    for l in head {
+                        *l = xyz[i];
+
+                        // cycle through x, y, z, more efficient than %/mod 3
+                        i += 1;
+                        if i == 3 {
+                            i = 0;
+                        }
+                    }
+                    // reference behavior
+                    //s.chunks_exact_mut(3).for_each(|chunk| {
+                    //    chunk.copy_from_slice(&xyz);
+                    //})
+                })
+            }
+
+            Instruction::Curve(idx) => stack.peek_one_mut(|x| *x = rom.get_curve(idx).eval::<S>(*x)),
+
+            Instruction::Random(offset) => {
+                let value = random.lanes::<S>(offset);
+                stack.push(1, |head| head.fill(value));
+            }
+
+            Instruction::ScalarBinaryDup(op) => stack.peek_one_mut(|x| *x = op.eval::<S>(*x, *x)),
+            Instruction::ScalarMulAdd => stack.reduce(|[a, b, c]| b.mul_add(c, a)),
+            Instruction::ScalarMulSub => stack.reduce(|[a, b, c]| b.nmul_add(c, a)),
+            Instruction::VectorMulAdd => {
+                stack.map(|[xc, yc, zc, xb, yb, zb, xa, ya, za]: [Vf32<S>; 9]| [xa.mul_add(xb, xc), ya.mul_add(yb, yc), za.mul_add(zb, zc)])
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use thermite::*;
+
+use crate::vm::dual::Dual;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+#[repr(u8)]
+pub enum UnaryOp {
+    Saturate,
+    Neg,
+    Abs,
+    Sqrt,
+    Square,
+    Sign,
+    Ln,
+    Sin,
+    Cos,
+    Tan,
+    ArcSin,
+    ArcCos,
+    ArcTan,
+    Trunc,
+    Fract,
+    Round,
+    Floor,
+    Ceil,
+    ToDegrees,
+    ToRadians,
+    Invert,
+    Heavyside,
+}
+
+impl UnaryOp {
+    #[inline(always)]
+    #[rustfmt::skip]
+    pub fn eval<S: Simd>(self, x: Vf32<S>) -> Vf32<S> {
+        use std::f32::consts::PI;
+
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+
+        let res = match self {
+            UnaryOp::Saturate => return x.clamp(zero, one),
+            UnaryOp::Neg => return -x,
+            UnaryOp::Abs => return x.abs(),
+            UnaryOp::Sqrt => return x.is_negative().select(zero, x.sqrt()),
+            UnaryOp::Square => return x * x,
+            UnaryOp::Sign => return x.signum(),
+            UnaryOp::Ln => return x.is_negative().select(zero, x.ln()),
+            UnaryOp::Sin => x.sin(),
+            UnaryOp::Cos => x.cos(),
+            UnaryOp::Tan => x.tan(),
+            UnaryOp::ArcSin => x.asin(),
+            UnaryOp::ArcCos => x.acos(),
+            UnaryOp::ArcTan => x.atan(),
+            UnaryOp::Trunc => return x.trunc(),
+            UnaryOp::Fract => return x.fract(),
+            UnaryOp::Round => return x.round(),
+            UnaryOp::Floor => return x.floor(),
+            UnaryOp::Ceil => return x.ceil(),
+            UnaryOp::ToDegrees => return x * Vf32::<S>::splat(180.0 / PI),
+            UnaryOp::ToRadians => return x * Vf32::<S>::splat(PI / 180.0),
+            UnaryOp::Invert => return one - x,
+            UnaryOp::Heavyside => return x.is_negative().select(zero, one) ,
+        };
+
+        res.is_normal().select(res, zero)
+    }
+
+    /// Evaluates the operation along with its derivative. Values match `UnaryOp::eval`.
+    #[inline(always)]
+    #[rustfmt::skip]
+    pub fn eval_dual<S: Simd, const N: usize>(self, x: Dual<S, N>) -> Dual<S, N> {
+        use std::f32::consts::PI;
+
+        let zero = Vf32::<S>::zero();
+        let one = Vf32::<S>::one();
+
+        let v = x.v;
+
+        let res = match self {
+            UnaryOp::Saturate => return x.chain(v.clamp(zero, one), (v.gt(zero) & v.lt(one)).select(one, zero)),
+            UnaryOp::Neg => return x.chain(-v, -one),
+            UnaryOp::Abs => return x.chain(v.abs(), v.signum()),
+            UnaryOp::Sqrt => {
+                let s = v.sqrt();
+                let negative = v.is_negative();
+                return x.chain(negative.select(zero, s), negative.select(zero, Vf32::<S>::splat(0.5) / s)).flush_derivatives();
+            }
+            UnaryOp::Square => return x.chain(v * v, v + v),
+            UnaryOp::Sign => return x.chain(v.signum(), zero),
+            UnaryOp::Ln => {
+                let negative = v.is_negative();
+                return x.chain(negative.select(zero, v.ln()), negative.select(zero, one / v)).flush_derivatives();
+            }
+            UnaryOp::Sin => x.chain(v.sin(), v.cos()),
+            UnaryOp::Cos => x.chain(v.cos(), -v.sin()),
+            UnaryOp::Tan => {
+                let t = v.tan();
+                x.chain(t, t.mul_add(t, one))
+            }
+            UnaryOp::ArcSin => x.chain(v.asin(), v.nmul_add(v, one).invsqrt()),
+            UnaryOp::ArcCos => x.chain(v.acos(), -v.nmul_add(v, one).invsqrt()),
+            UnaryOp::ArcTan => x.chain(v.atan(), one / v.mul_add(v, one)),
+            UnaryOp::Trunc => return x.chain(v.trunc(), zero),
+            UnaryOp::Fract => return x.chain(v.fract(), one),
+            UnaryOp::Round => return x.chain(v.round(), zero),
+            UnaryOp::Floor => return x.chain(v.floor(), zero),
+            UnaryOp::Ceil => return x.chain(v.ceil(), zero),
+            UnaryOp::ToDegrees => return x.chain(v * Vf32::<S>::splat(180.0 / PI), Vf32::<S>::splat(180.0 / PI)),
+            UnaryOp::ToRadians => return x.chain(v * Vf32::<S>::splat(PI / 180.0), Vf32::<S>::splat(PI / 180.0)),
+            UnaryOp::Invert => return x.chain(one - v, -one),
+            UnaryOp::Heavyside => return x.chain(v.is_negative().select(zero, one), zero),
+        };
+
+        res.flush()
+    }
+}
@@ -0,0 +1,10 @@
+pub mod dual;
+pub mod exec;
+pub mod fuse;
+pub mod instr;
+pub mod profile;
+pub mod program;
+pub mod random;
+pub mod register;
+pub mod rom;
+pub mod stack;
@@ -0,0 +1,152 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::sync::Arc;
+
+use clocksource::Clocksource;
+use raygon_profiler::{Counter, Profiler, SplitDuration};
+
+use super::instr::Instruction;
+
+/// Execution statistics for a single program
+#[derive(Debug)]
+pub struct ProgramProfile {
+    /// Times each call to `Executor::run_profiled`
+    pub runs: Profiler,
+    /// Total shading points evaluated
+    pub points: Counter,
+    /// Times each dispatch of an opcode, indexed by `Instruction::opcode`, if enabled
+    pub opcodes: Option<Vec<Profiler>>,
+}
+
+/// Collects shader execution statistics keyed by program name
+///
+/// Execution counts are always recorded, while timing is only recorded when active.
+/// Timing can be toggled at any point with `set_active`, which is forwarded to every `Profiler`.
+#[derive(Debug)]
+pub struct ShaderProfiler {
+    clock: Option<Arc<Clocksource>>,
+    per_opcode: bool,
+    programs: HashMap<String, ProgramProfile>,
+}
+
+impl ShaderProfiler {
+    pub fn new(active: bool) -> ShaderProfiler {
+        ShaderProfiler {
+            clock: if active { Some(Arc::new(Clocksource::new())) } else { None },
+            per_opcode: false,
+            programs: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.clock.is_some()
+    }
+
+    /// Enables or disables timing for all programs, sharing one clock between them
+    pub fn set_active(&mut self, active: bool) {
+        self.clock = match (self.clock.take(), active) {
+            (Some(clock), true) => Some(clock),
+            (_, false) => None,
+            (_, true) => Some(Arc::new(Clocksource::new())),
+        };
+
+        for profile in self.programs.values_mut() {
+            profile.runs.set_clock(self.clock.clone());
+
+            for op in profile.opcodes.iter_mut().flatten() {
+                op.set_clock(self.clock.clone());
+            }
+        }
+    }
+
+    /// Enables or disables recording of each instruction dispatch.
+    ///
+    /// This adds overhead to every instruction, so it is disabled by default.
+    pub fn set_per_opcode(&mut self, per_opcode: bool) {
+        self.per_opcode = per_opcode;
+
+        for profile in self.programs.values_mut() {
+            profile.opcodes = match (profile.opcodes.take(), per_opcode) {
+                (_, false) => None,
+                (Some(opcodes), true) => Some(opcodes),
+                (None, true) => Some(Self::new_opcodes(&self.clock)),
+            };
+        }
+    }
+
+    fn new_opcodes(clock: &Option<Arc<Clocksource>>) -> Vec<Profiler> {
+        (0..Instruction::NUM_OPCODES)
+            .map(|_| Profiler::new_with_clock(clock.clone()))
+            .collect()
+    }
+
+    /// Returns the profile for the named program, creating it if necessary
+    pub fn program(&mut self, name: &str) -> &mut ProgramProfile {
+        let (clock, per_opcode) = (&self.clock, self.per_opcode);
+
+        self.programs.entry(name.to_owned()).or_insert_with(|| ProgramProfile {
+            runs: Profiler::new_with_clock(clock.clone()),
+            points: Counter::new(),
+            opcodes: if per_opcode { Some(Self::new_opcodes(clock)) } else { None },
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ProgramProfile> {
+        self.programs.get(name)
+    }
+
+    /// Clears all recorded statistics
+    pub fn reset(&mut self) {
+        self.programs.clear();
+    }
+
+    /// Report of all programs, most expensive first
+    pub fn report(&self) -> ProfileReport<'_> {
+        let mut programs: Vec<_> = self.programs.iter().map(|(name, profile)| (name.as_str(), profile)).collect();
+        programs.sort_by(|a, b| b.1.runs.total_time().cmp(&a.1.runs.total_time()).then(a.0.cmp(b.0)));
+
+        ProfileReport { programs }
+    }
+}
+
+/// Human-readable summary of a `ShaderProfiler`
+pub struct ProfileReport<'a> {
+    pub programs: Vec<(&'a str, &'a ProgramProfile)>,
+}
+
+impl Display for ProfileReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, profile) in &self.programs {
+            let name = if name.is_empty() { "<unnamed>" } else { name };
+
+            writeln!(
+                f,
+                "{}: {} runs, {} points, {} total, {} per run",
+                name,
+                profile.runs.samples(),
+                profile.points,
+                SplitDuration::split_ns(profile.runs.total_time()),
+                SplitDuration::split_ns(profile.runs.get_average()),
+            )?;
+
+            if let Some(ref opcodes) = profile.opcodes {
+                let mut opcodes: Vec<_> = opcodes.iter().enumerate().filter(|(_, op)| op.samples() > 0).collect();
+                opcodes.sort_by_key(|(_, op)| Reverse(op.total_time()));
+
+                for (opcode, op) in opcodes {
+                    writeln!(
+                        f,
+                        "    {:<16} {:>12} dispatches, {} total",
+                        Instruction::OPCODE_NAMES[opcode],
+                        op.samples(),
+                        SplitDuration::split_ns(op.total_time()),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use super::{fuse::fuse, instr::Instruction};
+
+/// A validated sequence of stack-machine instructions
+///
+/// Programs start with their inputs pushed onto the stack, in order, and must leave exactly
+/// their outputs on the stack when finished, with the last output on top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    name: String,
+    code: Vec<Instruction>,
+    inputs: usize,
+    outputs: usize,
+    max_depth: usize,
+    curves: usize,
+    random_dimensions: u32,
+}
+
+impl Program {
+    /// Validates the stack effects of `code` and computes the maximum stack depth it requires.
+    ///
+    /// Returns `None` if the code would underflow the stack or does not leave exactly `outputs` values on it.
+    /// Curve indices are checked against the ROM when the program is run.
+    pub fn new(code: Vec<Instruction>, inputs: usize, outputs: usize) -> Option<Program> {
+        let mut depth = inputs;
+        let mut max_depth = depth;
+        let mut curves = 0;
+        let mut random_dimensions = 0;
+
+        for instr in &code {
+            let (pops, pushes) = instr.stack_effect();
+
+            if depth < pops {
+                return None;
+            }
+
+            depth = depth - pops + pushes;
+            max_depth = max_depth.max(depth);
+
+            match *instr {
+                Instruction::Curve(idx) => curves = curves.max(usize::from(idx) + 1),
+                Instruction::Random(offset) => random_dimensions = random_dimensions.max(offset as u32 + 1),
+                _ => {}
+            }
+        }
+
+        if depth != outputs {
+            return None;
+        }
+
+        Some(Program {
+            name: String::new(),
+            code,
+            inputs,
+            outputs,
+            max_depth,
+            curves,
+            random_dimensions,
+        })
+    }
+
+    /// Sets the name used to identify the program in profiling reports
+    pub fn with_name(mut self, name: impl Into<String>) -> Program {
+        self.name = name.into();
+        self
+    }
+
+    /// Returns an equivalent program with common instruction sequences fused into superinstructions
+    pub fn fused(&self) -> Program {
+        Program::new(fuse(&self.code), self.inputs, self.outputs)
+            .expect("Fused program changed stack effects")
+            .with_name(self.name.clone())
+    }
+
+    #[inline(always)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline(always)]
+    pub fn code(&self) -> &[Instruction] {
+        &self.code
+    }
+
+    /// Number of values pushed onto the stack before the program starts
+    #[inline(always)]
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    /// Number of values left on the stack after the program finishes
+    #[inline(always)]
+    pub fn outputs(&self) -> usize {
+        self.outputs
+    }
+
+    /// Deepest the stack will get while running the program
+    #[inline(always)]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Number of ROM curves the program needs, one past the highest curve index it reads
+    #[inline(always)]
+    pub fn curves(&self) -> usize {
+        self.curves
+    }
+
+    /// Number of sampler dimensions the program draws random numbers from, see `vm::random`
+    #[inline(always)]
+    pub fn random_dimensions(&self) -> u32 {
+        self.random_dimensions
+    }
+}
@@ -0,0 +1,112 @@
+//! Random numbers for stochastic shaders
+//!
+//! `Instruction::Random(k)` reads dimension `dimension + k` of the shading point's own pixel sample,
+//! so results depend only on the pixel, sample index and instruction, and never on how shading points
+//! were batched or which instruction set ran them. Integrators should reserve a block of
+//! `Program::random_dimensions` sampler dimensions for the shader, starting at `dimension`.
+
+use thermite::*;
+
+use raygon_sampling::{Pixel, Sampler};
+
+/// Pixel and sample index of a shading point
+pub type SamplePoint = (Pixel, u32);
+
+/// Widest supported vector, in lanes
+const MAX_LANES: usize = 16;
+
+/// Draws the values of `Instruction::Random` from a `Sampler`
+pub struct ShaderRandom<'a> {
+    sampler: &'a mut dyn Sampler,
+    points: &'a [SamplePoint],
+    dimension: u32,
+}
+
+impl<'a> ShaderRandom<'a> {
+    /// Creates a source for the shading points `points`, with the shader's dimensions starting at `dimension`
+    pub fn new(sampler: &'a mut dyn Sampler, points: &'a [SamplePoint], dimension: u32) -> ShaderRandom<'a> {
+        ShaderRandom {
+            sampler,
+            points,
+            dimension,
+        }
+    }
+
+    /// Number of shading points
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Value of `Instruction::Random(offset)` at shading point `point`
+    #[inline]
+    pub fn value(&mut self, point: usize, offset: u8) -> f32 {
+        let (pixel, sample_index) = self.points[point];
+
+        self.sampler
+            .start_pixel_sample_at(pixel, sample_index, self.dimension + offset as u32);
+        self.sampler.next_1d()
+    }
+
+    /// Values of `Instruction::Random(offset)` at the shading points starting at `first`,
+    /// padded with zeroes past the last shading point
+    #[inline]
+    pub fn lanes<S: Simd>(&mut self, first: usize, offset: u8) -> Vf32<S> {
+        let lanes = Vf32::<S>::NUM_ELEMENTS;
+        debug_assert!(lanes <= MAX_LANES);
+
+        let mut values = [0.0f32; MAX_LANES];
+        for (lane, value) in values[..lanes.min(self.len().saturating_sub(first))].iter_mut().enumerate() {
+            *value = self.value(first + lane, offset);
+        }
+
+        Vf32::<S>::load_unaligned(&values[..lanes])
+    }
+}
+
+/// Random numbers for the current batch of shading points, if the program was given a source of them
+pub struct RandomBatch<'r, 'a> {
+    random: Option<&'r mut ShaderRandom<'a>>,
+    first: usize,
+}
+
+impl<'r, 'a> RandomBatch<'r, 'a> {
+    /// The batch of shading points starting at `first`
+    #[inline(always)]
+    pub fn new(random: Option<&'r mut ShaderRandom<'a>>, first: usize) -> RandomBatch<'r, 'a> {
+        RandomBatch { random, first }
+    }
+
+    /// A batch without random numbers, for programs that never draw any
+    #[inline(always)]
+    pub fn none() -> RandomBatch<'r, 'a> {
+        RandomBatch { random: None, first: 0 }
+    }
+
+    /// Whether the batch has no source of random numbers, like one made with `RandomBatch::none`
+    #[inline(always)]
+    pub fn is_none(&self) -> bool {
+        self.random.is_none()
+    }
+
+    #[inline(always)]
+    pub fn lanes<S: Simd>(&mut self, offset: u8) -> Vf32<S> {
+        match self.random {
+            Some(ref mut random) => random.lanes::<S>(self.first, offset),
+            None => {
+                #[inline(never)]
+                #[cold]
+                fn on_missing_random() -> ! {
+                    panic!("Shader program draws random numbers, but was not given a ShaderRandom")
+                }
+
+                on_missing_random()
+            }
+        }
+    }
+}
@@ -0,0 +1,482 @@
+//! Register-based execution format
+//!
+//! Each stack slot is mapped to a register of the same index, so the register file is only
+//! as large as the deepest point of the stack program. Copies and splats are resolved at
+//! translation time by aliasing registers, so they cost nothing at runtime.
+//!
+//! A stack slot only ever aliases a register at or below its own index, and results are
+//! always written to the registers of newly pushed slots, so no instruction can overwrite
+//! a value still in use further down the stack.
+
+use thermite::*;
+
+use raygon_core::slice::SliceExt;
+
+use super::instr::{binary::BinaryOp, compare::CompareMode, unary::UnaryOp, CurveIndex, Instruction, RegisterIndex};
+use super::{program::Program, random::RandomBatch, rom::ROM};
+
+type Reg = RegisterIndex;
+
+/// Three-address instruction over a register file of `Vf32<S>`
+///
+/// Operands follow the stack machine, so binary ops compute `op(a, b)`
+/// and vector ops apply to each of the three components.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegisterInstruction {
+    ScalarUnary {
+        op: UnaryOp,
+        dst: Reg,
+        src: Reg,
+    },
+    ScalarBinary {
+        op: BinaryOp,
+        dst: Reg,
+        a: Reg,
+        b: Reg,
+    },
+    ScalarCompare {
+        mode: CompareMode,
+        dst: Reg,
+        a: Reg,
+        b: Reg,
+    },
+    /// `a + b * c`
+    ScalarMulAdd {
+        dst: Reg,
+        a: Reg,
+        b: Reg,
+        c: Reg,
+    },
+    /// `a - b * c`
+    ScalarMulSub {
+        dst: Reg,
+        a: Reg,
+        b: Reg,
+        c: Reg,
+    },
+    VectorUnary {
+        op: UnaryOp,
+        dst: [Reg; 3],
+        src: [Reg; 3],
+    },
+    VectorBinary {
+        op: BinaryOp,
+        dst: [Reg; 3],
+        a: [Reg; 3],
+        b: [Reg; 3],
+    },
+    VectorCompare {
+        mode: CompareMode,
+        dst: [Reg; 3],
+        a: [Reg; 3],
+        b: [Reg; 3],
+    },
+    /// `a * b + c`
+    VectorMulAdd {
+        dst: [Reg; 3],
+        a: [Reg; 3],
+        b: [Reg; 3],
+        c: [Reg; 3],
+    },
+    VectorSum {
+        dst: Reg,
+        src: [Reg; 3],
+    },
+    VectorProduct {
+        dst: Reg,
+        src: [Reg; 3],
+    },
+    VectorMin {
+        dst: Reg,
+        src: [Reg; 3],
+    },
+    VectorMax {
+        dst: Reg,
+        src: [Reg; 3],
+    },
+    Curve {
+        idx: CurveIndex,
+        dst: Reg,
+        src: Reg,
+    },
+    Random {
+        offset: u8,
+        dst: Reg,
+    },
+}
+
+#[inline(always)]
+fn get<S: Simd>(regs: &[Vf32<S>], r: Reg) -> Vf32<S> {
+    unsafe { *regs.get_unchecked_debug_checked(r.into()) }
+}
+
+#[inline(always)]
+fn get3<S: Simd>(regs: &[Vf32<S>], r: [Reg; 3]) -> [Vf32<S>; 3] {
+    [get::<S>(regs, r[0]), get::<S>(regs, r[1]), get::<S>(regs, r[2])]
+}
+
+#[inline(always)]
+fn set<S: Simd>(regs: &mut [Vf32<S>], r: Reg, value: Vf32<S>) {
+    unsafe { *regs.get_unchecked_debug_checked_mut(r.into()) = value }
+}
+
+#[inline(always)]
+fn set3<S: Simd>(regs: &mut [Vf32<S>], r: [Reg; 3], values: [Vf32<S>; 3]) {
+    set::<S>(regs, r[0], values[0]);
+    set::<S>(regs, r[1], values[1]);
+    set::<S>(regs, r[2], values[2]);
+}
+
+impl RegisterInstruction {
+    /// Evaluates the instruction. All operands are read before any result is written.
+    #[inline(always)]
+    pub fn eval<S: Simd>(self, regs: &mut [Vf32<S>], rom: &ROM, random: &mut RandomBatch) {
+        use RegisterInstruction as R;
+
+        match self {
+            R::ScalarUnary { op, dst, src } => {
+                let x = get::<S>(regs, src);
+                set::<S>(regs, dst, op.eval::<S>(x));
+            }
+            R::ScalarBinary { op, dst, a, b } => {
+                let (a, b) = (get::<S>(regs, a), get::<S>(regs, b));
+                set::<S>(regs, dst, op.eval::<S>(a, b));
+            }
+            R::ScalarCompare { mode, dst, a, b } => {
+                let (a, b) = (get::<S>(regs, a), get::<S>(regs, b));
+                set::<S>(regs, dst, mode.compare::<S>(a, b));
+            }
+            R::ScalarMulAdd { dst, a, b, c } => {
+                let (a, b, c) = (get::<S>(regs, a), get::<S>(regs, b), get::<S>(regs, c));
+                set::<S>(regs, dst, b.mul_add(c, a));
+            }
+            R::ScalarMulSub { dst, a, b, c } => {
+                let (a, b, c) = (get::<S>(regs, a), get::<S>(regs, b), get::<S>(regs, c));
+                set::<S>(regs, dst, b.nmul_add(c, a));
+            }
+            R::VectorUnary { op, dst, src } => {
+                let [x, y, z] = get3::<S>(regs, src);
+                set3::<S>(regs, dst, [op.eval::<S>(x), op.eval::<S>(y), op.eval::<S>(z)]);
+            }
+            R::VectorBinary { op, dst, a, b } => {
+                let ([xa, ya, za], [xb, yb, zb]) = (get3::<S>(regs, a), get3::<S>(regs, b));
+                set3::<S>(regs, dst, [op.eval::<S>(xa, xb), op.eval::<S>(ya, yb), op.eval::<S>(za, zb)]);
+            }
+            R::VectorCompare { mode, dst, a, b } => {
+                let ([xa, ya, za], [xb, yb, zb]) = (get3::<S>(regs, a), get3::<S>(regs, b));
+                set3::<S>(
+                    regs,
+                    dst,
+                    [mode.compare::<S>(xa, xb), mode.compare::<S>(ya, yb), mode.compare::<S>(za, zb)],
+                );
+            }
+            R::VectorMulAdd { dst, a, b, c } => {
+                let ([xa, ya, za], [xb, yb, zb], [xc, yc, zc]) = (get3::<S>(regs, a), get3::<S>(regs, b), get3::<S>(regs, c));
+                set3::<S>(regs, dst, [xa.mul_add(xb, xc), ya.mul_add(yb, yc), za.mul_add(zb, zc)]);
+            }
+            R::VectorSum { dst, src } => {
+                let [x, y, z] = get3::<S>(regs, src);
+                set::<S>(regs, dst, x + y + z);
+            }
+            R::VectorProduct { dst, src } => {
+                let [x, y, z] = get3::<S>(regs, src);
+                set::<S>(regs, dst, x * y * z);
+            }
+            R::VectorMin { dst, src } => {
+                let [x, y, z] = get3::<S>(regs, src);
+                set::<S>(regs, dst, x.min(y).min(z));
+            }
+            R::VectorMax { dst, src } => {
+                let [x, y, z] = get3::<S>(regs, src);
+                set::<S>(regs, dst, x.max(y).max(z));
+            }
+            R::Curve { idx, dst, src } => {
+                let x = get::<S>(regs, src);
+                set::<S>(regs, dst, rom.get_curve(idx).eval::<S>(x));
+            }
+            R::Random { offset, dst } => {
+                set::<S>(regs, dst, random.lanes::<S>(offset));
+            }
+        }
+    }
+}
+
+/// A program translated from stack bytecode into register instructions
+///
+/// Inputs are placed in the first registers, in order, before the program starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisterProgram {
+    code: Vec<RegisterInstruction>,
+    inputs: usize,
+    outputs: Vec<Reg>,
+    registers: usize,
+    curves: usize,
+    random_dimensions: u32,
+}
+
+impl RegisterProgram {
+    #[inline(always)]
+    pub fn code(&self) -> &[RegisterInstruction] {
+        &self.code
+    }
+
+    #[inline(always)]
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    /// Registers holding each output once the program finishes. These may alias each other.
+    #[inline(always)]
+    pub fn outputs(&self) -> &[Reg] {
+        &self.outputs
+    }
+
+    /// Size of the register file needed to run the program
+    #[inline(always)]
+    pub fn registers(&self) -> usize {
+        self.registers
+    }
+
+    /// Number of ROM curves the program needs, as for the stack program it was translated from
+    #[inline(always)]
+    pub fn curves(&self) -> usize {
+        self.curves
+    }
+
+    /// Number of sampler dimensions the program draws random numbers from, as for the stack program
+    #[inline(always)]
+    pub fn random_dimensions(&self) -> u32 {
+        self.random_dimensions
+    }
+
+    /// Translates a validated stack program, including any superinstructions
+    pub fn translate(program: &Program) -> RegisterProgram {
+        let mut t = Translator {
+            stack: (0..program.inputs()).map(Reg::new).collect(),
+            code: Vec::with_capacity(program.code().len()),
+        };
+
+        for &instr in program.code() {
+            t.translate(instr);
+        }
+
+        debug_assert_eq!(t.stack.len(), program.outputs());
+
+        RegisterProgram {
+            code: t.code,
+            inputs: program.inputs(),
+            outputs: t.stack,
+            registers: program.max_depth().max(1),
+            curves: program.curves(),
+            random_dimensions: program.random_dimensions(),
+        }
+    }
+}
+
+/// Virtual stack of the registers each slot currently refers to
+struct Translator {
+    stack: Vec<Reg>,
+    code: Vec<RegisterInstruction>,
+}
+
+impl Translator {
+    /// Pops `N` slots, returned bottom-most first
+    #[inline]
+    fn pop<const N: usize>(&mut self) -> [Reg; N] {
+        let mut regs = [Reg::new(0); N];
+        regs.copy_from_slice(&self.stack[self.stack.len() - N..]);
+        self.stack.truncate(self.stack.len() - N);
+        regs
+    }
+
+    /// Pushes `N` new slots, returning their own registers to write results into
+    #[inline]
+    fn push<const N: usize>(&mut self) -> [Reg; N] {
+        let mut regs = [Reg::new(0); N];
+        for r in &mut regs {
+            *r = Reg::new(self.stack.len());
+            self.stack.push(*r);
+        }
+        regs
+    }
+
+    /// Pushes new slots aliasing existing registers
+    #[inline]
+    fn alias(&mut self, regs: &[Reg], count: usize) {
+        for _ in 0..count {
+            self.stack.extend_from_slice(regs);
+        }
+    }
+
+    fn translate(&mut self, instr: Instruction) {
+        use RegisterInstruction as R;
+
+        let emit = match instr {
+            Instruction::NoOp => return,
+
+            Instruction::ScalarUnary(op) => {
+                let [src] = self.pop();
+                let [dst] = self.push();
+                R::ScalarUnary { op, dst, src }
+            }
+            Instruction::ScalarBinary(op) => {
+                let [a, b] = self.pop();
+                let [dst] = self.push();
+                R::ScalarBinary { op, dst, a, b }
+            }
+            Instruction::ScalarCompare(mode) => {
+                let [a, b] = self.pop();
+                let [dst] = self.push();
+                R::ScalarCompare { mode, dst, a, b }
+            }
+            Instruction::ScalarBinaryDup(op) => {
+                let [x] = self.pop();
+                let [dst] = self.push();
+                R::ScalarBinary { op, dst, a: x, b: x }
+            }
+            Instruction::ScalarMulAdd => {
+                let [a, b, c] = self.pop();
+                let [dst] = self.push();
+                R::ScalarMulAdd { dst, a, b, c }
+            }
+            Instruction::ScalarMulSub => {
+                let [a, b, c] = self.pop();
+                let [dst] = self.push();
+                R::ScalarMulSub { dst, a, b, c }
+            }
+
+            Instruction::VectorUnary(op) => {
+                let src = self.pop();
+                let dst = self.push();
+                R::VectorUnary { op, dst, src }
+            }
+            // the top-most vector is A
+            Instruction::VectorBinary(op) => {
+                let [xb, yb, zb, xa, ya, za] = self.pop();
+                let dst = self.push();
+                R::VectorBinary {
+                    op,
+                    dst,
+                    a: [xa, ya, za],
+                    b: [xb, yb, zb],
+                }
+            }
+            Instruction::VectorCompare(mode) => {
+                let [xb, yb, zb, xa, ya, za] = self.pop();
+                let dst = self.push();
+                R::VectorCompare {
+                    mode,
+                    dst,
+                    a: [xa, ya, za],
+                    b: [xb, yb, zb],
+                }
+            }
+            Instruction::VectorMulAdd => {
+                let [xc, yc, zc, xb, yb, zb, xa, ya, za] = self.pop();
+                let dst = self.push();
+                R::VectorMulAdd {
+                    dst,
+                    a: [xa, ya, za],
+                    b: [xb, yb, zb],
+                    c: [xc, yc, zc],
+                }
+            }
+            Instruction::VectorSum => {
+                let src = self.pop();
+                let [dst] = self.push();
+                R::VectorSum { dst, src }
+            }
+            Instruction::VectorProduct => {
+                let src = self.pop();
+                let [dst] = self.push();
+                R::VectorProduct { dst, src }
+            }
+            Instruction::VectorMin => {
+                let src = self.pop();
+                let [dst] = self.push();
+                R::VectorMin { dst, src }
+            }
+            Instruction::VectorMax => {
+                let src = self.pop();
+                let [dst] = self.push();
+                R::VectorMax { dst, src }
+            }
+
+            Instruction::VectorSplat => {
+                let [x] = self.pop::<1>();
+                return self.alias(&[x], 3);
+            }
+            Instruction::CopyScalar(count) => {
+                let top = self.stack[self.stack.len() - 1];
+                return self.alias(&[top], count as usize);
+            }
+            Instruction::CopyVector(count) => {
+                let xyz = self.pop::<3>();
+                return self.alias(&xyz, 1 + count as usize);
+            }
+
+            Instruction::Curve(idx) => {
+                let [src] = self.pop();
+                let [dst] = self.push();
+                R::Curve { idx, dst, src }
+            }
+
+            Instruction::Random(offset) => {
+                let [dst] = self.push();
+                R::Random { offset, dst }
+            }
+        };
+
+        self.code.push(emit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::exec::Executor;
+
+    #[test]
+    fn test_register_program_matches() {
+        let code = vec![
+            Instruction::VectorSplat,
+            Instruction::VectorBinary(BinaryOp::Mul),
+            Instruction::CopyVector(1),
+            Instruction::VectorBinary(BinaryOp::Add),
+            Instruction::VectorSum,
+            Instruction::CopyScalar(2),
+            Instruction::ScalarBinary(BinaryOp::Sub),
+            Instruction::ScalarUnary(UnaryOp::Abs),
+            Instruction::ScalarBinary(BinaryOp::Add),
+        ];
+
+        let program = Program::new(code, 4, 1).unwrap();
+
+        let rom = ROM {
+            scalar: Vec::new(),
+            curves: Vec::new(),
+        };
+
+        let count = 21;
+        let inputs: Vec<f32> = (0..4 * count).map(|i| (i % 5) as f32 - 2.0).collect();
+
+        let mut expected = vec![0.0; count];
+        let executor = Executor::new();
+        executor.run(&program, &rom, &inputs, &mut expected, count);
+
+        for program in &[program.clone(), program.fused()] {
+            let registers = RegisterProgram::translate(program);
+
+            // splats and copies are free
+            assert_eq!(registers.code().len(), 6);
+            assert_eq!(registers.registers(), program.max_depth());
+
+            let mut outputs = vec![0.0; count];
+            executor.run_registers(&registers, &rom, &inputs, &mut outputs, count);
+
+            for (y, e) in outputs.iter().zip(&expected) {
+                assert!((y - e).abs() < 1e-4, "{} == {}", y, e);
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use thermite::*;
 
+use crate::vm::dual::Dual;
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, DeepSizeOf)]
 pub enum InterpolationMode {
     Nearest,
@@ -59,6 +61,40 @@ impl Curve {
         }
     }
 
+    /// Derivative of `eval_scalar` with respect to `x`
+    #[inline(always)]
+    fn derivative_scalar(&self, x: f32) -> f32 {
+        match self {
+            Curve::LookupTable { values, interpolation } => match raygon_core::lower_bound(values.len(), |idx| values[idx].0 < x) {
+                Some(idx) if idx > 0 && idx < values.len() => {
+                    let a = unsafe { values.get_unchecked(idx - 1) };
+                    let b = unsafe { values.get_unchecked(idx) };
+
+                    let dx = b.0 - a.0;
+                    let t = (x - a.0) / dx;
+
+                    match interpolation {
+                        InterpolationMode::Linear => (b.1 - a.1) / dx,
+                        InterpolationMode::Nearest => 0.0,
+                        InterpolationMode::CubicHermite => {
+                            let t_squared = t * t;
+
+                            // derivatives of the Hermite basis functions with respect to t
+                            let dh00 = 6.0 * (t_squared - t);
+                            let dh10 = 3.0 * t_squared - 4.0 * t + 1.0;
+                            let dh01 = -dh00;
+                            let dh11 = 3.0 * t_squared - 2.0 * t;
+
+                            (((dh00 * a.1) + (dh10 * a.2)) + ((dh01 * b.1) + (dh11 * b.2))) / dx
+                        }
+                    }
+                }
+                _ => 0.0,
+            },
+            _ => 0.0,
+        }
+    }
+
     #[inline(always)]
     pub fn eval<S: Simd>(&self, x: Vf32<S>) -> Vf32<S> {
         match *self {
@@ -69,6 +105,29 @@ impl Curve {
             _ => x.map_scalar(|_, x| self.eval_scalar(x)),
         }
     }
+
+    /// Evaluates the curve along with its derivative. Values match `Curve::eval`.
+    #[inline(always)]
+    pub fn eval_dual<S: Simd, const N: usize>(&self, x: Dual<S, N>) -> Dual<S, N> {
+        match *self {
+            Curve::Poly(ref poly) => {
+                // Horner's method for the derivative alongside the polynomial
+                let mut p = Vf32::<S>::zero();
+                let mut dp = Vf32::<S>::zero();
+
+                for &c in poly.iter().rev() {
+                    dp = dp.mul_add(x.v, p);
+                    p = p.mul_add(x.v, Vf32::<S>::splat(c));
+                }
+
+                x.chain(x.v.poly(poly), dp)
+            }
+            _ => x.chain(
+                x.v.map_scalar(|_, x| self.eval_scalar(x)),
+                x.v.map_scalar(|_, x| self.derivative_scalar(x)),
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
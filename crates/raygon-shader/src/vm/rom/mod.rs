@@ -12,6 +12,7 @@ pub struct ROM {
 }
 
 impl ROM {
+    /// Curve `index`, which must be in range. Programs are checked against the ROM before they run.
     #[inline(always)]
     pub fn get_curve(&self, index: CurveIndex) -> &Curve {
         unsafe { &*self.curves.get_unchecked_debug_checked(index.into()) }